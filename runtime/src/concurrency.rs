@@ -14,21 +14,58 @@
 
 //! Concurrency primitives runtime support
 
-use std::ffi::c_int;
-use std::sync::{Arc, Mutex, mpsc, atomic::{AtomicI32, Ordering}};
+use std::cell::RefCell;
+use std::ffi::{c_int, c_void};
+use std::sync::{Arc, Condvar, Mutex, RwLock, RwLockReadGuard, RwLockWriteGuard, atomic::{AtomicI32, AtomicI64, Ordering}};
 use std::thread;
 use std::time::Duration;
 use std::collections::HashMap;
 use std::sync::Mutex as StdMutex;
 
+use crossbeam_channel::{bounded, unbounded, Select, Sender, Receiver};
+
 // Global thread registry for tracking threads
 lazy_static::lazy_static! {
     static ref THREAD_REGISTRY: StdMutex<HashMap<i32, thread::JoinHandle<()>>> = StdMutex::new(HashMap::new());
+    // Per-thread "finished" flag/condvar pair, set by the spawned closure
+    // right before it returns so `aether_thread_join` can wait with a
+    // timeout instead of blocking forever on `JoinHandle::join`.
+    static ref THREAD_FINISHED_REGISTRY: StdMutex<HashMap<i32, Arc<(Mutex<bool>, Condvar)>>> = StdMutex::new(HashMap::new());
     static ref NEXT_THREAD_ID: AtomicI32 = AtomicI32::new(1);
     static ref NEXT_MUTEX_ID: AtomicI32 = AtomicI32::new(1);
     static ref MUTEX_REGISTRY: StdMutex<HashMap<i32, Arc<Mutex<MutexState>>>> = StdMutex::new(HashMap::new());
     static ref NEXT_CHANNEL_ID: AtomicI32 = AtomicI32::new(1);
     static ref CHANNEL_REGISTRY: StdMutex<HashMap<i32, ChannelPair>> = StdMutex::new(HashMap::new());
+    static ref NEXT_CONDVAR_ID: AtomicI32 = AtomicI32::new(1);
+    static ref CONDVAR_REGISTRY: StdMutex<HashMap<i32, Arc<Condvar>>> = StdMutex::new(HashMap::new());
+    static ref NEXT_RWLOCK_ID: AtomicI32 = AtomicI32::new(1);
+    static ref RWLOCK_REGISTRY: StdMutex<HashMap<i32, Arc<RwLock<()>>>> = StdMutex::new(HashMap::new());
+}
+
+/// A held read or write guard, kept alive until the matching unlock call.
+enum RwLockGuard {
+    Read(RwLockReadGuard<'static, ()>),
+    Write(RwLockWriteGuard<'static, ()>),
+}
+
+thread_local! {
+    // The real, live guard for every mutex this thread currently holds,
+    // keyed by mutex handle. `aether_mutex_lock`/`aether_mutex_unlock`
+    // only need a `locked` flag, but `aether_condvar_wait` needs an actual
+    // `MutexGuard` to hand to `Condvar::wait_timeout` so the unlock and
+    // the sleep happen atomically; stashing the guard here instead of
+    // dropping it immediately lets both paths share it. The lifetime is
+    // erased to `'static` below; that's sound because the owning `Arc` is
+    // kept alive right alongside the guard in the same map entry.
+    static HELD_GUARDS: RefCell<HashMap<i32, (Arc<Mutex<MutexState>>, std::sync::MutexGuard<'static, MutexState>)>> =
+        RefCell::new(HashMap::new());
+
+    // Live read/write guards for rwlocks this thread currently holds,
+    // keyed by handle. A `Vec` rather than a single slot because multiple
+    // read locks on the same handle can be held concurrently by one
+    // thread; unlocking pops the most recently acquired matching guard.
+    static HELD_RWLOCK_GUARDS: RefCell<HashMap<i32, Vec<(Arc<RwLock<()>>, RwLockGuard)>>> =
+        RefCell::new(HashMap::new());
 }
 
 struct MutexState {
@@ -36,23 +73,38 @@ struct MutexState {
     owner_thread: i32,
 }
 
-enum ChannelSender {
-    Bounded(mpsc::SyncSender<i32>),
-    Unbounded(mpsc::Sender<i32>),
+/// Records that the current thread now holds `guard` for `handle`,
+/// updating the lock bookkeeping before stashing it away.
+fn hold_guard(handle: c_int, mutex: Arc<Mutex<MutexState>>, mut guard: std::sync::MutexGuard<MutexState>) {
+    guard.locked = true;
+    guard.owner_thread = aether_thread_current_id();
+    // SAFETY: `mutex` is moved into the same map entry as `guard`, so the
+    // `Mutex<MutexState>` the guard borrows from stays alive for as long
+    // as the guard does.
+    let guard: std::sync::MutexGuard<'static, MutexState> = unsafe { std::mem::transmute(guard) };
+    HELD_GUARDS.with(|held| {
+        held.borrow_mut().insert(handle, (mutex, guard));
+    });
 }
 
-impl ChannelSender {
-    fn send(&self, value: i32) -> Result<(), mpsc::SendError<i32>> {
-        match self {
-            ChannelSender::Bounded(s) => s.send(value),
-            ChannelSender::Unbounded(s) => s.send(value),
-        }
-    }
-}
+/// A value sent over a channel, carried as an opaque pointer so any Aether
+/// value can travel without the channel needing to know its shape. The
+/// runtime only moves the pointer itself, never dereferences it, so it's
+/// sound to treat as `Send`/`Sync`; the caller owns whatever thread-safety
+/// the pointee actually needs.
+#[derive(Clone, Copy)]
+struct ChannelPtr(*mut c_void);
+unsafe impl Send for ChannelPtr {}
+unsafe impl Sync for ChannelPtr {}
 
+/// A channel's sender and receiver, cloned out of the registry on every
+/// operation. `crossbeam_channel::Sender`/`Receiver` clones are cheap and
+/// lock-free, which is what gives this channel true multi-producer/
+/// multi-consumer semantics instead of the single-consumer-at-a-time
+/// serialization a shared `Mutex<Receiver>>` would impose.
 struct ChannelPair {
-    sender: ChannelSender,
-    receiver: Arc<Mutex<mpsc::Receiver<i32>>>,
+    sender: Sender<ChannelPtr>,
+    receiver: Receiver<ChannelPtr>,
 }
 
 /// Create a new thread
@@ -62,43 +114,83 @@ pub unsafe extern "C" fn aether_thread_create(
     stack_size: c_int
 ) -> c_int {
     let stack_size = if stack_size > 0 { stack_size as usize } else { 2 * 1024 * 1024 };
-    
+
+    let thread_id = NEXT_THREAD_ID.fetch_add(1, Ordering::SeqCst);
+    let finished = Arc::new((Mutex::new(false), Condvar::new()));
+    THREAD_FINISHED_REGISTRY.lock().unwrap().insert(thread_id, finished.clone());
+
     let builder = thread::Builder::new()
         .stack_size(stack_size);
-    
+
     match builder.spawn(move || {
         function();
+        let (lock, cvar) = &*finished;
+        *lock.lock().unwrap() = true;
+        cvar.notify_all();
     }) {
         Ok(handle) => {
-            let thread_id = NEXT_THREAD_ID.fetch_add(1, Ordering::SeqCst);
             THREAD_REGISTRY.lock().unwrap().insert(thread_id, handle);
             thread_id
         },
-        Err(_) => -1,
+        Err(_) => {
+            THREAD_FINISHED_REGISTRY.lock().unwrap().remove(&thread_id);
+            -1
+        },
     }
 }
 
-/// Join a thread
+/// Join a thread. With `timeout_ms < 0` this blocks forever, as before.
+/// With `timeout_ms >= 0` it waits on the thread's finished condvar for at
+/// most that long: if the thread finished in time, the handle is removed
+/// from the registry and joined (now immediate), returning 1; otherwise
+/// the handle is left in the registry so the caller can retry, and this
+/// returns -1 to mean "still running, not joined".
 #[no_mangle]
 pub extern "C" fn aether_thread_join(handle: c_int, timeout_ms: c_int) -> c_int {
-    let handle_opt = THREAD_REGISTRY.lock().unwrap().remove(&handle);
-    
-    match handle_opt {
-        Some(thread_handle) => {
-            if timeout_ms < 0 {
-                // Infinite wait
-                match thread_handle.join() {
-                    Ok(_) => 1,
-                    Err(_) => 0,
-                }
-            } else {
-                // For simplicity, we'll do a blocking join since Rust doesn't have timed join
-                // In a real implementation, you'd use a different approach
+    if timeout_ms < 0 {
+        return match THREAD_REGISTRY.lock().unwrap().remove(&handle) {
+            Some(thread_handle) => {
+                THREAD_FINISHED_REGISTRY.lock().unwrap().remove(&handle);
                 match thread_handle.join() {
                     Ok(_) => 1,
                     Err(_) => 0,
                 }
-            }
+            },
+            None => 0,
+        };
+    }
+
+    let finished = match THREAD_FINISHED_REGISTRY.lock().unwrap().get(&handle).cloned() {
+        Some(f) => f,
+        None => return 0,
+    };
+
+    let (lock, cvar) = &*finished;
+    let timeout = Duration::from_millis(timeout_ms as u64);
+    let start = std::time::Instant::now();
+    let mut done = lock.lock().unwrap();
+    while !*done {
+        let remaining = timeout.saturating_sub(start.elapsed());
+        if remaining.is_zero() {
+            break;
+        }
+        let (guard, result) = cvar.wait_timeout(done, remaining).unwrap();
+        done = guard;
+        if result.timed_out() {
+            break;
+        }
+    }
+
+    if !*done {
+        return -1;
+    }
+    drop(done);
+
+    THREAD_FINISHED_REGISTRY.lock().unwrap().remove(&handle);
+    match THREAD_REGISTRY.lock().unwrap().remove(&handle) {
+        Some(thread_handle) => match thread_handle.join() {
+            Ok(_) => 1,
+            Err(_) => 0,
         },
         None => 0,
     }
@@ -143,15 +235,14 @@ pub extern "C" fn aether_mutex_create() -> c_int {
 #[no_mangle]
 pub extern "C" fn aether_mutex_lock(handle: c_int, timeout_ms: c_int) -> c_int {
     let mutex_opt = MUTEX_REGISTRY.lock().unwrap().get(&handle).cloned();
-    
+
     match mutex_opt {
         Some(mutex) => {
             if timeout_ms < 0 {
                 // Infinite wait
                 match mutex.lock() {
-                    Ok(mut state) => {
-                        state.locked = true;
-                        state.owner_thread = aether_thread_current_id();
+                    Ok(guard) => {
+                        hold_guard(handle, mutex.clone(), guard);
                         1
                     },
                     Err(_) => 0,
@@ -160,12 +251,11 @@ pub extern "C" fn aether_mutex_lock(handle: c_int, timeout_ms: c_int) -> c_int {
                 // Try lock with timeout
                 let timeout = Duration::from_millis(timeout_ms as u64);
                 let start = std::time::Instant::now();
-                
+
                 loop {
                     match mutex.try_lock() {
-                        Ok(mut state) => {
-                            state.locked = true;
-                            state.owner_thread = aether_thread_current_id();
+                        Ok(guard) => {
+                            hold_guard(handle, mutex.clone(), guard);
                             return 1;
                         },
                         Err(_) => {
@@ -185,116 +275,357 @@ pub extern "C" fn aether_mutex_lock(handle: c_int, timeout_ms: c_int) -> c_int {
 /// Unlock a mutex
 #[no_mangle]
 pub extern "C" fn aether_mutex_unlock(handle: c_int) {
-    let mutex_opt = MUTEX_REGISTRY.lock().unwrap().get(&handle).cloned();
-    
-    if let Some(mutex) = mutex_opt {
-        if let Ok(mut state) = mutex.lock() {
-            state.locked = false;
-            state.owner_thread = -1;
+    HELD_GUARDS.with(|held| {
+        if let Some((_mutex, mut guard)) = held.borrow_mut().remove(&handle) {
+            guard.locked = false;
+            guard.owner_thread = -1;
+            // `guard` (and the `Arc` kept alive alongside it) drop here,
+            // releasing the real lock.
         }
-    }
+    });
 }
 
 /// Destroy a mutex
 #[no_mangle]
 pub extern "C" fn aether_mutex_destroy(handle: c_int) {
+    HELD_GUARDS.with(|held| {
+        held.borrow_mut().remove(&handle);
+    });
     MUTEX_REGISTRY.lock().unwrap().remove(&handle);
 }
 
-/// Create a channel
+/// Create a reader/writer lock
 #[no_mangle]
-pub extern "C" fn aether_channel_create(capacity: c_int) -> c_int {
-    let channel_id = NEXT_CHANNEL_ID.fetch_add(1, Ordering::SeqCst);
-    
-    let channel_pair = if capacity == 0 {
-        let (sender, receiver) = mpsc::channel();
-        ChannelPair {
-            sender: ChannelSender::Unbounded(sender),
-            receiver: Arc::new(Mutex::new(receiver)),
+pub extern "C" fn aether_rwlock_create() -> c_int {
+    let rwlock_id = NEXT_RWLOCK_ID.fetch_add(1, Ordering::SeqCst);
+    RWLOCK_REGISTRY.lock().unwrap().insert(rwlock_id, Arc::new(RwLock::new(())));
+    rwlock_id
+}
+
+fn hold_rwlock_guard(handle: c_int, rwlock: Arc<RwLock<()>>, guard: RwLockGuard) {
+    HELD_RWLOCK_GUARDS.with(|held| {
+        held.borrow_mut().entry(handle).or_insert_with(Vec::new).push((rwlock, guard));
+    });
+}
+
+/// Acquire a shared (read) lock, allowing other readers to proceed
+/// concurrently. Returns 1 on success, 0 on timeout or an unknown handle.
+#[no_mangle]
+pub extern "C" fn aether_rwlock_read_lock(handle: c_int, timeout_ms: c_int) -> c_int {
+    let rwlock_opt = RWLOCK_REGISTRY.lock().unwrap().get(&handle).cloned();
+    let rwlock = match rwlock_opt {
+        Some(rwlock) => rwlock,
+        None => return 0,
+    };
+
+    let timeout = Duration::from_millis(timeout_ms.max(0) as u64);
+    let start = std::time::Instant::now();
+
+    loop {
+        match rwlock.try_read() {
+            Ok(guard) => {
+                let guard: RwLockReadGuard<'static, ()> = unsafe { std::mem::transmute(guard) };
+                hold_rwlock_guard(handle, rwlock, RwLockGuard::Read(guard));
+                return 1;
+            },
+            Err(_) => {
+                if timeout_ms >= 0 && start.elapsed() >= timeout {
+                    return 0;
+                }
+                thread::sleep(Duration::from_millis(1));
+            }
+        }
+    }
+}
+
+/// Acquire an exclusive (write) lock. Returns 1 on success, 0 on timeout
+/// or an unknown handle.
+#[no_mangle]
+pub extern "C" fn aether_rwlock_write_lock(handle: c_int, timeout_ms: c_int) -> c_int {
+    let rwlock_opt = RWLOCK_REGISTRY.lock().unwrap().get(&handle).cloned();
+    let rwlock = match rwlock_opt {
+        Some(rwlock) => rwlock,
+        None => return 0,
+    };
+
+    let timeout = Duration::from_millis(timeout_ms.max(0) as u64);
+    let start = std::time::Instant::now();
+
+    loop {
+        match rwlock.try_write() {
+            Ok(guard) => {
+                let guard: RwLockWriteGuard<'static, ()> = unsafe { std::mem::transmute(guard) };
+                hold_rwlock_guard(handle, rwlock, RwLockGuard::Write(guard));
+                return 1;
+            },
+            Err(_) => {
+                if timeout_ms >= 0 && start.elapsed() >= timeout {
+                    return 0;
+                }
+                thread::sleep(Duration::from_millis(1));
+            }
+        }
+    }
+}
+
+/// Release the most recently acquired read lock on `handle`
+#[no_mangle]
+pub extern "C" fn aether_rwlock_read_unlock(handle: c_int) {
+    HELD_RWLOCK_GUARDS.with(|held| {
+        let mut held = held.borrow_mut();
+        if let Some(stack) = held.get_mut(&handle) {
+            if let Some(pos) = stack.iter().rposition(|(_, guard)| matches!(guard, RwLockGuard::Read(_))) {
+                stack.remove(pos);
+            }
+        }
+    });
+}
+
+/// Release the most recently acquired write lock on `handle`
+#[no_mangle]
+pub extern "C" fn aether_rwlock_write_unlock(handle: c_int) {
+    HELD_RWLOCK_GUARDS.with(|held| {
+        let mut held = held.borrow_mut();
+        if let Some(stack) = held.get_mut(&handle) {
+            if let Some(pos) = stack.iter().rposition(|(_, guard)| matches!(guard, RwLockGuard::Write(_))) {
+                stack.remove(pos);
+            }
+        }
+    });
+}
+
+/// Destroy a reader/writer lock
+#[no_mangle]
+pub extern "C" fn aether_rwlock_destroy(handle: c_int) {
+    HELD_RWLOCK_GUARDS.with(|held| {
+        held.borrow_mut().remove(&handle);
+    });
+    RWLOCK_REGISTRY.lock().unwrap().remove(&handle);
+}
+
+/// Create a condition variable
+#[no_mangle]
+pub extern "C" fn aether_condvar_create() -> c_int {
+    let condvar_id = NEXT_CONDVAR_ID.fetch_add(1, Ordering::SeqCst);
+    CONDVAR_REGISTRY.lock().unwrap().insert(condvar_id, Arc::new(Condvar::new()));
+    condvar_id
+}
+
+/// Wait on a condition variable, atomically releasing `mutex` and
+/// re-acquiring it before returning. Returns 1 if woken by a signal or
+/// broadcast, 0 if `timeout_ms` elapsed first (or the handles don't
+/// refer to a mutex the calling thread currently holds / a registered
+/// condvar).
+#[no_mangle]
+pub extern "C" fn aether_condvar_wait(cv: c_int, mutex: c_int, timeout_ms: c_int) -> c_int {
+    let condvar_opt = CONDVAR_REGISTRY.lock().unwrap().get(&cv).cloned();
+    let condvar = match condvar_opt {
+        Some(condvar) => condvar,
+        None => return 0,
+    };
+
+    let held = HELD_GUARDS.with(|held| held.borrow_mut().remove(&mutex));
+    let (owning_mutex, guard) = match held {
+        Some(entry) => entry,
+        None => return 0,
+    };
+
+    if timeout_ms < 0 {
+        // Infinite wait
+        match condvar.wait(guard) {
+            Ok(guard) => {
+                hold_guard(mutex, owning_mutex, guard);
+                1
+            },
+            Err(_) => 0,
         }
     } else {
-        let (sender, receiver) = mpsc::sync_channel(capacity as usize);
-        ChannelPair {
-            sender: ChannelSender::Bounded(sender),
-            receiver: Arc::new(Mutex::new(receiver)),
+        let timeout = Duration::from_millis(timeout_ms as u64);
+        match condvar.wait_timeout(guard, timeout) {
+            Ok((guard, result)) => {
+                hold_guard(mutex, owning_mutex, guard);
+                if result.timed_out() { 0 } else { 1 }
+            },
+            Err(_) => 0,
         }
+    }
+}
+
+/// Wake one thread waiting on a condition variable
+#[no_mangle]
+pub extern "C" fn aether_condvar_signal(cv: c_int) {
+    if let Some(condvar) = CONDVAR_REGISTRY.lock().unwrap().get(&cv) {
+        condvar.notify_one();
+    }
+}
+
+/// Wake every thread waiting on a condition variable
+#[no_mangle]
+pub extern "C" fn aether_condvar_broadcast(cv: c_int) {
+    if let Some(condvar) = CONDVAR_REGISTRY.lock().unwrap().get(&cv) {
+        condvar.notify_all();
+    }
+}
+
+/// Destroy a condition variable
+#[no_mangle]
+pub extern "C" fn aether_condvar_destroy(cv: c_int) {
+    CONDVAR_REGISTRY.lock().unwrap().remove(&cv);
+}
+
+/// Create a channel. `capacity == 0` creates an unbounded channel;
+/// otherwise the channel is bounded to `capacity` in-flight values.
+#[no_mangle]
+pub extern "C" fn aether_channel_create(capacity: c_int) -> c_int {
+    let channel_id = NEXT_CHANNEL_ID.fetch_add(1, Ordering::SeqCst);
+
+    let (sender, receiver) = if capacity == 0 {
+        unbounded()
+    } else {
+        bounded(capacity as usize)
     };
-    
-    CHANNEL_REGISTRY.lock().unwrap().insert(channel_id, channel_pair);
+
+    CHANNEL_REGISTRY.lock().unwrap().insert(channel_id, ChannelPair { sender, receiver });
     channel_id
 }
 
-/// Send on a channel
+/// Send a pointer on a channel, blocking until there's room (or forever if
+/// `timeout_ms < 0`). Returns 1 on success, 0 on timeout, a disconnected
+/// channel, or an unknown handle.
 #[no_mangle]
-pub extern "C" fn aether_channel_send(handle: c_int, value: c_int, timeout_ms: c_int) -> c_int {
-    let sender_opt = CHANNEL_REGISTRY.lock().unwrap().get(&handle).map(|c| match &c.sender {
-        ChannelSender::Bounded(s) => ChannelSender::Bounded(s.clone()),
-        ChannelSender::Unbounded(s) => ChannelSender::Unbounded(s.clone()),
-    });
-    
-    match sender_opt {
-        Some(sender) => {
-            if timeout_ms < 0 {
-                // Blocking send
-                match sender.send(value) {
-                    Ok(_) => 1,
-                    Err(_) => 0,
-                }
-            } else {
-                // Rust's channels don't have timeout on send, so we simulate
-                match sender.send(value) {
-                    Ok(_) => 1,
-                    Err(_) => 0,
-                }
-            }
+pub unsafe extern "C" fn aether_channel_send_ptr(handle: c_int, value: *mut c_void, timeout_ms: c_int) -> c_int {
+    let sender = match CHANNEL_REGISTRY.lock().unwrap().get(&handle).map(|c| c.sender.clone()) {
+        Some(s) => s,
+        None => return 0,
+    };
+
+    let ok = if timeout_ms < 0 {
+        sender.send(ChannelPtr(value)).is_ok()
+    } else {
+        sender.send_timeout(ChannelPtr(value), Duration::from_millis(timeout_ms as u64)).is_ok()
+    };
+
+    if ok { 1 } else { 0 }
+}
+
+/// Receive a pointer from a channel, blocking until a value is available
+/// (or forever if `timeout_ms < 0`). Returns 1 on success, 0 on timeout, a
+/// disconnected channel, or an unknown handle.
+#[no_mangle]
+pub unsafe extern "C" fn aether_channel_recv_ptr(handle: c_int, value: *mut *mut c_void, timeout_ms: c_int) -> c_int {
+    if value.is_null() {
+        return 0;
+    }
+
+    let receiver = match CHANNEL_REGISTRY.lock().unwrap().get(&handle).map(|c| c.receiver.clone()) {
+        Some(r) => r,
+        None => return 0,
+    };
+
+    let result = if timeout_ms < 0 {
+        receiver.recv().map_err(|_| ())
+    } else {
+        receiver.recv_timeout(Duration::from_millis(timeout_ms as u64)).map_err(|_| ())
+    };
+
+    match result {
+        Ok(ChannelPtr(ptr)) => {
+            *value = ptr;
+            1
         },
-        None => 0,
+        Err(_) => 0,
     }
 }
 
-/// Receive from a channel
+/// Send a pointer on a channel without blocking. Returns 1 if the value
+/// was accepted, 0 if the channel is full, disconnected, or unknown.
 #[no_mangle]
-pub unsafe extern "C" fn aether_channel_receive(
-    handle: c_int,
-    value: *mut c_int,
-    timeout_ms: c_int
-) -> c_int {
+pub unsafe extern "C" fn aether_channel_try_send(handle: c_int, value: *mut c_void) -> c_int {
+    let sender = match CHANNEL_REGISTRY.lock().unwrap().get(&handle).map(|c| c.sender.clone()) {
+        Some(s) => s,
+        None => return 0,
+    };
+
+    match sender.try_send(ChannelPtr(value)) {
+        Ok(_) => 1,
+        Err(_) => 0,
+    }
+}
+
+/// Receive a pointer from a channel without blocking. Returns 1 and writes
+/// the value to `value` if one was immediately available, 0 if the
+/// channel is empty, disconnected, or unknown.
+#[no_mangle]
+pub unsafe extern "C" fn aether_channel_try_recv(handle: c_int, value: *mut *mut c_void) -> c_int {
     if value.is_null() {
         return 0;
     }
-    
-    let receiver_opt = CHANNEL_REGISTRY.lock().unwrap()
-        .get(&handle)
-        .map(|c| c.receiver.clone());
-    
-    match receiver_opt {
-        Some(receiver) => {
-            let receiver = match receiver.lock() {
-                Ok(r) => r,
-                Err(_) => return 0,
-            };
-            
-            if timeout_ms < 0 {
-                // Blocking receive
-                match receiver.recv() {
-                    Ok(v) => {
-                        *value = v;
-                        1
-                    },
-                    Err(_) => 0,
-                }
-            } else {
-                // Timed receive
-                match receiver.recv_timeout(Duration::from_millis(timeout_ms as u64)) {
-                    Ok(v) => {
-                        *value = v;
-                        1
-                    },
-                    Err(_) => 0,
-                }
-            }
+
+    let receiver = match CHANNEL_REGISTRY.lock().unwrap().get(&handle).map(|c| c.receiver.clone()) {
+        Some(r) => r,
+        None => return 0,
+    };
+
+    match receiver.try_recv() {
+        Ok(ChannelPtr(ptr)) => {
+            *value = ptr;
+            1
         },
-        None => 0,
+        Err(_) => 0,
+    }
+}
+
+/// Wait on several channels at once, receiving from whichever becomes
+/// ready first. Builds a `crossbeam_channel::Select` over the receivers
+/// named by `handles`/`count`, waits up to `timeout_ms` (or forever if
+/// negative), then writes the winning index to `out_index` and its value
+/// to `out_value`. Returns the winning index on success, 0 on timeout, an
+/// unknown handle, or a disconnected channel.
+#[no_mangle]
+pub unsafe extern "C" fn aether_channel_select(
+    handles: *const c_int,
+    count: c_int,
+    out_index: *mut c_int,
+    out_value: *mut *mut c_void,
+    timeout_ms: c_int,
+) -> c_int {
+    if handles.is_null() || out_index.is_null() || out_value.is_null() || count <= 0 {
+        return 0;
+    }
+
+    let handle_slice = std::slice::from_raw_parts(handles, count as usize);
+
+    let receivers: Option<Vec<Receiver<ChannelPtr>>> = {
+        let registry = CHANNEL_REGISTRY.lock().unwrap();
+        handle_slice.iter().map(|h| registry.get(h).map(|c| c.receiver.clone())).collect()
+    };
+    let receivers = match receivers {
+        Some(r) => r,
+        None => return 0,
+    };
+
+    let mut select = Select::new();
+    for receiver in &receivers {
+        select.recv(receiver);
+    }
+
+    let oper = if timeout_ms < 0 {
+        select.select()
+    } else {
+        match select.select_timeout(Duration::from_millis(timeout_ms as u64)) {
+            Ok(oper) => oper,
+            Err(_) => return 0,
+        }
+    };
+
+    let index = oper.index();
+    match oper.recv(&receivers[index]) {
+        Ok(ChannelPtr(ptr)) => {
+            *out_index = index as c_int;
+            *out_value = ptr;
+            index as c_int
+        },
+        Err(_) => 0,
     }
 }
 
@@ -304,37 +635,50 @@ pub extern "C" fn aether_channel_close(handle: c_int) {
     CHANNEL_REGISTRY.lock().unwrap().remove(&handle);
 }
 
+/// Map an Aether memory-order code (0=Relaxed, 1=Acquire, 2=Release,
+/// 3=AcqRel, 4=SeqCst) to the matching `Ordering`, defaulting unknown
+/// codes to `SeqCst` since that's the strongest/safest fallback.
+fn decode_ordering(order: c_int) -> Ordering {
+    match order {
+        0 => Ordering::Relaxed,
+        1 => Ordering::Acquire,
+        2 => Ordering::Release,
+        3 => Ordering::AcqRel,
+        _ => Ordering::SeqCst,
+    }
+}
+
 /// Atomic load
 #[no_mangle]
-pub unsafe extern "C" fn aether_atomic_load(ptr: *mut c_int) -> c_int {
+pub unsafe extern "C" fn aether_atomic_load(ptr: *mut c_int, order: c_int) -> c_int {
     if ptr.is_null() {
         return 0;
     }
-    
+
     let atomic = &*(ptr as *const AtomicI32);
-    atomic.load(Ordering::SeqCst)
+    atomic.load(decode_ordering(order))
 }
 
 /// Atomic store
 #[no_mangle]
-pub unsafe extern "C" fn aether_atomic_store(ptr: *mut c_int, value: c_int) {
+pub unsafe extern "C" fn aether_atomic_store(ptr: *mut c_int, value: c_int, order: c_int) {
     if ptr.is_null() {
         return;
     }
-    
+
     let atomic = &*(ptr as *const AtomicI32);
-    atomic.store(value, Ordering::SeqCst);
+    atomic.store(value, decode_ordering(order));
 }
 
 /// Atomic fetch and add
 #[no_mangle]
-pub unsafe extern "C" fn aether_atomic_fetch_add(ptr: *mut c_int, delta: c_int) -> c_int {
+pub unsafe extern "C" fn aether_atomic_fetch_add(ptr: *mut c_int, delta: c_int, order: c_int) -> c_int {
     if ptr.is_null() {
         return 0;
     }
-    
+
     let atomic = &*(ptr as *const AtomicI32);
-    atomic.fetch_add(delta, Ordering::SeqCst)
+    atomic.fetch_add(delta, decode_ordering(order))
 }
 
 /// Atomic compare and swap
@@ -342,15 +686,139 @@ pub unsafe extern "C" fn aether_atomic_fetch_add(ptr: *mut c_int, delta: c_int)
 pub unsafe extern "C" fn aether_atomic_compare_swap(
     ptr: *mut c_int,
     expected: c_int,
-    desired: c_int
+    desired: c_int,
+    order: c_int,
 ) -> c_int {
     if ptr.is_null() {
         return 0;
     }
-    
+
     let atomic = &*(ptr as *const AtomicI32);
-    match atomic.compare_exchange(expected, desired, Ordering::SeqCst, Ordering::SeqCst) {
+    let ordering = decode_ordering(order);
+    match atomic.compare_exchange(expected, desired, ordering, ordering) {
+        Ok(v) => v,
+        Err(v) => v,
+    }
+}
+
+/// Atomic load, 64-bit
+#[no_mangle]
+pub unsafe extern "C" fn aether_atomic_load64(ptr: *mut i64, order: c_int) -> i64 {
+    if ptr.is_null() {
+        return 0;
+    }
+
+    let atomic = &*(ptr as *const AtomicI64);
+    atomic.load(decode_ordering(order))
+}
+
+/// Atomic store, 64-bit
+#[no_mangle]
+pub unsafe extern "C" fn aether_atomic_store64(ptr: *mut i64, value: i64, order: c_int) {
+    if ptr.is_null() {
+        return;
+    }
+
+    let atomic = &*(ptr as *const AtomicI64);
+    atomic.store(value, decode_ordering(order));
+}
+
+/// Atomic fetch and add, 64-bit
+#[no_mangle]
+pub unsafe extern "C" fn aether_atomic_fetch_add64(ptr: *mut i64, delta: i64, order: c_int) -> i64 {
+    if ptr.is_null() {
+        return 0;
+    }
+
+    let atomic = &*(ptr as *const AtomicI64);
+    atomic.fetch_add(delta, decode_ordering(order))
+}
+
+/// Atomic compare and swap, 64-bit
+#[no_mangle]
+pub unsafe extern "C" fn aether_atomic_compare_swap64(
+    ptr: *mut i64,
+    expected: i64,
+    desired: i64,
+    order: c_int,
+) -> i64 {
+    if ptr.is_null() {
+        return 0;
+    }
+
+    let atomic = &*(ptr as *const AtomicI64);
+    let ordering = decode_ordering(order);
+    match atomic.compare_exchange(expected, desired, ordering, ordering) {
         Ok(v) => v,
         Err(v) => v,
     }
+}
+
+/// Wait queues for [`aether_futex_wait`]/[`aether_futex_wake`], keyed by
+/// the waited-on address. Entries are created lazily and never removed,
+/// the same trade-off [`MUTEX_REGISTRY`] and friends make: the number of
+/// distinct futex addresses a program uses is expected to be small and
+/// long-lived.
+lazy_static::lazy_static! {
+    static ref FUTEX_REGISTRY: StdMutex<HashMap<usize, Arc<(Mutex<()>, Condvar)>>> = StdMutex::new(HashMap::new());
+}
+
+fn futex_queue(addr: usize) -> Arc<(Mutex<()>, Condvar)> {
+    FUTEX_REGISTRY.lock().unwrap()
+        .entry(addr)
+        .or_insert_with(|| Arc::new((Mutex::new(()), Condvar::new())))
+        .clone()
+}
+
+/// Block the calling thread while `*ptr == expected`, waking on a
+/// matching [`aether_futex_wake`] or after `timeout_ms` (forever if
+/// negative). The value is rechecked under the wait-queue's lock right
+/// before sleeping, so a wake that lands between the caller's load and
+/// this call isn't lost. Returns 1 if woken, 0 on timeout or if
+/// `*ptr != expected` to begin with.
+#[no_mangle]
+pub unsafe extern "C" fn aether_futex_wait(ptr: *mut c_int, expected: c_int, timeout_ms: c_int) -> c_int {
+    if ptr.is_null() {
+        return 0;
+    }
+
+    let queue = futex_queue(ptr as usize);
+    let (lock, cvar) = &*queue;
+    let atomic = &*(ptr as *const AtomicI32);
+
+    let guard = lock.lock().unwrap();
+    if atomic.load(Ordering::SeqCst) != expected {
+        return 0;
+    }
+
+    if timeout_ms < 0 {
+        let _ = cvar.wait(guard).unwrap();
+        1
+    } else {
+        let (_, result) = cvar.wait_timeout(guard, Duration::from_millis(timeout_ms as u64)).unwrap();
+        if result.timed_out() { 0 } else { 1 }
+    }
+}
+
+/// Wake up to `count` threads blocked in [`aether_futex_wait`] on `ptr`.
+/// Returns `count`, the number of `notify_one` calls issued; `Condvar`
+/// doesn't report how many threads were actually woken, so this can be
+/// more than the number of threads that were waiting.
+#[no_mangle]
+pub unsafe extern "C" fn aether_futex_wake(ptr: *mut c_int, count: c_int) -> c_int {
+    if ptr.is_null() || count <= 0 {
+        return 0;
+    }
+
+    let queue = match FUTEX_REGISTRY.lock().unwrap().get(&(ptr as usize)).cloned() {
+        Some(queue) => queue,
+        None => return 0,
+    };
+
+    let (_lock, cvar) = &*queue;
+    for _ in 0..count {
+        cvar.notify_one();
+    }
+
+    count
 }
\ No newline at end of file
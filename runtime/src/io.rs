@@ -16,8 +16,12 @@
 
 use std::ffi::{c_char, c_int, c_void, CStr, CString};
 use std::fs::{File, OpenOptions};
-use std::io::{Read, Write};
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{TcpListener, TcpStream, UdpSocket};
+use std::path::{Path, PathBuf};
 use std::ptr;
+use std::sync::OnceLock;
+use std::time::Duration;
 
 /// File handle structure
 #[repr(C)]
@@ -148,6 +152,239 @@ pub unsafe extern "C" fn aether_write_file(handle: *mut FileHandle, data: *const
     }
 }
 
+thread_local! {
+    /// The reason the most recent `aether_io_canonicalize`,
+    /// `aether_io_open_existing_file`, or `aether_io_create_new_file` call on
+    /// this thread returned a null result, read back via
+    /// `aether_io_last_error`. Thread-local rather than a shared global so
+    /// concurrent callers on different threads don't clobber each other's
+    /// error.
+    static LAST_IO_ERROR: std::cell::RefCell<String> = const { std::cell::RefCell::new(String::new()) };
+}
+
+fn set_last_io_error(message: String) {
+    LAST_IO_ERROR.with(|cell| *cell.borrow_mut() = message);
+}
+
+/// The directory canonicalizing path operations must not escape, read once
+/// from `AETHER_IO_SANDBOX_ROOT`. Unset (the default) means no containment
+/// restriction is enforced - canonicalization still resolves `.`/`..` and
+/// symlinks, but the result is accepted wherever it lands.
+fn sandbox_root() -> Option<&'static PathBuf> {
+    static ROOT: OnceLock<Option<PathBuf>> = OnceLock::new();
+    ROOT.get_or_init(|| {
+        std::env::var("AETHER_IO_SANDBOX_ROOT")
+            .ok()
+            .and_then(|raw| std::fs::canonicalize(raw).ok())
+    })
+    .as_ref()
+}
+
+/// Canonicalize an existing path and, if a sandbox root is configured,
+/// verify the canonical result is contained in it. Resolving `.`/`..` and
+/// symlinks before the containment check (rather than pattern-matching the
+/// raw path) is what closes the TOCTOU-style traversal hole a naive prefix
+/// test would miss.
+fn canonicalize_checked(path: &Path) -> Result<PathBuf, String> {
+    let canonical = std::fs::canonicalize(path).map_err(|e| format!("not found: {e}"))?;
+    check_contained(&canonical)?;
+    Ok(canonical)
+}
+
+/// Like [`canonicalize_checked`], but for a path that may not exist yet:
+/// canonicalizes the parent directory (which must exist) and rejoins the
+/// file name, so a `../` can't be used to escape the sandbox root before
+/// the file is created either.
+fn canonicalize_new_checked(path: &Path) -> Result<PathBuf, String> {
+    let file_name = path.file_name().ok_or_else(|| "invalid path".to_string())?;
+    let parent = path.parent().filter(|p| !p.as_os_str().is_empty()).unwrap_or_else(|| Path::new("."));
+    let canonical_parent = std::fs::canonicalize(parent).map_err(|e| format!("not found: {e}"))?;
+    let canonical = canonical_parent.join(file_name);
+    check_contained(&canonical)?;
+    Ok(canonical)
+}
+
+fn check_contained(canonical: &Path) -> Result<(), String> {
+    check_contained_in(canonical, sandbox_root().map(|root| root.as_path()))
+}
+
+fn check_contained_in(canonical: &Path, root: Option<&Path>) -> Result<(), String> {
+    match root {
+        Some(root) if !canonical.starts_with(root) => Err("outside sandbox root".to_string()),
+        _ => Ok(()),
+    }
+}
+
+unsafe fn malloc_c_string(value: &str) -> *mut c_char {
+    let c_string = match CString::new(value) {
+        Ok(s) => s,
+        Err(_) => return ptr::null_mut(),
+    };
+    let bytes = c_string.as_bytes_with_nul();
+    let out = crate::memory::aether_malloc(bytes.len() as c_int) as *mut c_char;
+    if !out.is_null() {
+        ptr::copy_nonoverlapping(bytes.as_ptr() as *const c_char, out, bytes.len());
+    }
+    out
+}
+
+/// Resolve `path` to its canonical absolute form, resolving `.`/`..` and
+/// symlinks, and checking it against the configured sandbox root. Returns
+/// null (with the reason retrievable via [`aether_io_last_error`]) if the
+/// path doesn't exist or escapes the root.
+#[no_mangle]
+pub unsafe extern "C" fn aether_io_canonicalize(path: *const c_char) -> *mut c_char {
+    if path.is_null() {
+        set_last_io_error("null path".to_string());
+        return ptr::null_mut();
+    }
+    let path_str = match CStr::from_ptr(path).to_str() {
+        Ok(s) => s,
+        Err(_) => {
+            set_last_io_error("path is not valid UTF-8".to_string());
+            return ptr::null_mut();
+        }
+    };
+
+    match canonicalize_checked(Path::new(path_str)) {
+        Ok(canonical) => match canonical.to_str() {
+            Some(s) => malloc_c_string(s),
+            None => {
+                set_last_io_error("canonical path is not valid UTF-8".to_string());
+                ptr::null_mut()
+            }
+        },
+        Err(message) => {
+            set_last_io_error(message);
+            ptr::null_mut()
+        }
+    }
+}
+
+/// Open a file that must already exist, after canonicalizing its path and
+/// checking containment against the sandbox root. `mode` is `"r"`, `"w"`
+/// (truncate an existing file, unlike [`aether_open_file`]'s `"w"` this
+/// never creates one), or `"a"`.
+#[no_mangle]
+pub unsafe extern "C" fn aether_io_open_existing_file(path: *const c_char, mode: *const c_char) -> *mut FileHandle {
+    if path.is_null() || mode.is_null() {
+        set_last_io_error("null path or mode".to_string());
+        return ptr::null_mut();
+    }
+    let path_str = match CStr::from_ptr(path).to_str() {
+        Ok(s) => s,
+        Err(_) => {
+            set_last_io_error("path is not valid UTF-8".to_string());
+            return ptr::null_mut();
+        }
+    };
+    let mode_str = match CStr::from_ptr(mode).to_str() {
+        Ok(s) => s,
+        Err(_) => {
+            set_last_io_error("mode is not valid UTF-8".to_string());
+            return ptr::null_mut();
+        }
+    };
+
+    let canonical = match canonicalize_checked(Path::new(path_str)) {
+        Ok(canonical) => canonical,
+        Err(message) => {
+            set_last_io_error(message);
+            return ptr::null_mut();
+        }
+    };
+
+    let (file, mode_num) = match mode_str {
+        "r" => match File::open(&canonical) {
+            Ok(f) => (f, 0),
+            Err(e) => {
+                set_last_io_error(format!("open failed: {e}"));
+                return ptr::null_mut();
+            }
+        },
+        "w" => match OpenOptions::new().write(true).truncate(true).open(&canonical) {
+            Ok(f) => (f, 1),
+            Err(e) => {
+                set_last_io_error(format!("open failed: {e}"));
+                return ptr::null_mut();
+            }
+        },
+        "a" => match OpenOptions::new().append(true).open(&canonical) {
+            Ok(f) => (f, 2),
+            Err(e) => {
+                set_last_io_error(format!("open failed: {e}"));
+                return ptr::null_mut();
+            }
+        },
+        _ => {
+            set_last_io_error(format!("unknown mode: {mode_str}"));
+            return ptr::null_mut();
+        }
+    };
+
+    let handle = crate::memory::aether_malloc(std::mem::size_of::<FileHandle>() as c_int) as *mut FileHandle;
+    if handle.is_null() {
+        set_last_io_error("allocation failed".to_string());
+        return ptr::null_mut();
+    }
+
+    (*handle).file = Box::into_raw(Box::new(file));
+    (*handle).mode = mode_num;
+    handle
+}
+
+/// Create a new file that must not already exist, after canonicalizing its
+/// parent directory and checking containment against the sandbox root.
+#[no_mangle]
+pub unsafe extern "C" fn aether_io_create_new_file(path: *const c_char) -> *mut FileHandle {
+    if path.is_null() {
+        set_last_io_error("null path".to_string());
+        return ptr::null_mut();
+    }
+    let path_str = match CStr::from_ptr(path).to_str() {
+        Ok(s) => s,
+        Err(_) => {
+            set_last_io_error("path is not valid UTF-8".to_string());
+            return ptr::null_mut();
+        }
+    };
+
+    let canonical = match canonicalize_new_checked(Path::new(path_str)) {
+        Ok(canonical) => canonical,
+        Err(message) => {
+            set_last_io_error(message);
+            return ptr::null_mut();
+        }
+    };
+
+    let file = match OpenOptions::new().write(true).create_new(true).open(&canonical) {
+        Ok(f) => f,
+        Err(e) => {
+            set_last_io_error(format!("create failed: {e}"));
+            return ptr::null_mut();
+        }
+    };
+
+    let handle = crate::memory::aether_malloc(std::mem::size_of::<FileHandle>() as c_int) as *mut FileHandle;
+    if handle.is_null() {
+        set_last_io_error("allocation failed".to_string());
+        return ptr::null_mut();
+    }
+
+    (*handle).file = Box::into_raw(Box::new(file));
+    (*handle).mode = 1;
+    handle
+}
+
+/// The reason the most recent canonicalizing constructor on this thread
+/// returned null - e.g. `"not found: ..."` vs `"outside sandbox root"` -
+/// so callers can tell the two apart without a dedicated out-param.
+#[no_mangle]
+pub unsafe extern "C" fn aether_io_last_error() -> *mut c_char {
+    let message = LAST_IO_ERROR.with(|cell| cell.borrow().clone());
+    malloc_c_string(&message)
+}
+
 /// Allocate a string buffer
 #[no_mangle]
 pub unsafe extern "C" fn aether_allocate_string(size: c_int) -> *mut c_char {
@@ -258,4 +495,644 @@ pub unsafe extern "C" fn aether_list_directory(path: *const c_char, entries: *mu
         },
         Err(_) => -1,
     }
-}
\ No newline at end of file
+}
+
+/// A single directory entry, `#[repr(C)]` so it can be handed back to
+/// Aether as a plain struct. `name` is malloc'd (free with
+/// [`crate::memory::aether_free`]); for [`aether_dir_walk`] it holds the
+/// path relative to the walk root rather than a bare file name.
+#[repr(C)]
+pub struct DirEntryC {
+    name: *mut c_char,
+    is_dir: u8,
+    is_file: u8,
+    is_symlink: u8,
+    size: i64,
+    modified: i64,
+}
+
+/// One level of an in-progress [`aether_dir_walk`], pairing a directory's
+/// iterator with the path (relative to the walk root) it was opened at.
+struct WalkFrame {
+    iter: std::fs::ReadDir,
+    prefix: std::path::PathBuf,
+}
+
+enum DirIter {
+    Flat(std::fs::ReadDir),
+    Walk { recursive: bool, stack: Vec<WalkFrame> },
+}
+
+/// Directory iteration handle, modeled on [`FileHandle`]: a boxed
+/// [`DirIter`] allocated through [`crate::memory::aether_malloc`].
+#[repr(C)]
+pub struct DirHandle {
+    iter: *mut DirIter,
+}
+
+unsafe fn alloc_dir_handle(iter: DirIter) -> *mut DirHandle {
+    let handle = crate::memory::aether_malloc(std::mem::size_of::<DirHandle>() as c_int) as *mut DirHandle;
+    if handle.is_null() {
+        return ptr::null_mut();
+    }
+
+    (*handle).iter = Box::into_raw(Box::new(iter));
+    handle
+}
+
+/// Fill `entry_out` from `metadata`/`name`, malloc'ing the name string.
+/// Returns 1 on success, -1 if the name isn't valid UTF-8 or allocation
+/// fails.
+unsafe fn fill_dir_entry(metadata: &std::fs::Metadata, name: &std::path::Path, entry_out: *mut DirEntryC) -> c_int {
+    let name_str = match name.to_str() {
+        Some(s) => s,
+        None => return -1,
+    };
+    let c_string = match CString::new(name_str) {
+        Ok(s) => s,
+        Err(_) => return -1,
+    };
+
+    let bytes = c_string.as_bytes_with_nul();
+    let name_ptr = crate::memory::aether_malloc(bytes.len() as c_int) as *mut c_char;
+    if name_ptr.is_null() {
+        return -1;
+    }
+    ptr::copy_nonoverlapping(bytes.as_ptr() as *const c_char, name_ptr, bytes.len());
+
+    let file_type = metadata.file_type();
+    let modified = metadata.modified().ok()
+        .and_then(|t| t.duration_since(std::time::UNIX_EPOCH).ok())
+        .map(|d| d.as_secs() as i64)
+        .unwrap_or(0);
+
+    (*entry_out).name = name_ptr;
+    (*entry_out).is_dir = file_type.is_dir() as u8;
+    (*entry_out).is_file = file_type.is_file() as u8;
+    (*entry_out).is_symlink = file_type.is_symlink() as u8;
+    (*entry_out).size = metadata.len() as i64;
+    (*entry_out).modified = modified;
+
+    1
+}
+
+/// Open a directory for streaming iteration via [`aether_dir_next`],
+/// without the 1000-entry cap [`aether_list_directory`] imposes.
+#[no_mangle]
+pub unsafe extern "C" fn aether_dir_open(path: *const c_char) -> *mut DirHandle {
+    if path.is_null() {
+        return ptr::null_mut();
+    }
+
+    let path_str = match CStr::from_ptr(path).to_str() {
+        Ok(s) => s,
+        Err(_) => return ptr::null_mut(),
+    };
+
+    match std::fs::read_dir(path_str) {
+        Ok(read_dir) => alloc_dir_handle(DirIter::Flat(read_dir)),
+        Err(_) => ptr::null_mut(),
+    }
+}
+
+/// Open a directory for recursive (depth-first, pre-order) traversal via
+/// [`aether_dir_next`]. Each yielded entry's name is its path relative to
+/// `path`. When `recursive` is 0, this behaves like [`aether_dir_open`]
+/// except for that relative naming.
+#[no_mangle]
+pub unsafe extern "C" fn aether_dir_walk(path: *const c_char, recursive: c_int) -> *mut DirHandle {
+    if path.is_null() {
+        return ptr::null_mut();
+    }
+
+    let path_str = match CStr::from_ptr(path).to_str() {
+        Ok(s) => s,
+        Err(_) => return ptr::null_mut(),
+    };
+
+    match std::fs::read_dir(path_str) {
+        Ok(read_dir) => alloc_dir_handle(DirIter::Walk {
+            recursive: recursive != 0,
+            stack: vec![WalkFrame { iter: read_dir, prefix: std::path::PathBuf::new() }],
+        }),
+        Err(_) => ptr::null_mut(),
+    }
+}
+
+/// Produce the next directory entry into `entry_out`. Returns 1 when an
+/// entry was produced, 0 at the end of the iteration, -1 on error.
+#[no_mangle]
+pub unsafe extern "C" fn aether_dir_next(handle: *mut DirHandle, entry_out: *mut DirEntryC) -> c_int {
+    if handle.is_null() || (*handle).iter.is_null() || entry_out.is_null() {
+        return -1;
+    }
+
+    match &mut *(*handle).iter {
+        DirIter::Flat(read_dir) => match read_dir.next() {
+            None => 0,
+            Some(Err(_)) => -1,
+            Some(Ok(entry)) => match entry.metadata() {
+                Ok(metadata) => fill_dir_entry(&metadata, &std::path::PathBuf::from(entry.file_name()), entry_out),
+                Err(_) => -1,
+            },
+        },
+        DirIter::Walk { recursive, stack } => loop {
+            let frame = match stack.last_mut() {
+                Some(frame) => frame,
+                None => return 0,
+            };
+
+            match frame.iter.next() {
+                None => {
+                    stack.pop();
+                    continue;
+                },
+                Some(Err(_)) => return -1,
+                Some(Ok(entry)) => {
+                    let metadata = match entry.metadata() {
+                        Ok(metadata) => metadata,
+                        Err(_) => return -1,
+                    };
+                    let relative_path = frame.prefix.join(entry.file_name());
+
+                    if *recursive && metadata.is_dir() {
+                        if let Ok(sub_dir) = std::fs::read_dir(entry.path()) {
+                            stack.push(WalkFrame { iter: sub_dir, prefix: relative_path.clone() });
+                        }
+                    }
+
+                    return fill_dir_entry(&metadata, &relative_path, entry_out);
+                },
+            }
+        },
+    }
+}
+
+/// Close a directory handle opened by [`aether_dir_open`] or
+/// [`aether_dir_walk`]
+#[no_mangle]
+pub unsafe extern "C" fn aether_dir_close(handle: *mut DirHandle) {
+    if handle.is_null() {
+        return;
+    }
+
+    if !(*handle).iter.is_null() {
+        let _ = Box::from_raw((*handle).iter);
+    }
+
+    crate::memory::aether_free(handle as *mut c_void);
+}
+
+/// Fill buffer capacity for [`BufferedReader`], matching `BufReader`'s own
+/// default
+const BUFFERED_READER_CAPACITY: usize = 8 * 1024;
+
+/// Buffered file reader handle, wrapping a boxed `BufReader<File>` so
+/// line- and delimiter-oriented reads don't issue one syscall per call,
+/// the way [`aether_read_file`] does.
+#[repr(C)]
+pub struct BufferedReader {
+    reader: *mut BufReader<File>,
+}
+
+/// Open a file for buffered reading
+#[no_mangle]
+pub unsafe extern "C" fn aether_buffered_open(path: *const c_char) -> *mut BufferedReader {
+    if path.is_null() {
+        return ptr::null_mut();
+    }
+
+    let path_str = match CStr::from_ptr(path).to_str() {
+        Ok(s) => s,
+        Err(_) => return ptr::null_mut(),
+    };
+
+    let file = match File::open(path_str) {
+        Ok(f) => f,
+        Err(_) => return ptr::null_mut(),
+    };
+
+    let handle = crate::memory::aether_malloc(std::mem::size_of::<BufferedReader>() as c_int) as *mut BufferedReader;
+    if handle.is_null() {
+        return ptr::null_mut();
+    }
+
+    let reader = BufReader::with_capacity(BUFFERED_READER_CAPACITY, file);
+    (*handle).reader = Box::into_raw(Box::new(reader));
+
+    handle
+}
+
+/// Close a buffered reader
+#[no_mangle]
+pub unsafe extern "C" fn aether_buffered_close(handle: *mut BufferedReader) {
+    if handle.is_null() {
+        return;
+    }
+
+    if !(*handle).reader.is_null() {
+        let _ = Box::from_raw((*handle).reader);
+    }
+
+    crate::memory::aether_free(handle as *mut c_void);
+}
+
+/// Read up to and including `delimiter` into `buffer`, scanning and
+/// refilling the internal buffer as needed so delimiters spanning a
+/// refill boundary are still found. Returns the number of bytes written
+/// (truncated to `max_size`), or -1 at EOF / on error.
+#[no_mangle]
+pub unsafe extern "C" fn aether_buffered_read_until(
+    handle: *mut BufferedReader,
+    delimiter: c_char,
+    buffer: *mut c_char,
+    max_size: c_int,
+) -> c_int {
+    if handle.is_null() || (*handle).reader.is_null() || buffer.is_null() || max_size <= 0 {
+        return -1;
+    }
+
+    let reader = &mut *(*handle).reader;
+    let mut data = Vec::new();
+
+    match reader.read_until(delimiter as u8, &mut data) {
+        Ok(0) => -1, // EOF, nothing read
+        Ok(bytes_read) => {
+            let copy_len = std::cmp::min(bytes_read, max_size as usize);
+            ptr::copy_nonoverlapping(data.as_ptr(), buffer as *mut u8, copy_len);
+            copy_len as c_int
+        },
+        Err(_) => -1,
+    }
+}
+
+/// Read a line (including the trailing `\n` if present) into `buffer`.
+/// Returns the number of bytes written, or -1 at EOF / on error.
+#[no_mangle]
+pub unsafe extern "C" fn aether_buffered_read_line(handle: *mut BufferedReader, buffer: *mut c_char, max_size: c_int) -> c_int {
+    aether_buffered_read_until(handle, b'\n' as c_char, buffer, max_size)
+}
+
+/// Return the next byte without consuming it, or -1 at EOF / on error
+#[no_mangle]
+pub unsafe extern "C" fn aether_buffered_peek(handle: *mut BufferedReader) -> c_int {
+    if handle.is_null() || (*handle).reader.is_null() {
+        return -1;
+    }
+
+    let reader = &mut *(*handle).reader;
+    match reader.fill_buf() {
+        Ok(buf) if !buf.is_empty() => buf[0] as c_int,
+        Ok(_) => -1, // EOF
+        Err(_) => -1,
+    }
+}
+
+/// Which kind of socket [`SocketHandle::ptr`] points at
+const SOCKET_KIND_TCP_STREAM: i32 = 0;
+const SOCKET_KIND_TCP_LISTENER: i32 = 1;
+const SOCKET_KIND_UDP: i32 = 2;
+
+/// Socket handle structure, modeled on [`FileHandle`]: a tagged, boxed
+/// `TcpStream`/`TcpListener`/`UdpSocket` allocated through
+/// [`crate::memory::aether_malloc`].
+#[repr(C)]
+pub struct SocketHandle {
+    kind: i32,
+    ptr: *mut c_void,
+}
+
+unsafe fn host_str<'a>(host: *const c_char) -> Option<&'a str> {
+    if host.is_null() {
+        return None;
+    }
+    CStr::from_ptr(host).to_str().ok()
+}
+
+unsafe fn alloc_socket_handle(kind: i32, ptr: *mut c_void) -> *mut SocketHandle {
+    let handle = crate::memory::aether_malloc(std::mem::size_of::<SocketHandle>() as c_int) as *mut SocketHandle;
+    if handle.is_null() {
+        return ptr::null_mut();
+    }
+
+    (*handle).kind = kind;
+    (*handle).ptr = ptr;
+    handle
+}
+
+/// Connect to a TCP server
+#[no_mangle]
+pub unsafe extern "C" fn aether_tcp_connect(host: *const c_char, port: c_int) -> *mut SocketHandle {
+    let host_str = match host_str(host) {
+        Some(s) => s,
+        None => return ptr::null_mut(),
+    };
+
+    match TcpStream::connect((host_str, port as u16)) {
+        Ok(stream) => alloc_socket_handle(SOCKET_KIND_TCP_STREAM, Box::into_raw(Box::new(stream)) as *mut c_void),
+        Err(_) => ptr::null_mut(),
+    }
+}
+
+/// Start listening for TCP connections
+#[no_mangle]
+pub unsafe extern "C" fn aether_tcp_listen(host: *const c_char, port: c_int, _backlog: c_int) -> *mut SocketHandle {
+    let host_str = match host_str(host) {
+        Some(s) => s,
+        None => return ptr::null_mut(),
+    };
+
+    // The standard library doesn't expose a way to configure the listen
+    // backlog, so `_backlog` is accepted for API compatibility and
+    // otherwise unused.
+    match TcpListener::bind((host_str, port as u16)) {
+        Ok(listener) => alloc_socket_handle(SOCKET_KIND_TCP_LISTENER, Box::into_raw(Box::new(listener)) as *mut c_void),
+        Err(_) => ptr::null_mut(),
+    }
+}
+
+/// Accept an incoming TCP connection
+#[no_mangle]
+pub unsafe extern "C" fn aether_tcp_accept(listener: *mut SocketHandle) -> *mut SocketHandle {
+    if listener.is_null() || (*listener).kind != SOCKET_KIND_TCP_LISTENER || (*listener).ptr.is_null() {
+        return ptr::null_mut();
+    }
+
+    let tcp_listener = &*((*listener).ptr as *const TcpListener);
+    match tcp_listener.accept() {
+        Ok((stream, _addr)) => alloc_socket_handle(SOCKET_KIND_TCP_STREAM, Box::into_raw(Box::new(stream)) as *mut c_void),
+        Err(_) => ptr::null_mut(),
+    }
+}
+
+/// Send data on a connected TCP socket
+#[no_mangle]
+pub unsafe extern "C" fn aether_socket_send(sock: *mut SocketHandle, data: *const c_char, size: c_int) -> c_int {
+    if sock.is_null() || (*sock).kind != SOCKET_KIND_TCP_STREAM || (*sock).ptr.is_null() || data.is_null() || size <= 0 {
+        return -1;
+    }
+
+    let stream = &mut *((*sock).ptr as *mut TcpStream);
+    let slice = std::slice::from_raw_parts(data as *const u8, size as usize);
+
+    match stream.write(slice) {
+        Ok(bytes_written) => bytes_written as c_int,
+        Err(_) => -1,
+    }
+}
+
+/// Receive data from a connected TCP socket
+#[no_mangle]
+pub unsafe extern "C" fn aether_socket_recv(sock: *mut SocketHandle, buffer: *mut c_char, size: c_int) -> c_int {
+    if sock.is_null() || (*sock).kind != SOCKET_KIND_TCP_STREAM || (*sock).ptr.is_null() || buffer.is_null() || size <= 0 {
+        return -1;
+    }
+
+    let stream = &mut *((*sock).ptr as *mut TcpStream);
+    let mut vec = vec![0u8; size as usize];
+
+    match stream.read(&mut vec) {
+        Ok(bytes_read) => {
+            ptr::copy_nonoverlapping(vec.as_ptr(), buffer as *mut u8, bytes_read);
+            bytes_read as c_int
+        },
+        Err(_) => -1,
+    }
+}
+
+/// Bind a UDP socket
+#[no_mangle]
+pub unsafe extern "C" fn aether_udp_bind(host: *const c_char, port: c_int) -> *mut SocketHandle {
+    let host_str = match host_str(host) {
+        Some(s) => s,
+        None => return ptr::null_mut(),
+    };
+
+    match UdpSocket::bind((host_str, port as u16)) {
+        Ok(socket) => alloc_socket_handle(SOCKET_KIND_UDP, Box::into_raw(Box::new(socket)) as *mut c_void),
+        Err(_) => ptr::null_mut(),
+    }
+}
+
+/// Send a UDP datagram to the given host/port
+#[no_mangle]
+pub unsafe extern "C" fn aether_udp_send_to(
+    sock: *mut SocketHandle,
+    data: *const c_char,
+    size: c_int,
+    host: *const c_char,
+    port: c_int,
+) -> c_int {
+    if sock.is_null() || (*sock).kind != SOCKET_KIND_UDP || (*sock).ptr.is_null() || data.is_null() || size <= 0 {
+        return -1;
+    }
+
+    let dest = match host_str(host) {
+        Some(s) => s,
+        None => return -1,
+    };
+
+    let socket = &*((*sock).ptr as *const UdpSocket);
+    let slice = std::slice::from_raw_parts(data as *const u8, size as usize);
+
+    match socket.send_to(slice, (dest, port as u16)) {
+        Ok(bytes_sent) => bytes_sent as c_int,
+        Err(_) => -1,
+    }
+}
+
+/// Receive a UDP datagram, writing the sender's address into `out_host`
+/// and its port into `out_port`
+#[no_mangle]
+pub unsafe extern "C" fn aether_udp_recv_from(
+    sock: *mut SocketHandle,
+    buffer: *mut c_char,
+    size: c_int,
+    out_host: *mut c_char,
+    out_host_size: c_int,
+    out_port: *mut c_int,
+) -> c_int {
+    if sock.is_null() || (*sock).kind != SOCKET_KIND_UDP || (*sock).ptr.is_null() || buffer.is_null() || size <= 0 {
+        return -1;
+    }
+
+    let socket = &*((*sock).ptr as *const UdpSocket);
+    let mut vec = vec![0u8; size as usize];
+
+    match socket.recv_from(&mut vec) {
+        Ok((bytes_read, from)) => {
+            ptr::copy_nonoverlapping(vec.as_ptr(), buffer as *mut u8, bytes_read);
+
+            if !out_host.is_null() && out_host_size > 0 {
+                let addr_str = format!("{}\0", from.ip());
+                let len = std::cmp::min(addr_str.len(), out_host_size as usize);
+                ptr::copy_nonoverlapping(addr_str.as_ptr() as *const c_char, out_host, len);
+            }
+
+            if !out_port.is_null() {
+                *out_port = from.port() as c_int;
+            }
+
+            bytes_read as c_int
+        },
+        Err(_) => -1,
+    }
+}
+
+/// Set read/write timeouts (in milliseconds) on a socket; a value <= 0
+/// clears that timeout
+#[no_mangle]
+pub unsafe extern "C" fn aether_socket_set_timeout(sock: *mut SocketHandle, read_ms: c_int, write_ms: c_int) -> c_int {
+    if sock.is_null() || (*sock).ptr.is_null() {
+        return -1;
+    }
+
+    let read_timeout = if read_ms > 0 { Some(Duration::from_millis(read_ms as u64)) } else { None };
+    let write_timeout = if write_ms > 0 { Some(Duration::from_millis(write_ms as u64)) } else { None };
+
+    let result = match (*sock).kind {
+        SOCKET_KIND_TCP_STREAM => {
+            let stream = &*((*sock).ptr as *const TcpStream);
+            stream.set_read_timeout(read_timeout).and_then(|_| stream.set_write_timeout(write_timeout))
+        },
+        SOCKET_KIND_UDP => {
+            let socket = &*((*sock).ptr as *const UdpSocket);
+            socket.set_read_timeout(read_timeout).and_then(|_| socket.set_write_timeout(write_timeout))
+        },
+        _ => return -1,
+    };
+
+    if result.is_ok() { 0 } else { -1 }
+}
+
+/// Close a socket, freeing the boxed stream/listener it wraps
+#[no_mangle]
+pub unsafe extern "C" fn aether_socket_close(sock: *mut SocketHandle) {
+    if sock.is_null() {
+        return;
+    }
+
+    if !(*sock).ptr.is_null() {
+        match (*sock).kind {
+            SOCKET_KIND_TCP_STREAM => {
+                let _ = Box::from_raw((*sock).ptr as *mut TcpStream);
+            },
+            SOCKET_KIND_TCP_LISTENER => {
+                let _ = Box::from_raw((*sock).ptr as *mut TcpListener);
+            },
+            SOCKET_KIND_UDP => {
+                let _ = Box::from_raw((*sock).ptr as *mut UdpSocket);
+            },
+            _ => {},
+        }
+    }
+
+    crate::memory::aether_free(sock as *mut c_void);
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn to_cstring(s: &str) -> CString {
+        CString::new(s).unwrap()
+    }
+
+    #[test]
+    fn canonicalize_resolves_dot_dot_within_an_unconfigured_root() {
+        unsafe {
+            let dir = std::env::temp_dir().join(format!("aether_io_canon_{}_{}", std::process::id(), 1));
+            std::fs::create_dir_all(&dir).unwrap();
+            std::fs::write(dir.join("file.txt"), "hi").unwrap();
+
+            let traversal = dir.join("../").join(dir.file_name().unwrap()).join("file.txt");
+            let result = aether_io_canonicalize(to_cstring(traversal.to_str().unwrap()).as_ptr());
+            assert!(!result.is_null());
+
+            let resolved = CStr::from_ptr(result).to_str().unwrap().to_string();
+            assert_eq!(std::path::PathBuf::from(resolved), dir.join("file.txt").canonicalize().unwrap());
+
+            crate::memory::aether_free(result as *mut c_void);
+            std::fs::remove_dir_all(&dir).ok();
+        }
+    }
+
+    #[test]
+    fn canonicalize_reports_not_found_via_last_error() {
+        unsafe {
+            let missing = std::env::temp_dir().join(format!("aether_io_missing_{}", std::process::id()));
+            let result = aether_io_canonicalize(to_cstring(missing.to_str().unwrap()).as_ptr());
+            assert!(result.is_null());
+
+            let error = aether_io_last_error();
+            assert!(!error.is_null());
+            let message = CStr::from_ptr(error).to_str().unwrap();
+            assert!(message.starts_with("not found"), "unexpected error: {message}");
+            crate::memory::aether_free(error as *mut c_void);
+        }
+    }
+
+    #[test]
+    fn open_existing_file_reads_back_what_create_new_file_wrote() {
+        unsafe {
+            let dir = std::env::temp_dir().join(format!("aether_io_roundtrip_{}", std::process::id()));
+            std::fs::create_dir_all(&dir).unwrap();
+            let path = dir.join("created.txt");
+
+            let create_handle = aether_io_create_new_file(to_cstring(path.to_str().unwrap()).as_ptr());
+            assert!(!create_handle.is_null());
+            let data = to_cstring("hello from create_new_file");
+            let written = aether_write_file(create_handle, data.as_ptr(), data.as_bytes().len() as c_int);
+            assert_eq!(written as usize, data.as_bytes().len());
+            aether_close_file(create_handle);
+
+            // Creating it again should fail - it already exists.
+            let recreate = aether_io_create_new_file(to_cstring(path.to_str().unwrap()).as_ptr());
+            assert!(recreate.is_null());
+
+            let read_handle = aether_io_open_existing_file(to_cstring(path.to_str().unwrap()).as_ptr(), to_cstring("r").as_ptr());
+            assert!(!read_handle.is_null());
+            let mut buffer = vec![0u8; 64];
+            let read = aether_read_file(read_handle, buffer.as_mut_ptr() as *mut c_char, buffer.len() as c_int);
+            assert_eq!(&buffer[..read as usize], b"hello from create_new_file");
+            aether_close_file(read_handle);
+
+            std::fs::remove_dir_all(&dir).ok();
+        }
+    }
+
+    #[test]
+    fn open_existing_file_rejects_a_path_that_does_not_exist() {
+        unsafe {
+            let missing = std::env::temp_dir().join(format!("aether_io_open_missing_{}", std::process::id()));
+            let handle = aether_io_open_existing_file(to_cstring(missing.to_str().unwrap()).as_ptr(), to_cstring("r").as_ptr());
+            assert!(handle.is_null());
+        }
+    }
+
+    #[test]
+    fn sandbox_root_rejects_a_traversal_that_escapes_it() {
+        // `sandbox_root()` memoizes `AETHER_IO_SANDBOX_ROOT` on first read via
+        // `OnceLock`, so this test exercises the containment-check logic
+        // (`check_contained_in`) directly with an explicit root rather than
+        // the env var (which a prior test in this binary may have already
+        // observed as unset).
+        let root = std::env::temp_dir().join(format!("aether_io_sandbox_{}", std::process::id()));
+        let outside = std::env::temp_dir().join(format!("aether_io_sandbox_outside_{}", std::process::id()));
+        std::fs::create_dir_all(&root).unwrap();
+        std::fs::create_dir_all(&outside).unwrap();
+        std::fs::write(outside.join("secret.txt"), "top secret").unwrap();
+
+        let canonical_root = root.canonicalize().unwrap();
+        let traversal = root.join("..").join(outside.file_name().unwrap()).join("secret.txt");
+        let canonical_escape = traversal.canonicalize().unwrap();
+        assert!(!canonical_escape.starts_with(&canonical_root), "test fixture should actually be outside the root");
+        assert_eq!(
+            check_contained_in(&canonical_escape, Some(canonical_root.as_path())),
+            Err("outside sandbox root".to_string())
+        );
+        assert!(check_contained_in(&canonical_root.join("inside.txt"), Some(canonical_root.as_path())).is_ok());
+
+        std::fs::remove_dir_all(&root).ok();
+        std::fs::remove_dir_all(&outside).ok();
+    }
+}
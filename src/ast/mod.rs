@@ -210,22 +210,71 @@ pub enum PrimitiveType {
     Void,
     SizeT,
     UIntPtrT,
+    I8,
+    I16,
+    U8,
+    U16,
+    U32,
+    U64,
 }
 
 impl PrimitiveType {
     /// Check if this is a numeric type
     pub fn is_numeric(&self) -> bool {
-        matches!(self, 
-            PrimitiveType::Integer | 
-            PrimitiveType::Integer32 | 
+        matches!(self,
+            PrimitiveType::Integer |
+            PrimitiveType::Integer32 |
             PrimitiveType::Integer64 |
             PrimitiveType::Float |
             PrimitiveType::Float32 |
             PrimitiveType::Float64 |
             PrimitiveType::SizeT |
-            PrimitiveType::UIntPtrT
+            PrimitiveType::UIntPtrT |
+            PrimitiveType::I8 |
+            PrimitiveType::I16 |
+            PrimitiveType::U8 |
+            PrimitiveType::U16 |
+            PrimitiveType::U32 |
+            PrimitiveType::U64
         )
     }
+
+    /// Look up the sized integer type matching a literal's `bits`/`signed`
+    /// suffix (e.g. `(8, false)` for `u8`), reusing [`PrimitiveType::Integer32`]
+    /// for the `i32` case and [`PrimitiveType::Integer`] for `i64`/unsuffixed
+    /// (both already mean "64-bit signed" throughout this module) rather than
+    /// introducing redundant variants for them.
+    pub fn sized_integer(bits: u32, signed: bool) -> Option<PrimitiveType> {
+        match (bits, signed) {
+            (8, true) => Some(PrimitiveType::I8),
+            (8, false) => Some(PrimitiveType::U8),
+            (16, true) => Some(PrimitiveType::I16),
+            (16, false) => Some(PrimitiveType::U16),
+            (32, true) => Some(PrimitiveType::Integer32),
+            (32, false) => Some(PrimitiveType::U32),
+            (64, true) => Some(PrimitiveType::Integer),
+            (64, false) => Some(PrimitiveType::U64),
+            _ => None,
+        }
+    }
+
+    /// The inclusive `[min, max]` range representable by this type, for
+    /// integer types only (`None` for float/non-numeric types).
+    pub fn integer_range(&self) -> Option<(i128, i128)> {
+        match self {
+            PrimitiveType::I8 => Some((i8::MIN as i128, i8::MAX as i128)),
+            PrimitiveType::U8 => Some((u8::MIN as i128, u8::MAX as i128)),
+            PrimitiveType::I16 => Some((i16::MIN as i128, i16::MAX as i128)),
+            PrimitiveType::U16 => Some((u16::MIN as i128, u16::MAX as i128)),
+            PrimitiveType::Integer32 => Some((i32::MIN as i128, i32::MAX as i128)),
+            PrimitiveType::U32 => Some((u32::MIN as i128, u32::MAX as i128)),
+            PrimitiveType::Integer | PrimitiveType::Integer64 => {
+                Some((i64::MIN as i128, i64::MAX as i128))
+            }
+            PrimitiveType::U64 => Some((u64::MIN as i128, u64::MAX as i128)),
+            _ => None,
+        }
+    }
 }
 
 impl std::fmt::Display for PrimitiveType {
@@ -243,6 +292,12 @@ impl std::fmt::Display for PrimitiveType {
             PrimitiveType::Void => write!(f, "VOID"),
             PrimitiveType::SizeT => write!(f, "SIZE_T"),
             PrimitiveType::UIntPtrT => write!(f, "UINTPTR_T"),
+            PrimitiveType::I8 => write!(f, "I8"),
+            PrimitiveType::I16 => write!(f, "I16"),
+            PrimitiveType::U8 => write!(f, "U8"),
+            PrimitiveType::U16 => write!(f, "U16"),
+            PrimitiveType::U32 => write!(f, "U32"),
+            PrimitiveType::U64 => write!(f, "U64"),
         }
     }
 }
@@ -311,6 +366,10 @@ pub struct FunctionMetadata {
     pub throws_exceptions: Vec<Box<TypeSpecifier>>,
     pub thread_safe: Option<bool>,
     pub may_block: Option<bool>,
+    /// Whether this function is a test function, declared with a `(TEST)`
+    /// field, e.g. `(DEFINE_FUNCTION (NAME my_test) (TEST TRUE) ...)`.
+    /// Discovered via [`crate::test_harness::find_test_functions`].
+    pub is_test: bool,
 }
 
 /// Contract assertion (precondition, postcondition, invariant)
@@ -585,10 +644,18 @@ pub enum Expression {
     // Literals
     IntegerLiteral {
         value: i64,
+        /// Width of the literal's suffix (`i8`/`u8`/.../`i64`/`u64`), or 64
+        /// for an unsuffixed literal.
+        bits: u32,
+        /// `false` for a `u`-suffixed literal, `true` otherwise.
+        signed: bool,
         source_location: SourceLocation,
     },
     FloatLiteral {
         value: f64,
+        /// Width of the literal's suffix (`f32`/`f64`), or 64 for an
+        /// unsuffixed literal.
+        bits: u32,
         source_location: SourceLocation,
     },
     StringLiteral {
@@ -1220,6 +1287,8 @@ mod tests {
     fn test_expression_serialization() {
         let loc = SourceLocation::new("test.aether".to_string(), 1, 1, 0);
         let expr = Expression::IntegerLiteral {
+            bits: 64,
+            signed: true,
             value: 42,
             source_location: loc,
         };
@@ -0,0 +1,261 @@
+// Copyright 2025 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! AST pretty-printer, emitting the same S-expression surface syntax the
+//! [`crate::parser`] consumes. Intended primarily for parse -> print ->
+//! reparse round-trip testing (see `tests/property_based_tests.rs`), not as
+//! a full source formatter - it covers the subset of the grammar the parser
+//! can itself produce from a literal expression or a primitive/named type,
+//! not the full `Statement`/`Expression` surface.
+
+use crate::ast::{ConstantDeclaration, Expression, Module, PrimitiveType, Program, TypeSpecifier};
+
+/// Pretty-prints AST nodes back into AetherScript source text.
+///
+/// Field order within a construct (e.g. `NAME` before `TYPE` before `VALUE`
+/// in a `DECLARE_CONSTANT`) is fixed by this printer for readability, but is
+/// not significant to the parser, which matches fields by keyword.
+pub struct Printer {
+    indent_width: usize,
+}
+
+impl Printer {
+    /// Create a printer that indents nested forms by `indent_width` spaces.
+    pub fn new(indent_width: usize) -> Self {
+        Self { indent_width }
+    }
+
+    /// Print a whole program as a sequence of top-level modules.
+    pub fn print_program(&self, program: &Program) -> String {
+        program
+            .modules
+            .iter()
+            .map(|module| self.print_module(module))
+            .collect::<Vec<_>>()
+            .join("\n\n")
+    }
+
+    /// Print a single `(DEFINE_MODULE ...)` form.
+    pub fn print_module(&self, module: &Module) -> String {
+        assert!(
+            module.imports.is_empty()
+                && module.exports.is_empty()
+                && module.type_definitions.is_empty()
+                && module.function_definitions.is_empty()
+                && module.external_functions.is_empty(),
+            "Printer::print_module: module {} has content other than constant declarations",
+            module.name.name
+        );
+        let mut lines = Vec::new();
+        lines.push("(DEFINE_MODULE".to_string());
+        lines.push(self.indent(1, &format!("(NAME {})", module.name.name)));
+        if let Some(intent) = &module.intent {
+            lines.push(self.indent(1, &format!("(INTENT {})", quote_string(intent))));
+        }
+
+        lines.push(self.indent(1, "(CONTENT"));
+        for constant in &module.constant_declarations {
+            lines.push(self.indent(2, &self.print_constant_declaration(constant)));
+        }
+        lines.push(self.indent(1, ")"));
+        lines.push(")".to_string());
+        lines.join("\n")
+    }
+
+    /// Print a single `(DECLARE_CONSTANT ...)` form.
+    pub fn print_constant_declaration(&self, constant: &ConstantDeclaration) -> String {
+        let mut parts = vec![
+            "(DECLARE_CONSTANT".to_string(),
+            format!("(NAME {})", constant.name.name),
+            format!("(TYPE {})", self.print_type_specifier(&constant.type_spec)),
+            format!("(VALUE {})", self.print_expression(&constant.value)),
+        ];
+        if let Some(intent) = &constant.intent {
+            parts.push(format!("(INTENT {})", quote_string(intent)));
+        }
+        parts.push(")".to_string());
+        parts.join(" ")
+    }
+
+    /// Print a type specifier, covering the subset the parser can itself
+    /// construct directly (primitive keywords and bare names).
+    pub fn print_type_specifier(&self, type_spec: &TypeSpecifier) -> String {
+        match type_spec {
+            TypeSpecifier::Primitive { type_name, .. } => print_primitive_type(*type_name).to_string(),
+            TypeSpecifier::Named { name, .. } => name.name.clone(),
+            other => panic!("Printer::print_type_specifier: unsupported type specifier {other:?}"),
+        }
+    }
+
+    /// Print an expression, covering literal and variable-reference forms.
+    pub fn print_expression(&self, expression: &Expression) -> String {
+        match expression {
+            Expression::IntegerLiteral { value, bits, signed, .. } => {
+                format!("{value}{}", integer_suffix(*bits, *signed))
+            }
+            Expression::FloatLiteral { value, bits, .. } => {
+                format!("{}{}", format_float(*value), float_suffix(*bits))
+            }
+            Expression::StringLiteral { value, .. } => quote_string(value),
+            Expression::CharacterLiteral { value, .. } => format!("'{value}'"),
+            Expression::BooleanLiteral { value, .. } => {
+                if *value { "TRUE".to_string() } else { "FALSE".to_string() }
+            }
+            Expression::Variable { name, .. } => name.name.clone(),
+            other => panic!("Printer::print_expression: unsupported expression {other:?}"),
+        }
+    }
+
+    fn indent(&self, depth: usize, text: &str) -> String {
+        format!("{}{}", " ".repeat(self.indent_width * depth), text)
+    }
+}
+
+impl Default for Printer {
+    fn default() -> Self {
+        Self::new(2)
+    }
+}
+
+fn print_primitive_type(type_name: PrimitiveType) -> &'static str {
+    match type_name {
+        PrimitiveType::Integer => "INTEGER",
+        PrimitiveType::Float => "FLOAT",
+        PrimitiveType::String => "STRING",
+        PrimitiveType::Char => "CHAR",
+        PrimitiveType::Boolean => "BOOLEAN",
+        PrimitiveType::Void => "VOID",
+        other => panic!("Printer::print_type_specifier: {other:?} has no source-level keyword"),
+    }
+}
+
+/// `i{bits}`/`u{bits}` suffix, omitted when it matches the unsuffixed
+/// literal default (`bits: 64, signed: true`).
+fn integer_suffix(bits: u32, signed: bool) -> String {
+    if bits == 64 && signed {
+        String::new()
+    } else if signed {
+        format!("i{bits}")
+    } else {
+        format!("u{bits}")
+    }
+}
+
+/// `f{bits}` suffix, omitted when it matches the unsuffixed literal default
+/// (`bits: 64`).
+fn float_suffix(bits: u32) -> String {
+    if bits == 64 {
+        String::new()
+    } else {
+        format!("f{bits}")
+    }
+}
+
+/// Format a float literal so it always contains a `.` (round-tripping
+/// through the lexer's float grammar) even for whole-number values.
+fn format_float(value: f64) -> String {
+    if value.fract() == 0.0 && value.is_finite() {
+        format!("{value:.1}")
+    } else {
+        value.to_string()
+    }
+}
+
+/// Quote and escape a string value back into source-level `"..."` syntax.
+fn quote_string(value: &str) -> String {
+    let mut quoted = String::with_capacity(value.len() + 2);
+    quoted.push('"');
+    for ch in value.chars() {
+        match ch {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\t' => quoted.push_str("\\t"),
+            '\r' => quoted.push_str("\\r"),
+            '$' => quoted.push_str("\\$"),
+            other => quoted.push(other),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::SourceLocation;
+    use crate::lexer::Lexer;
+    use crate::parser::Parser;
+
+    fn parse_module(source: &str) -> Module {
+        let mut lexer = Lexer::new(source, "test.aether".to_string());
+        let tokens = lexer.tokenize().expect("source should lex");
+        let mut parser = Parser::new(tokens);
+        parser.parse_module().expect("source should parse")
+    }
+
+    #[test]
+    fn round_trips_a_simple_constant_module() {
+        let source = r#"(DEFINE_MODULE (NAME example) (CONTENT (DECLARE_CONSTANT (NAME answer) (TYPE INTEGER) (VALUE 42))))"#;
+        let module = parse_module(source);
+
+        let printer = Printer::default();
+        let printed = printer.print_module(&module);
+        let reparsed = parse_module(&printed);
+
+        assert_eq!(reparsed.name.name, module.name.name);
+        assert_eq!(reparsed.constant_declarations.len(), 1);
+        assert_eq!(reparsed.constant_declarations[0].name.name, "answer");
+    }
+
+    #[test]
+    fn prints_sized_integer_suffix() {
+        let location = SourceLocation::unknown();
+        let expr = Expression::IntegerLiteral {
+            value: -5,
+            bits: 8,
+            signed: true,
+            source_location: location,
+        };
+
+        assert_eq!(Printer::default().print_expression(&expr), "-5i8");
+    }
+
+    #[test]
+    fn omits_default_integer_suffix() {
+        let location = SourceLocation::unknown();
+        let expr = Expression::IntegerLiteral {
+            value: 7,
+            bits: 64,
+            signed: true,
+            source_location: location,
+        };
+
+        assert_eq!(Printer::default().print_expression(&expr), "7");
+    }
+
+    #[test]
+    fn escapes_string_literal_specials() {
+        let location = SourceLocation::unknown();
+        let expr = Expression::StringLiteral {
+            value: "line\nwith \"quotes\" and $braces".to_string(),
+            source_location: location,
+        };
+
+        assert_eq!(
+            Printer::default().print_expression(&expr),
+            r#""line\nwith \"quotes\" and \$braces""#
+        );
+    }
+}
@@ -581,6 +581,7 @@ mod tests {
             throws_exceptions: Vec::new(),
             thread_safe: None,
             may_block: None,
+            is_test: false,
         };
 
         let code = validator.generate_runtime_assertions(&metadata, "test_function");
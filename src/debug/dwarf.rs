@@ -344,6 +344,12 @@ impl DwarfGenerator {
                     crate::ast::PrimitiveType::Void => 5,
                     crate::ast::PrimitiveType::SizeT => 1, // Treat as integer
                     crate::ast::PrimitiveType::UIntPtrT => 1, // Treat as integer
+                    crate::ast::PrimitiveType::I8 => 1,
+                    crate::ast::PrimitiveType::I16 => 1,
+                    crate::ast::PrimitiveType::U8 => 1,
+                    crate::ast::PrimitiveType::U16 => 1,
+                    crate::ast::PrimitiveType::U32 => 1,
+                    crate::ast::PrimitiveType::U64 => 1,
                 }
             }
             _ => 0, // Unknown type
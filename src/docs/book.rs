@@ -0,0 +1,295 @@
+// Copyright 2025 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Book-style authored documentation
+//!
+//! mdbook-style long-form docs: an ordered, nested chapter tree parsed from
+//! a `SUMMARY.md` manifest, with each chapter's Markdown loaded from disk.
+//! A [`DocPreprocessor`] pipeline runs between parsing and rendering so
+//! callers can splice in generated content (API docs, `{{#include}}`
+//! expansion, playground blocks) without touching the manifest format.
+
+use crate::error::SemanticError;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+/// A single chapter parsed from `SUMMARY.md`, with its nested
+/// sub-chapters in reading order.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Chapter {
+    /// Chapter title, taken from the `SUMMARY.md` link text
+    pub title: String,
+
+    /// Source Markdown file path, relative to `SUMMARY.md`'s directory
+    pub path: PathBuf,
+
+    /// Loaded (and preprocessed) Markdown content
+    pub content: String,
+
+    /// Nested sub-chapters, in reading order
+    pub sub_chapters: Vec<Chapter>,
+}
+
+/// The full authored book: an ordered chapter tree with every chapter's
+/// Markdown content already loaded.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Book {
+    /// Top-level chapters, in reading order
+    pub chapters: Vec<Chapter>,
+}
+
+impl Book {
+    /// Parse `summary_path` (an mdbook-style `SUMMARY.md`) and load every
+    /// chapter's Markdown content relative to its directory.
+    pub fn load(summary_path: &Path) -> Result<Self, SemanticError> {
+        let summary = std::fs::read_to_string(summary_path).map_err(|e| SemanticError::Internal {
+            message: format!("Failed to read book summary {}: {}", summary_path.display(), e),
+        })?;
+
+        let root_dir = summary_path.parent().unwrap_or_else(|| Path::new("."));
+        let entries = parse_summary(&summary);
+        let mut index = 0;
+        let chapters = load_chapters(&entries, &mut index, 0, root_dir)?;
+
+        Ok(Self { chapters })
+    }
+
+    /// Flatten the chapter tree into reading order (depth-first), for
+    /// prev/next navigation and sidebar rendering.
+    pub fn flatten(&self) -> Vec<&Chapter> {
+        fn walk<'a>(chapters: &'a [Chapter], out: &mut Vec<&'a Chapter>) {
+            for chapter in chapters {
+                out.push(chapter);
+                walk(&chapter.sub_chapters, out);
+            }
+        }
+
+        let mut out = Vec::new();
+        walk(&self.chapters, &mut out);
+        out
+    }
+
+    /// Run every preprocessor in order between parsing and rendering.
+    pub fn preprocess(&mut self, preprocessors: &[Box<dyn DocPreprocessor>]) -> Result<(), SemanticError> {
+        for preprocessor in preprocessors {
+            preprocessor.run(self)?;
+        }
+        Ok(())
+    }
+}
+
+/// A parsed `SUMMARY.md` line before its chapter content has been loaded:
+/// a title/path pair at some nesting depth.
+struct SummaryEntry {
+    title: String,
+    path: PathBuf,
+    depth: usize,
+}
+
+/// Parse an mdbook-style `SUMMARY.md`: a Markdown list whose indentation
+/// encodes chapter nesting and whose items are links, e.g.
+/// `  - [Getting Started](getting_started.md)`.
+fn parse_summary(summary: &str) -> Vec<SummaryEntry> {
+    let mut entries = Vec::new();
+
+    for line in summary.lines() {
+        let trimmed = line.trim_start();
+        let indent = line.len() - trimmed.len();
+
+        let Some(rest) = trimmed.strip_prefix("- ").or_else(|| trimmed.strip_prefix("* ")) else {
+            continue;
+        };
+        let Some((title, path)) = parse_markdown_link(rest) else {
+            continue;
+        };
+
+        entries.push(SummaryEntry {
+            title,
+            path: PathBuf::from(path),
+            depth: indent / 2,
+        });
+    }
+
+    entries
+}
+
+/// Parse a single inline Markdown link `[Title](path)`.
+fn parse_markdown_link(text: &str) -> Option<(String, String)> {
+    let text = text.trim();
+    let title_start = text.find('[')?;
+    let title_end = title_start + text[title_start..].find(']')?;
+    let path_start = title_end + text[title_end..].find('(')?;
+    let path_end = path_start + text[path_start..].find(')')?;
+
+    Some((
+        text[title_start + 1..title_end].to_string(),
+        text[path_start + 1..path_end].to_string(),
+    ))
+}
+
+/// Turn the flat, depth-annotated `entries` into a nested chapter tree,
+/// loading each chapter's Markdown content relative to `root_dir`.
+fn load_chapters(
+    entries: &[SummaryEntry],
+    index: &mut usize,
+    depth: usize,
+    root_dir: &Path,
+) -> Result<Vec<Chapter>, SemanticError> {
+    let mut chapters = Vec::new();
+
+    while *index < entries.len() && entries[*index].depth == depth {
+        let entry_path = entries[*index].path.clone();
+        let entry_title = entries[*index].title.clone();
+        *index += 1;
+
+        let content = std::fs::read_to_string(root_dir.join(&entry_path)).map_err(|e| SemanticError::Internal {
+            message: format!("Failed to read chapter {}: {}", entry_path.display(), e),
+        })?;
+
+        let sub_chapters = load_chapters(entries, index, depth + 1, root_dir)?;
+
+        chapters.push(Chapter {
+            title: entry_title,
+            path: entry_path,
+            content,
+            sub_chapters,
+        });
+    }
+
+    Ok(chapters)
+}
+
+/// Extension point run between parsing a [`Book`] and rendering it, so
+/// callers can inject generated content: splice API docs into a chapter,
+/// expand `{{#include file}}` directives, or resolve `{{#playground}}`
+/// blocks.
+pub trait DocPreprocessor {
+    fn run(&self, book: &mut Book) -> Result<(), SemanticError>;
+}
+
+/// Expands `{{#include path}}` directives into the referenced file's
+/// contents, resolved relative to `root_dir`.
+pub struct IncludePreprocessor {
+    pub root_dir: PathBuf,
+}
+
+impl DocPreprocessor for IncludePreprocessor {
+    fn run(&self, book: &mut Book) -> Result<(), SemanticError> {
+        fn expand(chapter: &mut Chapter, root_dir: &Path) -> Result<(), SemanticError> {
+            const DIRECTIVE: &str = "{{#include ";
+
+            while let Some(start) = chapter.content.find(DIRECTIVE) {
+                let Some(rel_end) = chapter.content[start..].find("}}") else {
+                    break;
+                };
+                let end = start + rel_end + 2;
+                let included_path = chapter.content[start + DIRECTIVE.len()..end - 2].trim();
+
+                let included = std::fs::read_to_string(root_dir.join(included_path)).map_err(|e| SemanticError::Internal {
+                    message: format!("Failed to include {}: {}", included_path, e),
+                })?;
+
+                chapter.content.replace_range(start..end, &included);
+            }
+
+            for sub_chapter in &mut chapter.sub_chapters {
+                expand(sub_chapter, root_dir)?;
+            }
+
+            Ok(())
+        }
+
+        for chapter in &mut book.chapters {
+            expand(chapter, &self.root_dir)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_summary_nests_by_indentation() {
+        let summary = "\
+- [Introduction](intro.md)
+- [Basics](basics/index.md)
+  - [Variables](basics/variables.md)
+  - [Functions](basics/functions.md)
+- [Advanced](advanced.md)
+";
+        let entries = parse_summary(summary);
+        assert_eq!(entries.len(), 5);
+        assert_eq!(entries[0].depth, 0);
+        assert_eq!(entries[2].depth, 1);
+        assert_eq!(entries[2].title, "Variables");
+        assert_eq!(entries[2].path, PathBuf::from("basics/variables.md"));
+    }
+
+    #[test]
+    fn test_parse_markdown_link() {
+        assert_eq!(
+            parse_markdown_link("[Getting Started](getting_started.md)"),
+            Some(("Getting Started".to_string(), "getting_started.md".to_string()))
+        );
+        assert_eq!(parse_markdown_link("not a link"), None);
+    }
+
+    #[test]
+    fn test_book_flatten_is_depth_first() {
+        let book = Book {
+            chapters: vec![Chapter {
+                title: "Parent".to_string(),
+                path: PathBuf::from("parent.md"),
+                content: String::new(),
+                sub_chapters: vec![Chapter {
+                    title: "Child".to_string(),
+                    path: PathBuf::from("child.md"),
+                    content: String::new(),
+                    sub_chapters: vec![],
+                }],
+            }],
+        };
+
+        let flat = book.flatten();
+        assert_eq!(flat.len(), 2);
+        assert_eq!(flat[0].title, "Parent");
+        assert_eq!(flat[1].title, "Child");
+    }
+
+    #[test]
+    fn test_include_preprocessor_expands_directive() {
+        let dir = std::env::temp_dir().join(format!("aether-book-test-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("snippet.aether"), "(println \"hi\")").unwrap();
+
+        let mut book = Book {
+            chapters: vec![Chapter {
+                title: "Ch".to_string(),
+                path: PathBuf::from("ch.md"),
+                content: "before\n{{#include snippet.aether}}\nafter".to_string(),
+                sub_chapters: vec![],
+            }],
+        };
+
+        let preprocessor = IncludePreprocessor { root_dir: dir.clone() };
+        preprocessor.run(&mut book).unwrap();
+
+        assert_eq!(book.chapters[0].content, "before\n(println \"hi\")\nafter");
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+}
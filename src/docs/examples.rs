@@ -4,8 +4,11 @@
 //! standard library usage, and common programming patterns.
 
 use crate::error::SemanticError;
-use crate::docs::{Example, DocConfig};
+use crate::docs::{xref, CodeExample, Documentation, Example, DocConfig, SourceLocation};
+use crate::pipeline::CompileOptions;
+use crate::Compiler;
 use std::collections::HashMap;
+use std::process::Command;
 
 /// Example manager for generating and organizing code examples
 #[derive(Debug)]
@@ -161,6 +164,7 @@ impl ExampleManager {
             dependencies: vec![],
             build_instructions: Some("aether run hello_world.aether".to_string()),
             tags: vec!["basic".to_string(), "output".to_string()],
+            refname: xref::derive_refname("Hello World"),
         });
         
         // Variables and data types
@@ -188,6 +192,7 @@ Hobbies: ["reading" "coding" "gaming"]"#.to_string()),
             dependencies: vec![],
             build_instructions: Some("aether run variables.aether".to_string()),
             tags: vec!["variables".to_string(), "types".to_string(), "basic".to_string()],
+            refname: xref::derive_refname("Variables and Types"),
         });
         
         // Functions
@@ -219,6 +224,7 @@ Hobbies: ["reading" "coding" "gaming"]"#.to_string()),
             dependencies: vec![],
             build_instructions: Some("aether run functions.aether".to_string()),
             tags: vec!["functions".to_string(), "recursion".to_string(), "basic".to_string()],
+            refname: xref::derive_refname("Functions"),
         });
         
         // Control flow
@@ -266,6 +272,7 @@ Sum 1-10: 55"#.to_string()),
             dependencies: vec![],
             build_instructions: Some("aether run control_flow.aether".to_string()),
             tags: vec!["control-flow".to_string(), "loops".to_string(), "conditionals".to_string()],
+            refname: xref::derive_refname("Control Flow"),
         });
         
         Ok(())
@@ -314,6 +321,7 @@ Squares 1-5: [1 4 9 16 25]"#.to_string()),
             dependencies: vec![],
             build_instructions: Some("aether run lists.aether".to_string()),
             tags: vec!["lists".to_string(), "collections".to_string(), "functional".to_string()],
+            refname: xref::derive_refname("Working with Lists"),
         });
         
         // Maps and dictionaries
@@ -362,6 +370,7 @@ First employee: Alice"#.to_string()),
             dependencies: vec![],
             build_instructions: Some("aether run maps.aether".to_string()),
             tags: vec!["maps".to_string(), "dictionaries".to_string(), "key-value".to_string()],
+            refname: xref::derive_refname("Working with Maps"),
         });
         
         // Sets
@@ -410,6 +419,7 @@ Reduced: #{3 4 5}"#.to_string()),
             dependencies: vec![],
             build_instructions: Some("aether run sets.aether".to_string()),
             tags: vec!["sets".to_string(), "collections".to_string(), "unique".to_string()],
+            refname: xref::derive_refname("Working with Sets"),
         });
         
         Ok(())
@@ -488,6 +498,7 @@ Merge sort: [11 12 22 25 34 64 90]"#.to_string()),
             dependencies: vec![],
             build_instructions: Some("aether run sorting.aether".to_string()),
             tags: vec!["algorithms".to_string(), "sorting".to_string(), "performance".to_string()],
+            refname: xref::derive_refname("Sorting Algorithms"),
         });
         
         // Search algorithms
@@ -565,6 +576,7 @@ Binary search result: -1"#.to_string()),
             dependencies: vec![],
             build_instructions: Some("aether run searching.aether".to_string()),
             tags: vec!["algorithms".to_string(), "searching".to_string(), "binary-search".to_string()],
+            refname: xref::derive_refname("Search Algorithms"),
         });
         
         Ok(())
@@ -634,6 +646,7 @@ Valid email? true"#.to_string()),
             dependencies: vec!["aether.string".to_string()],
             build_instructions: Some("aether run string_ops.aether".to_string()),
             tags: vec!["strings".to_string(), "stdlib".to_string(), "text-processing".to_string()],
+            refname: xref::derive_refname("String Manipulation"),
         });
         
         // File I/O
@@ -714,6 +727,7 @@ CSV data written to data.csv"#.to_string()),
             dependencies: vec!["aether.io".to_string(), "aether.string".to_string()],
             build_instructions: Some("aether run file_io.aether".to_string()),
             tags: vec!["io".to_string(), "files".to_string(), "stdlib".to_string()],
+            refname: xref::derive_refname("File Input/Output"),
         });
         
         Ok(())
@@ -818,6 +832,7 @@ Result: 18"#.to_string()),
             dependencies: vec!["aether.async".to_string()],
             build_instructions: Some("aether run concurrency.aether".to_string()),
             tags: vec!["concurrency".to_string(), "channels".to_string(), "async".to_string(), "advanced".to_string()],
+            refname: xref::derive_refname("Concurrency with Channels"),
         });
         
         // Metaprogramming example
@@ -932,6 +947,7 @@ many"#.to_string()),
             dependencies: vec![],
             build_instructions: Some("aether run metaprogramming.aether".to_string()),
             tags: vec!["macros".to_string(), "metaprogramming".to_string(), "code-generation".to_string(), "advanced".to_string()],
+            refname: xref::derive_refname("Metaprogramming with Macros"),
         });
         
         Ok(())
@@ -1063,6 +1079,7 @@ GET /api/users - 200 (3ms)"#.to_string()),
             dependencies: vec!["aether.http".to_string(), "aether.json".to_string(), "aether.string".to_string()],
             build_instructions: Some("aether run web_server.aether".to_string()),
             tags: vec!["web".to_string(), "http".to_string(), "server".to_string(), "api".to_string(), "application".to_string()],
+            refname: xref::derive_refname("Simple Web Server"),
         });
         
         Ok(())
@@ -1135,6 +1152,316 @@ GET /api/users - 200 (3ms)"#.to_string()),
     }
 }
 
+/// Attributes governing how a single doctest is compiled and run, parsed
+/// either from a fenced code block's info string (e.g. ` ```aether,ignore`)
+/// or from an [`Example`]'s `tags`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DocTestAttributes {
+    /// Skip this example entirely
+    pub ignore: bool,
+    /// Compile but do not execute
+    pub no_run: bool,
+    /// Expect a non-zero exit status (panic/error) instead of success
+    pub should_panic: bool,
+}
+
+impl DocTestAttributes {
+    fn from_tokens<'a>(tokens: impl Iterator<Item = &'a str>) -> Self {
+        let mut attrs = Self::default();
+        for token in tokens {
+            match token.trim() {
+                "ignore" => attrs.ignore = true,
+                "no_run" => attrs.no_run = true,
+                "should_panic" | "should_error" => attrs.should_panic = true,
+                _ => {}
+            }
+        }
+        attrs
+    }
+
+    /// Parse attributes from a fenced block's info string, e.g.
+    /// `"aether,should_panic"`. The first token is the language and is
+    /// ignored here.
+    fn from_info_string(info: &str) -> Self {
+        Self::from_tokens(info.split(',').skip(1))
+    }
+
+    /// Parse attributes out of an [`Example`]'s free-form tags.
+    fn from_tags(tags: &[String]) -> Self {
+        Self::from_tokens(tags.iter().map(String::as_str))
+    }
+}
+
+/// Strip hidden setup lines (prefixed with `# `, rustdoc-style) down to
+/// their executable content, for compiling.
+fn executable_code(code: &str) -> String {
+    code.lines()
+        .map(|line| line.strip_prefix("# ").unwrap_or(line))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Drop hidden setup lines entirely, for rendering to readers.
+pub fn visible_code(code: &str) -> String {
+    code.lines()
+        .filter(|line| !line.starts_with("# "))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// A fenced AetherScript code block pulled out of a raw doc comment.
+struct FencedBlock {
+    code: String,
+    attributes: DocTestAttributes,
+}
+
+/// Extract every ` ``` `-fenced code block written in AetherScript (an
+/// untagged fence, or one tagged `aether`/`aetherscript`) out of `docs`,
+/// along with the attributes on its info string. Fences in other
+/// languages are skipped.
+fn extract_fenced_blocks(docs: &str) -> Vec<FencedBlock> {
+    let mut blocks = Vec::new();
+    let mut lines = docs.lines();
+
+    while let Some(line) = lines.next() {
+        let Some(info) = line.trim_start().strip_prefix("```") else {
+            continue;
+        };
+
+        let lang = info.split(',').next().unwrap_or("").trim();
+        let is_aether = lang.is_empty() || lang.eq_ignore_ascii_case("aether") || lang.eq_ignore_ascii_case("aetherscript");
+
+        if !is_aether {
+            for skip_line in lines.by_ref() {
+                if skip_line.trim_start().starts_with("```") {
+                    break;
+                }
+            }
+            continue;
+        }
+
+        let attributes = DocTestAttributes::from_info_string(info);
+        let mut code = String::new();
+        for body_line in lines.by_ref() {
+            if body_line.trim_start().starts_with("```") {
+                break;
+            }
+            if !code.is_empty() {
+                code.push('\n');
+            }
+            code.push_str(body_line);
+        }
+
+        blocks.push(FencedBlock { code, attributes });
+    }
+
+    blocks
+}
+
+/// Wrap a bare sequence of body statements in a synthetic `main` function
+/// so it can be compiled standalone, unless `code` already declares its own
+/// module.
+fn wrap_in_synthetic_module(code: &str) -> String {
+    if code.contains("DEFINE_MODULE") {
+        return code.to_string();
+    }
+
+    format!(
+        "(DEFINE_MODULE doctest\n  (DEFINE_FUNCTION\n    (NAME \"main\")\n    (RETURNS (TYPE INT))\n    (BODY\n{}\n      (RETURN_VALUE (INTEGER_LITERAL 0)))))\n",
+        code,
+    )
+}
+
+/// Why a single doctest did not pass; `Passed` and `Ignored` both count as
+/// non-failures in [`DocTestReport`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DocTestOutcome {
+    /// Compiled (and, unless `no_run`, ran and matched `expected_output`)
+    Passed,
+    /// Skipped because of an `ignore` attribute
+    Ignored,
+    /// Compilation failed
+    CompileFailed(String),
+    /// Ran but exited non-zero without `should_panic`, or exited zero with it
+    RuntimeFailed(String),
+    /// Ran successfully but stdout didn't match the declared expected output
+    OutputMismatch { expected: String, actual: String },
+}
+
+/// Outcome of a single compiled/run example, with enough context to report
+/// a source location for failures.
+#[derive(Debug, Clone)]
+pub struct DocTestResult {
+    /// Human-readable identifier, e.g. `"my_module (example 1)"`
+    pub name: String,
+    /// Where the example came from, when known
+    pub source_location: Option<SourceLocation>,
+    pub outcome: DocTestOutcome,
+}
+
+impl DocTestResult {
+    /// Whether this result should count as a CI failure
+    pub fn is_failure(&self) -> bool {
+        !matches!(self.outcome, DocTestOutcome::Passed | DocTestOutcome::Ignored)
+    }
+}
+
+/// Report produced by [`run_doctests`], summarizing every example that was
+/// compiled and/or run.
+#[derive(Debug, Clone, Default)]
+pub struct DocTestReport {
+    pub results: Vec<DocTestResult>,
+}
+
+impl DocTestReport {
+    pub fn passed(&self) -> usize {
+        self.results.iter().filter(|r| r.outcome == DocTestOutcome::Passed).count()
+    }
+
+    pub fn ignored(&self) -> usize {
+        self.results.iter().filter(|r| r.outcome == DocTestOutcome::Ignored).count()
+    }
+
+    pub fn failed(&self) -> usize {
+        self.results.iter().filter(|r| r.is_failure()).count()
+    }
+
+    /// Whether CI should fail the build
+    pub fn all_passed(&self) -> bool {
+        self.failed() == 0
+    }
+}
+
+/// Compile `code` and, unless `attributes.no_run`, execute it and compare
+/// captured stdout against `expected_output`.
+fn run_one_doctest(code: &str, attributes: DocTestAttributes, expected_output: Option<&str>) -> DocTestOutcome {
+    let source = wrap_in_synthetic_module(&executable_code(code));
+
+    let work_dir = std::env::temp_dir().join(format!("aether-doctest-{}-{}", std::process::id(), fastrand_id()));
+    if let Err(e) = std::fs::create_dir_all(&work_dir) {
+        return DocTestOutcome::CompileFailed(format!("Failed to create scratch directory: {}", e));
+    }
+    let source_path = work_dir.join("doctest.aether");
+    if let Err(e) = std::fs::write(&source_path, &source) {
+        return DocTestOutcome::CompileFailed(format!("Failed to write scratch source: {}", e));
+    }
+
+    let mut options = CompileOptions::default();
+    options.optimization_level = 0;
+    options.output = Some(work_dir.join("doctest"));
+
+    let compiler = Compiler::with_options(options);
+    let result = match compiler.compile_files(&[source_path]) {
+        Ok(result) => result,
+        Err(e) => {
+            let _ = std::fs::remove_dir_all(&work_dir);
+            return DocTestOutcome::CompileFailed(e.to_string());
+        }
+    };
+
+    let outcome = if attributes.no_run {
+        DocTestOutcome::Passed
+    } else {
+        match Command::new(&result.executable_path).output() {
+            Ok(output) => {
+                let succeeded = output.status.success();
+                if succeeded == attributes.should_panic {
+                    DocTestOutcome::RuntimeFailed(format!(
+                        "expected {}, got exit status {}",
+                        if attributes.should_panic { "failure" } else { "success" },
+                        output.status,
+                    ))
+                } else if attributes.should_panic {
+                    DocTestOutcome::Passed
+                } else if let Some(expected) = expected_output {
+                    let actual = String::from_utf8_lossy(&output.stdout);
+                    if actual.trim_end() == expected.trim_end() {
+                        DocTestOutcome::Passed
+                    } else {
+                        DocTestOutcome::OutputMismatch {
+                            expected: expected.to_string(),
+                            actual: actual.into_owned(),
+                        }
+                    }
+                } else {
+                    DocTestOutcome::Passed
+                }
+            }
+            Err(e) => DocTestOutcome::RuntimeFailed(format!("Failed to execute compiled example: {}", e)),
+        }
+    };
+
+    let _ = std::fs::remove_dir_all(&work_dir);
+    outcome
+}
+
+/// Cheap process-local uniqueness for scratch directory names; not a PRNG,
+/// just enough to avoid collisions between doctests running concurrently.
+fn fastrand_id() -> usize {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    static COUNTER: AtomicUsize = AtomicUsize::new(0);
+    COUNTER.fetch_add(1, Ordering::Relaxed)
+}
+
+fn run_code_example(name: String, source_location: Option<SourceLocation>, example: &CodeExample, results: &mut Vec<DocTestResult>) {
+    let mut blocks = extract_fenced_blocks(&example.code);
+    if blocks.is_empty() {
+        blocks.push(FencedBlock { code: example.code.clone(), attributes: DocTestAttributes::default() });
+    }
+
+    for block in blocks {
+        if block.attributes.ignore {
+            results.push(DocTestResult { name: name.clone(), source_location: source_location.clone(), outcome: DocTestOutcome::Ignored });
+            continue;
+        }
+
+        let outcome = run_one_doctest(&block.code, block.attributes, example.output.as_deref());
+        results.push(DocTestResult { name: name.clone(), source_location: source_location.clone(), outcome });
+    }
+}
+
+/// Compile and run every doctest reachable from `docs`: fenced code blocks
+/// in module/function/type/macro doc comments, and every top-level
+/// [`Example`]. Each is compiled (bare snippets are wrapped in a synthetic
+/// `main`), and unless marked `no_run` or `ignore`, executed with its
+/// captured stdout compared against its declared expected output.
+pub fn run_doctests(docs: &Documentation) -> Result<DocTestReport, SemanticError> {
+    let mut results = Vec::new();
+
+    for module in &docs.api.modules {
+        for (i, example) in module.examples.iter().enumerate() {
+            run_code_example(format!("{} (example {})", module.name, i + 1), Some(module.source_location.clone()), example, &mut results);
+        }
+    }
+    for function in &docs.api.functions {
+        for (i, example) in function.examples.iter().enumerate() {
+            run_code_example(format!("{} (example {})", function.path, i + 1), Some(function.source_location.clone()), example, &mut results);
+        }
+    }
+    for ty in &docs.api.types {
+        for (i, example) in ty.examples.iter().enumerate() {
+            run_code_example(format!("{} (example {})", ty.path, i + 1), Some(ty.source_location.clone()), example, &mut results);
+        }
+    }
+    for macro_doc in &docs.api.macros {
+        for (i, example) in macro_doc.examples.iter().enumerate() {
+            run_code_example(format!("{} (example {})", macro_doc.path, i + 1), Some(macro_doc.source_location.clone()), example, &mut results);
+        }
+    }
+
+    for example in &docs.examples {
+        let attributes = DocTestAttributes::from_tags(&example.tags);
+        if attributes.ignore {
+            results.push(DocTestResult { name: example.name.clone(), source_location: None, outcome: DocTestOutcome::Ignored });
+            continue;
+        }
+        let outcome = run_one_doctest(&example.source_code, attributes, example.expected_output.as_deref());
+        results.push(DocTestResult { name: example.name.clone(), source_location: None, outcome });
+    }
+
+    Ok(DocTestReport { results })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,16 +1,342 @@
-use std::path::Path;
+// Copyright 2025 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Rustdoc-style Markdown API documentation generated directly from
+//! `ast::Module` metadata.
+//!
+//! Unlike [`super::DocumentationGenerator`], which renders parsed doc
+//! comments through a themed template pipeline, [`DocGenerator`] walks an
+//! already-constructed `Module` - the kind `stdlib::*::create_*_module`
+//! functions build - and renders one page per module plus an index, linking
+//! modules and functions to each other by anchor. Each function's and
+//! external's `intent` string is surfaced as prose, which is the point:
+//! AetherScript carries semantic intent that a plain symbol dump would
+//! throw away.
+
+use crate::ast::{
+    CallingConvention, ExternalFunction, Function, Module, OwnershipKind, Parameter,
+    PrimitiveType, TypeDefinition, TypeSpecifier,
+};
 use crate::error::SemanticError;
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+
+/// Where a documented symbol (type or function) lives, so other pages can
+/// link to it instead of repeating its name as plain text.
+struct SymbolLocation {
+    page: String,
+    anchor: String,
+}
 
 pub struct DocGenerator {
+    output_dir: PathBuf,
 }
 
 impl DocGenerator {
     pub fn new(output_dir: String) -> Self {
-        Self {}
+        Self { output_dir: PathBuf::from(output_dir) }
+    }
+
+    /// Render one Markdown page per module into the output directory, plus
+    /// an `index.md` listing every module with its intent and a link to its
+    /// page.
+    pub fn generate(&self, modules: &[Module]) -> Result<(), SemanticError> {
+        fs::create_dir_all(&self.output_dir).map_err(|e| SemanticError::IoError {
+            message: format!(
+                "Failed to create doc output directory {}: {}",
+                self.output_dir.display(),
+                e
+            ),
+        })?;
+
+        let symbols = build_symbol_index(modules);
+
+        for module in modules {
+            let page = render_module_page(module, &symbols);
+            self.write(&module_page_name(module), &page)?;
+        }
+
+        self.write("index.md", &render_index(modules))
+    }
+
+    fn write(&self, file_name: &str, content: &str) -> Result<(), SemanticError> {
+        let path = self.output_dir.join(file_name);
+        fs::write(&path, content).map_err(|e| SemanticError::IoError {
+            message: format!("Failed to write {}: {}", path.display(), e),
+        })
+    }
+}
+
+/// The Markdown file a module's page is written to: `std.io` -> `std_io.md`.
+fn module_page_name(module: &Module) -> String {
+    format!("{}.md", module.name.name.replace('.', "_"))
+}
+
+/// A GitHub-style Markdown heading anchor for `text`.
+fn anchor(text: &str) -> String {
+    text.to_lowercase()
+        .chars()
+        .filter_map(|c| match c {
+            ' ' => Some('-'),
+            c if c.is_alphanumeric() || c == '_' || c == '-' => Some(c),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Collect every type definition and function (exported or not) across all
+/// `modules` into a name -> page#anchor index, so [`render_type`] can turn a
+/// reference to one into a link instead of bare text.
+fn build_symbol_index(modules: &[Module]) -> HashMap<String, SymbolLocation> {
+    let mut index = HashMap::new();
+
+    for module in modules {
+        let page = module_page_name(module);
+
+        for type_def in &module.type_definitions {
+            let name = type_definition_name(type_def);
+            index.insert(
+                name.clone(),
+                SymbolLocation { page: page.clone(), anchor: anchor(&format!("type {name}")) },
+            );
+        }
+        for function in &module.function_definitions {
+            index.insert(
+                function.name.name.clone(),
+                SymbolLocation {
+                    page: page.clone(),
+                    anchor: anchor(&format!("fn {}", function.name.name)),
+                },
+            );
+        }
+        for external in &module.external_functions {
+            index.insert(
+                external.name.name.clone(),
+                SymbolLocation {
+                    page: page.clone(),
+                    anchor: anchor(&format!("fn {}", external.name.name)),
+                },
+            );
+        }
     }
-    
-    pub fn generate(&self, _module_path: &Path) -> Result<(), SemanticError> {
-        // Placeholder implementation
-        Ok(())
+
+    index
+}
+
+fn type_definition_name(type_def: &TypeDefinition) -> String {
+    match type_def {
+        TypeDefinition::Structured { name, .. } => name.name.clone(),
+        TypeDefinition::Enumeration { name, .. } => name.name.clone(),
+        TypeDefinition::Alias { new_name, .. } => new_name.name.clone(),
+    }
+}
+
+/// Render `ty` as a display string, linking to `symbols` when it names a
+/// type or function documented elsewhere in this run. This is the single
+/// place type names get turned into text, so every page renders the same
+/// type the same way.
+fn render_type(ty: &TypeSpecifier, symbols: &HashMap<String, SymbolLocation>) -> String {
+    match ty {
+        TypeSpecifier::Primitive { type_name, .. } => primitive_type_name(type_name).to_string(),
+        TypeSpecifier::Named { name, .. } => link_symbol(&name.name, symbols),
+        TypeSpecifier::Generic { base_type, type_arguments, .. } => {
+            let args = type_arguments.iter().map(|t| render_type(t, symbols)).collect::<Vec<_>>().join(", ");
+            format!("{}<{}>", link_symbol(&base_type.name, symbols), args)
+        }
+        TypeSpecifier::TypeParameter { name, .. } => name.name.clone(),
+        TypeSpecifier::Array { element_type, .. } => format!("Array<{}>", render_type(element_type, symbols)),
+        TypeSpecifier::Map { key_type, value_type, .. } => {
+            format!("Map<{}, {}>", render_type(key_type, symbols), render_type(value_type, symbols))
+        }
+        TypeSpecifier::Pointer { target_type, is_mutable, .. } => {
+            format!("{}{}", if *is_mutable { "*mut " } else { "*" }, render_type(target_type, symbols))
+        }
+        TypeSpecifier::Function { parameter_types, return_type, .. } => {
+            let params = parameter_types.iter().map(|t| render_type(t, symbols)).collect::<Vec<_>>().join(", ");
+            format!("({}) -> {}", params, render_type(return_type, symbols))
+        }
+        TypeSpecifier::Owned { base_type, ownership, .. } => {
+            let prefix = match ownership {
+                OwnershipKind::Owned => "^",
+                OwnershipKind::Borrowed => "&",
+                OwnershipKind::BorrowedMut => "&mut ",
+                OwnershipKind::Shared => "~",
+            };
+            format!("{}{}", prefix, render_type(base_type, symbols))
+        }
     }
-}
\ No newline at end of file
+}
+
+fn primitive_type_name(type_name: &PrimitiveType) -> &'static str {
+    match type_name {
+        PrimitiveType::Integer => "Integer",
+        PrimitiveType::Integer32 => "Integer32",
+        PrimitiveType::Integer64 => "Integer64",
+        PrimitiveType::Float => "Float",
+        PrimitiveType::Float32 => "Float32",
+        PrimitiveType::Float64 => "Float64",
+        PrimitiveType::String => "String",
+        PrimitiveType::Char => "Character",
+        PrimitiveType::Boolean => "Boolean",
+        PrimitiveType::Void => "Void",
+        PrimitiveType::SizeT => "SizeT",
+        PrimitiveType::UIntPtrT => "UIntPtrT",
+        PrimitiveType::I8 => "I8",
+        PrimitiveType::I16 => "I16",
+        PrimitiveType::U8 => "U8",
+        PrimitiveType::U16 => "U16",
+        PrimitiveType::U32 => "U32",
+        PrimitiveType::U64 => "U64",
+    }
+}
+
+fn link_symbol(name: &str, symbols: &HashMap<String, SymbolLocation>) -> String {
+    match symbols.get(name) {
+        Some(location) => format!("[{}]({}#{})", name, location.page, location.anchor),
+        None => name.to_string(),
+    }
+}
+
+fn render_parameters(parameters: &[Parameter], symbols: &HashMap<String, SymbolLocation>) -> String {
+    parameters
+        .iter()
+        .map(|p| format!("{}: {}", p.name.name, render_type(&p.param_type, symbols)))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn calling_convention_name(cc: &CallingConvention) -> &'static str {
+    match cc {
+        CallingConvention::C => "C",
+        CallingConvention::StdCall => "StdCall",
+        CallingConvention::FastCall => "FastCall",
+        CallingConvention::System => "System",
+    }
+}
+
+fn render_function(function: &Function, symbols: &HashMap<String, SymbolLocation>, out: &mut String) {
+    out.push_str(&format!("### fn {}\n\n", function.name.name));
+    out.push_str(&format!(
+        "`{}({}) -> {}`\n\n",
+        function.name.name,
+        render_parameters(&function.parameters, symbols),
+        render_type(&function.return_type, symbols),
+    ));
+    if let Some(intent) = &function.intent {
+        out.push_str(&format!("{intent}\n\n"));
+    }
+}
+
+fn render_external_function(external: &ExternalFunction, symbols: &HashMap<String, SymbolLocation>, out: &mut String) {
+    out.push_str(&format!("### fn {}\n\n", external.name.name));
+    out.push_str(&format!(
+        "`{}({}) -> {}`\n\n",
+        external.name.name,
+        render_parameters(&external.parameters, symbols),
+        render_type(&external.return_type, symbols),
+    ));
+    out.push_str(&format!(
+        "External symbol `{}`, calling convention {}.\n\n",
+        external.symbol.as_deref().unwrap_or(&external.name.name),
+        calling_convention_name(&external.calling_convention),
+    ));
+}
+
+fn render_module_page(module: &Module, symbols: &HashMap<String, SymbolLocation>) -> String {
+    let mut out = String::new();
+
+    out.push_str(&format!("# {}\n\n", module.name.name));
+    if let Some(intent) = &module.intent {
+        out.push_str(&format!("{intent}\n\n"));
+    }
+
+    let exported: std::collections::HashSet<&str> = module
+        .exports
+        .iter()
+        .filter_map(|e| match e {
+            crate::ast::ExportStatement::Function { name, .. } => Some(name.name.as_str()),
+            _ => None,
+        })
+        .collect();
+
+    if !module.function_definitions.is_empty() {
+        out.push_str("## Functions\n\n");
+        for function in &module.function_definitions {
+            render_function(function, symbols, &mut out);
+            if exported.contains(function.name.name.as_str()) {
+                out.push_str("*Exported.*\n\n");
+            }
+        }
+    }
+
+    if !module.external_functions.is_empty() {
+        out.push_str("## External Functions\n\n");
+        for external in &module.external_functions {
+            render_external_function(external, symbols, &mut out);
+        }
+    }
+
+    if !module.type_definitions.is_empty() {
+        out.push_str("## Types\n\n");
+        for type_def in &module.type_definitions {
+            out.push_str(&format!("### type {}\n\n", type_definition_name(type_def)));
+        }
+    }
+
+    out
+}
+
+fn render_index(modules: &[Module]) -> String {
+    let mut out = String::new();
+    out.push_str("# Module Index\n\n");
+    for module in modules {
+        out.push_str(&format!("- [{}]({})", module.name.name, module_page_name(module)));
+        if let Some(intent) = &module.intent {
+            out.push_str(&format!(" - {intent}"));
+        }
+        out.push('\n');
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stdlib::io::create_io_module;
+
+    #[test]
+    fn generate_renders_io_module_page_with_intent_and_externals() {
+        let dir = std::env::temp_dir().join(format!("aether_docgen_test_{}", std::process::id()));
+
+        let generator = DocGenerator::new(dir.to_string_lossy().to_string());
+        let module = create_io_module();
+        generator.generate(&[module]).unwrap();
+
+        let page = fs::read_to_string(dir.join("std_io.md")).unwrap();
+
+        assert!(page.contains("open_file"), "page should document open_file: {page}");
+        assert!(page.contains("aether_io_open_file"), "page should surface the external symbol: {page}");
+        assert!(
+            page.contains("Provides file and I/O operations for AetherScript programs"),
+            "page should surface the module's intent: {page}"
+        );
+
+        let index = fs::read_to_string(dir.join("index.md")).unwrap();
+        assert!(index.contains("std.io"));
+        assert!(index.contains("std_io.md"));
+
+        fs::remove_dir_all(&dir).ok();
+    }
+}
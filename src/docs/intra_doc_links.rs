@@ -0,0 +1,441 @@
+// Copyright 2025 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Intra-doc link resolution
+//!
+//! Doc comments write cross-references as a bracketed item path with no
+//! following `(url)`, e.g. `see [TypeName::method]`, optionally
+//! disambiguated with a `kind@` prefix (`[fn@foo]`, `[type@Bar]`). Plain
+//! Markdown links (`[text](url)`) and image links (`![alt]`) are left
+//! alone.
+//!
+//! [`resolve_links`] runs once the full API graph (see
+//! [`json_index`](crate::docs::json_index)) has been built: it scans every
+//! `docs`/`description` field, resolves each link against the graph using
+//! the originating item's module as the scope for relative lookups, and
+//! rewrites resolved links into `[text](url)` pointing at the target's
+//! page. Links that don't resolve, or resolve to more than one item, are
+//! left untouched and reported as a [`LinkWarning`] instead of failing the
+//! build.
+
+use crate::docs::json_index::{Id, ItemGraph};
+use crate::docs::{Documentation, ItemKind, SourceLocation};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt;
+
+/// Why a link could not be rewritten into a hyperlink.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum LinkIssue {
+    /// No item in the graph matches the link text from any scope.
+    Unresolved,
+    /// More than one item matches; rewriting would silently pick one.
+    Ambiguous(Vec<String>),
+}
+
+/// A link that couldn't be resolved, with enough context to find and fix
+/// it in the source.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LinkWarning {
+    /// The literal link text, e.g. `TypeName::method` or `fn@foo`
+    pub link_text: String,
+    /// Where the doc comment containing the link lives
+    pub location: SourceLocation,
+    /// Why resolution failed
+    pub issue: LinkIssue,
+}
+
+impl fmt::Display for LinkWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.issue {
+            LinkIssue::Unresolved => write!(
+                f,
+                "unresolved intra-doc link `[{}]` at {}:{}:{}",
+                self.link_text,
+                self.location.file.display(),
+                self.location.line,
+                self.location.column
+            ),
+            LinkIssue::Ambiguous(candidates) => write!(
+                f,
+                "ambiguous intra-doc link `[{}]` at {}:{}:{} (matches {})",
+                self.link_text,
+                self.location.file.display(),
+                self.location.line,
+                self.location.column,
+                candidates.join(", ")
+            ),
+        }
+    }
+}
+
+/// Resolve every intra-doc link across `documentation`'s API surface,
+/// rewriting resolvable ones in place and returning a warning for each one
+/// that wasn't. `graph` must have been built from this same
+/// `documentation` (see [`ItemGraph::build`]).
+pub fn resolve_links(documentation: &mut Documentation, graph: &ItemGraph) -> Vec<LinkWarning> {
+    let index = LinkIndex::build(graph);
+    let mut warnings = Vec::new();
+
+    for module in &mut documentation.api.modules {
+        let scope = module.path.clone();
+        let location = module.source_location.clone();
+        index.rewrite(&mut module.docs, &scope, &location, &mut warnings);
+        if let Some(description) = &mut module.description {
+            index.rewrite(description, &scope, &location, &mut warnings);
+        }
+    }
+    for function in &mut documentation.api.functions {
+        let scope = parent_scope(&function.path);
+        let location = function.source_location.clone();
+        index.rewrite(&mut function.docs, &scope, &location, &mut warnings);
+        if let Some(description) = &mut function.description {
+            index.rewrite(description, &scope, &location, &mut warnings);
+        }
+    }
+    for ty in &mut documentation.api.types {
+        let scope = parent_scope(&ty.path);
+        let location = ty.source_location.clone();
+        index.rewrite(&mut ty.docs, &scope, &location, &mut warnings);
+        if let Some(description) = &mut ty.description {
+            index.rewrite(description, &scope, &location, &mut warnings);
+        }
+    }
+    for constant in &mut documentation.api.constants {
+        let scope = parent_scope(&constant.path);
+        let location = constant.source_location.clone();
+        index.rewrite(&mut constant.docs, &scope, &location, &mut warnings);
+        if let Some(description) = &mut constant.description {
+            index.rewrite(description, &scope, &location, &mut warnings);
+        }
+    }
+    for macro_doc in &mut documentation.api.macros {
+        let scope = parent_scope(&macro_doc.path);
+        let location = macro_doc.source_location.clone();
+        index.rewrite(&mut macro_doc.docs, &scope, &location, &mut warnings);
+        if let Some(description) = &mut macro_doc.description {
+            index.rewrite(description, &scope, &location, &mut warnings);
+        }
+    }
+
+    warnings
+}
+
+/// The module an item's relative lookups are resolved against: everything
+/// up to (but not including) the item's own last path segment.
+fn parent_scope(path: &str) -> String {
+    match path.rsplit_once("::") {
+        Some((parent, _)) => parent.to_string(),
+        None => String::new(),
+    }
+}
+
+/// A disambiguator prefix on a link, e.g. the `fn` in `[fn@foo]`.
+fn disambiguator_kind(prefix: &str) -> Option<ItemKind> {
+    match prefix {
+        "fn" => Some(ItemKind::Function),
+        "struct" | "enum" | "type" => Some(ItemKind::Type),
+        "const" => Some(ItemKind::Constant),
+        "macro" => Some(ItemKind::Macro),
+        "mod" => Some(ItemKind::Module),
+        _ => None,
+    }
+}
+
+/// Lookup structure built once per [`resolve_links`] call: every item's
+/// full `::`-joined path and resolved page URL, keyed for both exact-path
+/// and bare-name lookups.
+struct LinkIndex<'a> {
+    graph: &'a ItemGraph,
+    by_path: HashMap<String, Vec<Id>>,
+    by_last_segment: HashMap<String, Vec<Id>>,
+}
+
+impl<'a> LinkIndex<'a> {
+    fn build(graph: &'a ItemGraph) -> Self {
+        let mut by_path: HashMap<String, Vec<Id>> = HashMap::new();
+        let mut by_last_segment: HashMap<String, Vec<Id>> = HashMap::new();
+
+        for (id, info) in &graph.paths {
+            by_path.entry(info.path.join("::")).or_default().push(*id);
+            if let Some(last) = info.path.last() {
+                by_last_segment.entry(last.clone()).or_default().push(*id);
+            }
+        }
+
+        Self { graph, by_path, by_last_segment }
+    }
+
+    fn kind_of(&self, id: Id) -> ItemKind {
+        self.graph.paths[&id].kind.clone()
+    }
+
+    fn url_of(&self, id: Id) -> String {
+        let path = &self.graph.paths[&id].path;
+        if path.len() <= 1 {
+            format!("api/{}.html", path.join("/"))
+        } else {
+            format!("api/{}.html#{}", path[0], path.join("::"))
+        }
+    }
+
+    /// Resolve `raw_link` (the text inside `[...]`, disambiguator prefix
+    /// already stripped by the caller) against `scope`, preferring a
+    /// sibling of `scope` before falling back to an absolute path and then
+    /// a crate-wide search by trailing segment.
+    fn resolve(&self, scope: &str, name: &str, kind: Option<ItemKind>) -> Result<Id, LinkIssue> {
+        let matches_kind = |id: &Id| kind.as_ref().is_none_or(|k| &self.kind_of(*id) == k);
+
+        let mut candidates = Vec::new();
+        if !scope.is_empty() {
+            candidates.push(format!("{}::{}", scope, name));
+        }
+        candidates.push(name.to_string());
+
+        for candidate in &candidates {
+            let Some(ids) = self.by_path.get(candidate) else { continue };
+            let matched: Vec<Id> = ids.iter().copied().filter(matches_kind).collect();
+            match matched.len() {
+                0 => continue,
+                1 => return Ok(matched[0]),
+                _ => {
+                    return Err(LinkIssue::Ambiguous(
+                        matched.iter().map(|id| self.graph.paths[id].path.join("::")).collect(),
+                    ))
+                }
+            }
+        }
+
+        if !name.contains("::") {
+            if let Some(ids) = self.by_last_segment.get(name) {
+                let mut found: Vec<Id> = ids.iter().copied().filter(matches_kind).collect();
+                found.sort();
+                found.dedup();
+                return match found.len() {
+                    0 => Err(LinkIssue::Unresolved),
+                    1 => Ok(found[0]),
+                    _ => Err(LinkIssue::Ambiguous(
+                        found.iter().map(|id| self.graph.paths[id].path.join("::")).collect(),
+                    )),
+                };
+            }
+        }
+
+        Err(LinkIssue::Unresolved)
+    }
+
+    /// Rewrite every resolvable `[link]` found in `text` in place, pushing
+    /// a [`LinkWarning`] onto `warnings` for each one that isn't.
+    fn rewrite(&self, text: &mut String, scope: &str, location: &SourceLocation, warnings: &mut Vec<LinkWarning>) {
+        let spans = find_link_spans(text);
+        for (start, end) in spans.into_iter().rev() {
+            let link_text = text[start + 1..end - 1].to_string();
+            let (kind, name) = match link_text.split_once('@') {
+                Some((prefix, rest)) if disambiguator_kind(prefix).is_some() => (disambiguator_kind(prefix), rest),
+                _ => (None, link_text.as_str()),
+            };
+
+            match self.resolve(scope, name, kind) {
+                Ok(id) => {
+                    let url = self.url_of(id);
+                    text.replace_range(start..end, &format!("[{}]({})", link_text, url));
+                }
+                Err(issue) => {
+                    warnings.push(LinkWarning { link_text: link_text.clone(), location: location.clone(), issue });
+                }
+            }
+        }
+    }
+}
+
+/// Find every `(start, end)` byte span (`end` exclusive, covering the
+/// brackets) of a bare `[link]` in `text` -- skipping image links
+/// (`![...]`) and ordinary Markdown links (`[text](url)`).
+fn find_link_spans(text: &str) -> Vec<(usize, usize)> {
+    let mut spans = Vec::new();
+    let mut i = 0;
+
+    while let Some(rel_start) = text[i..].find('[') {
+        let start = i + rel_start;
+        if start > 0 && text.as_bytes()[start - 1] == b'!' {
+            i = start + 1;
+            continue;
+        }
+        let Some(rel_end) = text[start + 1..].find(']') else {
+            break;
+        };
+        let end = start + 1 + rel_end + 1;
+        let inner = &text[start + 1..end - 1];
+
+        let is_markdown_link = text[end..].starts_with('(');
+        if !is_markdown_link && !inner.is_empty() && !inner.contains(char::is_whitespace) {
+            spans.push((start, end));
+        }
+        i = end;
+    }
+
+    spans
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::docs::json_index::ItemGraph;
+    use crate::docs::{
+        ApiDocumentation, FunctionDoc, FunctionSignature, ModuleDoc, ProjectMetadata, ReferenceManual, TypeDoc,
+        TypeKind, Visibility,
+    };
+    use std::collections::HashMap as StdHashMap;
+    use std::path::PathBuf;
+
+    fn loc() -> SourceLocation {
+        SourceLocation { file: PathBuf::from("test.aether"), line: 1, column: 1, span: 0 }
+    }
+
+    fn empty_docs() -> Documentation {
+        Documentation {
+            metadata: ProjectMetadata {
+                name: "test_crate".to_string(),
+                version: "1.0.0".to_string(),
+                description: None,
+                authors: vec![],
+                license: None,
+                homepage: None,
+                repository: None,
+                documentation_url: None,
+                generated_at: std::time::SystemTime::UNIX_EPOCH,
+            },
+            api: ApiDocumentation { modules: vec![], functions: vec![], types: vec![], constants: vec![], macros: vec![] },
+            tutorials: vec![],
+            examples: vec![],
+            reference: ReferenceManual { sections: vec![], appendices: vec![], glossary: StdHashMap::new(), index: vec![] },
+            search_index: None,
+            book: None,
+            link_warnings: vec![],
+            xref_table: crate::docs::xref::XRefTable::default(),
+        }
+    }
+
+    fn function(path: &str, docs: &str) -> FunctionDoc {
+        FunctionDoc {
+            name: path.rsplit("::").next().unwrap().to_string(),
+            path: path.to_string(),
+            description: None,
+            docs: docs.to_string(),
+            signature: FunctionSignature {
+                name: path.to_string(),
+                type_parameters: vec![],
+                parameters: vec![],
+                return_type: None,
+                is_async: false,
+                is_unsafe: false,
+            },
+            visibility: Visibility::Public,
+            source_location: loc(),
+            parameters: vec![],
+            return_type: None,
+            examples: vec![],
+            related: vec![],
+        }
+    }
+
+    #[test]
+    fn test_find_link_spans_skips_markdown_and_image_links() {
+        let text = "see [Foo::bar], ![alt], and [a normal link](http://x)";
+        let spans: Vec<&str> = find_link_spans(text).into_iter().map(|(s, e)| &text[s..e]).collect();
+        assert_eq!(spans, vec!["[Foo::bar]"]);
+    }
+
+    #[test]
+    fn test_resolves_sibling_function_by_bare_name() {
+        let mut docs = empty_docs();
+        docs.api.modules.push(ModuleDoc {
+            name: "m".to_string(),
+            path: "m".to_string(),
+            description: None,
+            docs: String::new(),
+            visibility: Visibility::Public,
+            source_location: loc(),
+            submodules: vec![],
+            items: vec![],
+            examples: vec![],
+            refname: "m".to_string(),
+        });
+        docs.api.functions.push(function("m::foo", "see [bar]"));
+        docs.api.functions.push(function("m::bar", ""));
+
+        let graph = ItemGraph::build(&docs);
+        let warnings = resolve_links(&mut docs, &graph);
+
+        assert!(warnings.is_empty());
+        let foo = docs.api.functions.iter().find(|f| f.path == "m::foo").unwrap();
+        assert_eq!(foo.docs, "see [bar](api/m.html#m::bar)");
+    }
+
+    #[test]
+    fn test_disambiguated_link_resolves_by_kind() {
+        let mut docs = empty_docs();
+        docs.api.types.push(TypeDoc {
+            name: "Bar".to_string(),
+            path: "Bar".to_string(),
+            description: None,
+            docs: String::new(),
+            kind: TypeKind::Struct,
+            visibility: Visibility::Public,
+            source_location: loc(),
+            type_parameters: vec![],
+            fields: vec![],
+            variants: vec![],
+            methods: vec![],
+            trait_impls: vec![],
+            examples: vec![],
+        });
+        docs.api.functions.push(function("Bar", "see [type@Bar] not the fn"));
+
+        let graph = ItemGraph::build(&docs);
+        let warnings = resolve_links(&mut docs, &graph);
+
+        assert!(warnings.is_empty());
+        let item = &docs.api.functions[0];
+        assert_eq!(item.docs, "see [type@Bar](api/Bar.html) not the fn");
+    }
+
+    #[test]
+    fn test_unresolved_link_is_left_untouched_and_warned() {
+        let mut docs = empty_docs();
+        docs.api.functions.push(function("foo", "see [DoesNotExist]"));
+
+        let graph = ItemGraph::build(&docs);
+        let warnings = resolve_links(&mut docs, &graph);
+
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].link_text, "DoesNotExist");
+        assert_eq!(warnings[0].issue, LinkIssue::Unresolved);
+        assert_eq!(docs.api.functions[0].docs, "see [DoesNotExist]");
+    }
+
+    #[test]
+    fn test_ambiguous_bare_name_across_modules_is_warned() {
+        let mut docs = empty_docs();
+        docs.api.functions.push(function("a::dup", ""));
+        docs.api.functions.push(function("b::dup", ""));
+        docs.api.functions.push(function("c::user", "see [dup]"));
+
+        let graph = ItemGraph::build(&docs);
+        let warnings = resolve_links(&mut docs, &graph);
+
+        assert_eq!(warnings.len(), 1);
+        assert!(matches!(&warnings[0].issue, LinkIssue::Ambiguous(candidates) if candidates.len() == 2));
+    }
+}
@@ -0,0 +1,564 @@
+// Copyright 2025 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Stable, versioned JSON item graph for documentation tooling
+//!
+//! Modeled on rustdoc's JSON output: the whole crate is flattened into an
+//! [`Id`]-indexed [`ItemGraph`] so downstream tools (link checkers, coverage
+//! tools, IDE indexers) can consume AetherScript docs without re-parsing
+//! HTML or re-resolving path strings themselves.
+
+use crate::docs::{
+    CodeExample, ConstantDoc, Documentation, FieldDoc, FunctionDoc, FunctionSignature, ItemKind,
+    MacroDoc, ModuleDoc, TraitImplDoc, TypeDoc, TypeKind, TypeReference, Visibility, VariantDoc,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Format version for the JSON item graph. Bump this on any breaking change
+/// to the shape of [`ItemGraph`] or its nested types.
+pub const FORMAT_VERSION: u32 = 1;
+
+/// Stable identifier for an item within an [`ItemGraph`]. Ids are assigned
+/// in a single build pass and index into `ItemGraph::index`; they are not
+/// stable across separate builds of the same crate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct Id(pub u32);
+
+/// Fully-qualified path and kind of an item, keyed by [`Id`] so tools can
+/// resolve a reference without walking the whole graph.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PathInfo {
+    /// Path segments, e.g. `["my_module", "MyType"]`
+    pub path: Vec<String>,
+    /// Kind of item this path refers to
+    pub kind: ItemKind,
+}
+
+/// An item referenced from the graph but not defined in the local crate
+/// (e.g. a standard library type, or a path that failed to resolve).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExternalItem {
+    /// The unresolved path or name as it appeared in the source item
+    pub name: String,
+}
+
+/// A single documented item in the flattened graph. Fields that referenced
+/// other items by bare string path in the tree-shaped [`Documentation`]
+/// (`TypeDoc::methods`, `TraitImplDoc::trait_path`, `FunctionDoc::related`)
+/// are resolved here into [`Id`]s pointing back into `ItemGraph::index`;
+/// references that don't resolve locally are recorded in
+/// `ItemGraph::external_crates` and omitted from the `Id` list.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "kind", content = "inner")]
+pub enum Item {
+    Module(ModuleItem),
+    Function(FunctionItem),
+    Type(TypeItem),
+    Constant(ConstantItem),
+    Macro(MacroItem),
+}
+
+/// Module item in the graph
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ModuleItem {
+    pub name: String,
+    pub docs: String,
+    pub visibility: Visibility,
+    /// Direct children (submodules, functions, types, constants, macros)
+    pub items: Vec<Id>,
+}
+
+/// Function item in the graph
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FunctionItem {
+    pub name: String,
+    pub docs: String,
+    pub signature: FunctionSignature,
+    pub visibility: Visibility,
+    pub examples: Vec<CodeExample>,
+    /// Resolved references to related functions
+    pub related: Vec<Id>,
+}
+
+/// Type item in the graph
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TypeItem {
+    pub name: String,
+    pub docs: String,
+    pub type_kind: TypeKind,
+    pub visibility: Visibility,
+    pub fields: Vec<FieldDoc>,
+    pub variants: Vec<VariantDoc>,
+    /// Resolved references to method `FunctionItem`s
+    pub methods: Vec<Id>,
+    pub trait_impls: Vec<TraitImplItem>,
+}
+
+/// Trait implementation, with the trait path resolved to an [`Id`] where
+/// possible
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TraitImplItem {
+    pub trait_name: String,
+    pub trait_path: Option<Id>,
+    pub methods: Vec<Id>,
+}
+
+/// Constant item in the graph
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConstantItem {
+    pub name: String,
+    pub docs: String,
+    pub const_type: TypeReference,
+    pub value: Option<String>,
+    pub visibility: Visibility,
+}
+
+/// Macro item in the graph
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MacroItem {
+    pub name: String,
+    pub docs: String,
+    pub signature: String,
+    pub visibility: Visibility,
+    pub examples: Vec<CodeExample>,
+}
+
+/// Flattened, Id-indexed representation of a crate's documentation,
+/// suitable for machine consumption.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ItemGraph {
+    /// See [`FORMAT_VERSION`]
+    pub format_version: u32,
+    /// Id of the synthetic crate-root module
+    pub root: Id,
+    /// Every locally-defined item, by Id
+    pub index: HashMap<Id, Item>,
+    /// Fully-qualified path and kind for every item in `index`
+    pub paths: HashMap<Id, PathInfo>,
+    /// Items referenced but not defined in this crate, keyed by the raw
+    /// path string that failed to resolve
+    pub external_crates: HashMap<String, ExternalItem>,
+}
+
+impl ItemGraph {
+    /// Build the item graph from a fully-populated [`Documentation`] tree.
+    pub fn build(docs: &Documentation) -> Self {
+        let mut next_id = 1u32; // Id(0) is reserved for the synthetic root
+        let mut path_to_id: HashMap<String, Id> = HashMap::new();
+        let mut paths: HashMap<Id, PathInfo> = HashMap::new();
+
+        let mut alloc = |raw_path: &str, kind: ItemKind| -> Id {
+            let id = Id(next_id);
+            next_id += 1;
+            path_to_id.insert(raw_path.to_string(), id);
+            paths.insert(
+                id,
+                PathInfo {
+                    path: raw_path.split("::").map(|s| s.to_string()).collect(),
+                    kind,
+                },
+            );
+            id
+        };
+
+        for module in &docs.api.modules {
+            alloc(&module.path, ItemKind::Module);
+        }
+        for function in &docs.api.functions {
+            alloc(&function.path, ItemKind::Function);
+        }
+        for ty in &docs.api.types {
+            alloc(&ty.path, ItemKind::Type);
+        }
+        for constant in &docs.api.constants {
+            alloc(&constant.path, ItemKind::Constant);
+        }
+        for macro_doc in &docs.api.macros {
+            alloc(&macro_doc.path, ItemKind::Macro);
+        }
+
+        let mut external_crates: HashMap<String, ExternalItem> = HashMap::new();
+        let mut index: HashMap<Id, Item> = HashMap::new();
+
+        for module in &docs.api.modules {
+            let id = path_to_id[&module.path];
+            index.insert(
+                id,
+                Item::Module(ModuleItem {
+                    name: module.name.clone(),
+                    docs: module.docs.clone(),
+                    visibility: module.visibility.clone(),
+                    items: children_of(&module.path, &path_to_id),
+                }),
+            );
+        }
+
+        for function in &docs.api.functions {
+            let id = path_to_id[&function.path];
+            let related = resolve_many(&function.related, &path_to_id, &mut external_crates);
+            index.insert(
+                id,
+                Item::Function(FunctionItem {
+                    name: function.name.clone(),
+                    docs: function.docs.clone(),
+                    signature: function.signature.clone(),
+                    visibility: function.visibility.clone(),
+                    examples: function.examples.clone(),
+                    related,
+                }),
+            );
+        }
+
+        for ty in &docs.api.types {
+            let id = path_to_id[&ty.path];
+            let methods = resolve_many(&ty.methods, &path_to_id, &mut external_crates);
+            let trait_impls = ty
+                .trait_impls
+                .iter()
+                .map(|t| resolve_trait_impl(t, &path_to_id, &mut external_crates))
+                .collect();
+            index.insert(
+                id,
+                Item::Type(TypeItem {
+                    name: ty.name.clone(),
+                    docs: ty.docs.clone(),
+                    type_kind: ty.kind.clone(),
+                    visibility: ty.visibility.clone(),
+                    fields: ty.fields.clone(),
+                    variants: ty.variants.clone(),
+                    methods,
+                    trait_impls,
+                }),
+            );
+        }
+
+        for constant in &docs.api.constants {
+            let id = path_to_id[&constant.path];
+            index.insert(
+                id,
+                Item::Constant(ConstantItem {
+                    name: constant.name.clone(),
+                    docs: constant.docs.clone(),
+                    const_type: constant.const_type.clone(),
+                    value: constant.value.clone(),
+                    visibility: constant.visibility.clone(),
+                }),
+            );
+        }
+
+        for macro_doc in &docs.api.macros {
+            let id = path_to_id[&macro_doc.path];
+            index.insert(
+                id,
+                Item::Macro(MacroItem {
+                    name: macro_doc.name.clone(),
+                    docs: macro_doc.docs.clone(),
+                    signature: macro_doc.signature.clone(),
+                    visibility: macro_doc.visibility.clone(),
+                    examples: macro_doc.examples.clone(),
+                }),
+            );
+        }
+
+        let root = Id(0);
+        paths.insert(
+            root,
+            PathInfo {
+                path: vec![docs.metadata.name.clone()],
+                kind: ItemKind::Module,
+            },
+        );
+        index.insert(
+            root,
+            Item::Module(ModuleItem {
+                name: docs.metadata.name.clone(),
+                docs: docs.metadata.description.clone().unwrap_or_default(),
+                visibility: Visibility::Public,
+                items: root_children(&path_to_id),
+            }),
+        );
+
+        Self {
+            format_version: FORMAT_VERSION,
+            root,
+            index,
+            paths,
+            external_crates,
+        }
+    }
+}
+
+/// Resolve `raw` against `path_to_id`; if it doesn't resolve, record it in
+/// `external_crates` and return `None`.
+fn resolve(
+    raw: &str,
+    path_to_id: &HashMap<String, Id>,
+    external_crates: &mut HashMap<String, ExternalItem>,
+) -> Option<Id> {
+    match path_to_id.get(raw) {
+        Some(id) => Some(*id),
+        None => {
+            external_crates
+                .entry(raw.to_string())
+                .or_insert_with(|| ExternalItem { name: raw.to_string() });
+            None
+        }
+    }
+}
+
+/// Resolve every path in `raw_paths`, dropping any that don't resolve
+/// locally (they are still recorded in `external_crates`).
+fn resolve_many(
+    raw_paths: &[String],
+    path_to_id: &HashMap<String, Id>,
+    external_crates: &mut HashMap<String, ExternalItem>,
+) -> Vec<Id> {
+    raw_paths
+        .iter()
+        .filter_map(|raw| resolve(raw, path_to_id, external_crates))
+        .collect()
+}
+
+fn resolve_trait_impl(
+    trait_impl: &TraitImplDoc,
+    path_to_id: &HashMap<String, Id>,
+    external_crates: &mut HashMap<String, ExternalItem>,
+) -> TraitImplItem {
+    TraitImplItem {
+        trait_name: trait_impl.trait_name.clone(),
+        trait_path: resolve(&trait_impl.trait_path, path_to_id, external_crates),
+        methods: resolve_many(&trait_impl.methods, path_to_id, external_crates),
+    }
+}
+
+/// Ids of every item whose path is a direct child of `parent_path`
+/// (exactly one more `::`-separated segment).
+fn children_of(parent_path: &str, path_to_id: &HashMap<String, Id>) -> Vec<Id> {
+    let prefix = format!("{}::", parent_path);
+    let mut children: Vec<(&str, Id)> = path_to_id
+        .iter()
+        .filter(|(path, _)| {
+            path.starts_with(&prefix) && !path[prefix.len()..].contains("::")
+        })
+        .map(|(path, id)| (path.as_str(), *id))
+        .collect();
+    children.sort_by_key(|(path, _)| *path);
+    children.into_iter().map(|(_, id)| id).collect()
+}
+
+/// Ids of every item that isn't nested under another item (no `::` in its
+/// path), i.e. the direct children of the synthetic crate root.
+fn root_children(path_to_id: &HashMap<String, Id>) -> Vec<Id> {
+    let mut children: Vec<(&str, Id)> = path_to_id
+        .iter()
+        .filter(|(path, _)| !path.contains("::"))
+        .map(|(path, id)| (path.as_str(), *id))
+        .collect();
+    children.sort_by_key(|(path, _)| *path);
+    children.into_iter().map(|(_, id)| id).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::docs::{ApiDocumentation, ProjectMetadata, ReferenceManual, SourceLocation};
+    use std::collections::HashMap;
+    use std::path::PathBuf;
+
+    fn empty_docs() -> Documentation {
+        Documentation {
+            metadata: ProjectMetadata {
+                name: "test_crate".to_string(),
+                version: "1.0.0".to_string(),
+                description: Some("A test crate".to_string()),
+                authors: vec![],
+                license: None,
+                homepage: None,
+                repository: None,
+                documentation_url: None,
+                generated_at: std::time::SystemTime::UNIX_EPOCH,
+            },
+            api: ApiDocumentation {
+                modules: vec![],
+                functions: vec![],
+                types: vec![],
+                constants: vec![],
+                macros: vec![],
+            },
+            tutorials: vec![],
+            examples: vec![],
+            reference: ReferenceManual {
+                sections: vec![],
+                appendices: vec![],
+                glossary: HashMap::new(),
+                index: vec![],
+            },
+            search_index: None,
+            book: None,
+            link_warnings: vec![],
+            xref_table: crate::docs::xref::XRefTable::default(),
+        }
+    }
+
+    fn loc() -> SourceLocation {
+        SourceLocation { file: PathBuf::from("test.aether"), line: 1, column: 1, span: 0 }
+    }
+
+    #[test]
+    fn test_root_is_always_present() {
+        let docs = empty_docs();
+        let graph = ItemGraph::build(&docs);
+
+        assert_eq!(graph.format_version, FORMAT_VERSION);
+        assert!(graph.index.contains_key(&graph.root));
+        assert!(matches!(graph.index[&graph.root], Item::Module(_)));
+    }
+
+    #[test]
+    fn test_function_related_resolves_to_local_id() {
+        let mut docs = empty_docs();
+        docs.api.functions.push(FunctionDoc {
+            name: "foo".to_string(),
+            path: "foo".to_string(),
+            description: None,
+            docs: String::new(),
+            signature: FunctionSignature {
+                name: "foo".to_string(),
+                type_parameters: vec![],
+                parameters: vec![],
+                return_type: None,
+                is_async: false,
+                is_unsafe: false,
+            },
+            visibility: Visibility::Public,
+            source_location: loc(),
+            parameters: vec![],
+            return_type: None,
+            examples: vec![],
+            related: vec!["bar".to_string()],
+        });
+        docs.api.functions.push(FunctionDoc {
+            name: "bar".to_string(),
+            path: "bar".to_string(),
+            description: None,
+            docs: String::new(),
+            signature: FunctionSignature {
+                name: "bar".to_string(),
+                type_parameters: vec![],
+                parameters: vec![],
+                return_type: None,
+                is_async: false,
+                is_unsafe: false,
+            },
+            visibility: Visibility::Public,
+            source_location: loc(),
+            parameters: vec![],
+            return_type: None,
+            examples: vec![],
+            related: vec![],
+        });
+
+        let graph = ItemGraph::build(&docs);
+        let foo_id = graph.paths.iter().find(|(_, p)| p.path == vec!["foo".to_string()]).unwrap().0;
+        let bar_id = *graph.paths.iter().find(|(_, p)| p.path == vec!["bar".to_string()]).unwrap().0;
+
+        match &graph.index[foo_id] {
+            Item::Function(f) => assert_eq!(f.related, vec![bar_id]),
+            _ => panic!("expected Function item"),
+        }
+        assert!(graph.external_crates.is_empty());
+    }
+
+    #[test]
+    fn test_unresolved_reference_becomes_external() {
+        let mut docs = empty_docs();
+        docs.api.functions.push(FunctionDoc {
+            name: "foo".to_string(),
+            path: "foo".to_string(),
+            description: None,
+            docs: String::new(),
+            signature: FunctionSignature {
+                name: "foo".to_string(),
+                type_parameters: vec![],
+                parameters: vec![],
+                return_type: None,
+                is_async: false,
+                is_unsafe: false,
+            },
+            visibility: Visibility::Public,
+            source_location: loc(),
+            parameters: vec![],
+            return_type: None,
+            examples: vec![],
+            related: vec!["std::collections::HashMap".to_string()],
+        });
+
+        let graph = ItemGraph::build(&docs);
+        let foo_id = *graph.paths.iter().find(|(_, p)| p.path == vec!["foo".to_string()]).unwrap().0;
+
+        match &graph.index[&foo_id] {
+            Item::Function(f) => assert!(f.related.is_empty()),
+            _ => panic!("expected Function item"),
+        }
+        assert!(graph.external_crates.contains_key("std::collections::HashMap"));
+    }
+
+    #[test]
+    fn test_module_children_nest_by_path() {
+        let mut docs = empty_docs();
+        docs.api.modules.push(ModuleDoc {
+            name: "outer".to_string(),
+            path: "outer".to_string(),
+            description: None,
+            docs: String::new(),
+            visibility: Visibility::Public,
+            source_location: loc(),
+            submodules: vec![],
+            items: vec![],
+            examples: vec![],
+            refname: "outer".to_string(),
+        });
+        docs.api.types.push(TypeDoc {
+            name: "Thing".to_string(),
+            path: "outer::Thing".to_string(),
+            description: None,
+            docs: String::new(),
+            kind: TypeKind::Struct,
+            visibility: Visibility::Public,
+            source_location: loc(),
+            type_parameters: vec![],
+            fields: vec![],
+            variants: vec![],
+            methods: vec![],
+            trait_impls: vec![],
+            examples: vec![],
+        });
+
+        let graph = ItemGraph::build(&docs);
+        let outer_id = *graph.paths.iter().find(|(_, p)| p.path == vec!["outer".to_string()]).unwrap().0;
+        let thing_id = *graph
+            .paths
+            .iter()
+            .find(|(_, p)| p.path == vec!["outer".to_string(), "Thing".to_string()])
+            .unwrap()
+            .0;
+
+        match &graph.index[&outer_id] {
+            Item::Module(m) => assert_eq!(m.items, vec![thing_id]),
+            _ => panic!("expected Module item"),
+        }
+        assert!(matches!(graph.paths[&thing_id].kind, ItemKind::Type));
+    }
+}
@@ -0,0 +1,331 @@
+// Copyright 2025 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Man pages and shell completions generated from the API reference
+//!
+//! One roff man page is emitted per top-level module plus a summary page
+//! listing all of them, mirroring how `clap_mangen` walks a `clap::Command`
+//! tree except here the tree being walked is [`ApiDocumentation`]. Shell
+//! completion scripts are generated from the same module/function metadata,
+//! treating every public top-level function as a command-line entry point.
+
+use crate::docs::{ApiDocumentation, Documentation, FunctionDoc, ModuleDoc, Visibility};
+use crate::error::SemanticError;
+use std::path::Path;
+
+/// A roff document under construction: push one macro or paragraph at a
+/// time, then [`RoffBuilder::build`] to join them into the final page.
+#[derive(Debug, Default)]
+struct RoffBuilder {
+    lines: Vec<String>,
+}
+
+impl RoffBuilder {
+    fn new() -> Self {
+        Self::default()
+    }
+
+    /// `.TH` title macro: page title, man section, and the source/manual
+    /// strings shown in the page header and footer.
+    fn title(mut self, title: &str, section: u8, source: &str, manual: &str) -> Self {
+        self.lines.push(format!(
+            ".TH \"{}\" \"{}\" \"\" \"{}\" \"{}\"",
+            title.to_uppercase(),
+            section,
+            source,
+            manual
+        ));
+        self
+    }
+
+    fn section(mut self, heading: &str) -> Self {
+        self.lines.push(format!(".SH {}", heading.to_uppercase()));
+        self
+    }
+
+    fn paragraph(mut self, text: &str) -> Self {
+        if text.is_empty() {
+            return self;
+        }
+        self.lines.push(".PP".to_string());
+        self.lines.push(escape_roff(text));
+        self
+    }
+
+    /// A hanging, indented paragraph, used for each function/type entry.
+    fn indented_paragraph(mut self, text: &str) -> Self {
+        self.lines.push(".IP".to_string());
+        self.lines.push(escape_roff(text));
+        self
+    }
+
+    fn build(self) -> String {
+        self.lines.join("\n") + "\n"
+    }
+}
+
+/// Escapes leading `.` and `'` on each line, which roff would otherwise
+/// interpret as a macro request.
+fn escape_roff(text: &str) -> String {
+    text.lines()
+        .map(|line| if line.starts_with('.') || line.starts_with('\'') { format!("\\&{}", line) } else { line.to_string() })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// A top-level module is one whose path contains no `::` separator.
+fn is_top_level(module: &ModuleDoc) -> bool {
+    !module.path.contains("::")
+}
+
+fn functions_of<'a>(api: &'a ApiDocumentation, module: &ModuleDoc) -> Vec<&'a FunctionDoc> {
+    api.functions
+        .iter()
+        .filter(|function| function.path.rsplit_once("::").map(|(parent, _)| parent) == Some(module.path.as_str()))
+        .collect()
+}
+
+fn render_module_page(module: &ModuleDoc, api: &ApiDocumentation, project: &str, section: u8) -> String {
+    let mut builder = RoffBuilder::new().title(&module.name, section, project, &format!("{} Reference", project));
+
+    let summary = module.description.clone().unwrap_or_else(|| module.name.clone());
+    builder = builder.section("NAME").paragraph(&format!("{} \\- {}", module.name, summary));
+    builder = builder.section("DESCRIPTION").paragraph(&module.docs);
+
+    let functions = functions_of(api, module);
+    if !functions.is_empty() {
+        builder = builder.section("FUNCTIONS");
+        for function in functions {
+            let params = function
+                .parameters
+                .iter()
+                .map(|p| p.name.as_str())
+                .collect::<Vec<_>>()
+                .join(", ");
+            builder = builder.indented_paragraph(&format!("{}({}) \\- {}", function.name, params, function.docs));
+        }
+    }
+
+    builder.build()
+}
+
+fn render_summary_page(api: &ApiDocumentation, project: &str, section: u8) -> String {
+    let mut builder = RoffBuilder::new()
+        .title(project, section, project, &format!("{} Reference", project))
+        .section("NAME")
+        .paragraph(&format!("{} \\- module index", project))
+        .section("MODULES");
+
+    for module in api.modules.iter().filter(|m| is_top_level(m)) {
+        builder = builder.indented_paragraph(&format!("{}({}) \\- {}", module.name, section, module.docs));
+    }
+
+    builder.build()
+}
+
+/// Writes one man page per top-level module, plus a `<project>.<section>`
+/// summary page, under `<output_dir>/man<section>/`.
+pub fn render_man_pages(docs: &Documentation, output_dir: &Path, section: u8) -> Result<(), SemanticError> {
+    let man_dir = output_dir.join(format!("man{}", section));
+    std::fs::create_dir_all(&man_dir)?;
+
+    let project = &docs.metadata.name;
+
+    for module in docs.api.modules.iter().filter(|m| is_top_level(m)) {
+        let page = render_module_page(module, &docs.api, project, section);
+        std::fs::write(man_dir.join(format!("{}.{}", module.name, section)), page)?;
+    }
+
+    let summary = render_summary_page(&docs.api, project, section);
+    std::fs::write(man_dir.join(format!("{}.{}", project.to_lowercase(), section)), summary)?;
+
+    Ok(())
+}
+
+/// Shells that [`render_completions`] can generate a script for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CompletionShell {
+    Bash,
+    Zsh,
+    Fish,
+}
+
+impl CompletionShell {
+    fn extension(self) -> &'static str {
+        match self {
+            CompletionShell::Bash => "bash",
+            CompletionShell::Zsh => "zsh",
+            CompletionShell::Fish => "fish",
+        }
+    }
+
+    const ALL: [CompletionShell; 3] = [CompletionShell::Bash, CompletionShell::Zsh, CompletionShell::Fish];
+}
+
+/// A public, top-level function is treated as a command-line entry point:
+/// these are the names offered for tab-completion.
+fn entry_points(api: &ApiDocumentation) -> Vec<&str> {
+    api.functions
+        .iter()
+        .filter(|f| matches!(f.visibility, Visibility::Public) && !f.path.contains("::"))
+        .map(|f| f.name.as_str())
+        .collect()
+}
+
+fn render_bash_completions(project: &str, commands: &[&str]) -> String {
+    format!(
+        "_{project}_complete() {{\n    local words=\"{commands}\"\n    COMPREPLY=($(compgen -W \"$words\" -- \"${{COMP_WORDS[COMP_CWORD]}}\"))\n}}\ncomplete -F _{project}_complete {project}\n",
+        project = project,
+        commands = commands.join(" "),
+    )
+}
+
+fn render_zsh_completions(project: &str, commands: &[&str]) -> String {
+    let entries = commands.iter().map(|c| format!("        '{}'", c)).collect::<Vec<_>>().join("\n");
+    format!(
+        "#compdef {project}\n\n_{project}() {{\n    local -a commands\n    commands=(\n{entries}\n    )\n    _describe 'command' commands\n}}\n\n_{project}\n",
+        project = project,
+        entries = entries,
+    )
+}
+
+fn render_fish_completions(project: &str, commands: &[&str]) -> String {
+    commands
+        .iter()
+        .map(|c| format!("complete -c {} -f -a '{}'", project, c))
+        .collect::<Vec<_>>()
+        .join("\n")
+        + "\n"
+}
+
+/// Writes a bash, zsh, and fish completion script under
+/// `<output_dir>/completions/`, listing every public top-level function as
+/// a completion candidate for `<project>`.
+pub fn render_completions(docs: &Documentation, output_dir: &Path) -> Result<(), SemanticError> {
+    let completions_dir = output_dir.join("completions");
+    std::fs::create_dir_all(&completions_dir)?;
+
+    let project = docs.metadata.name.to_lowercase();
+    let commands = entry_points(&docs.api);
+
+    for shell in CompletionShell::ALL {
+        let script = match shell {
+            CompletionShell::Bash => render_bash_completions(&project, &commands),
+            CompletionShell::Zsh => render_zsh_completions(&project, &commands),
+            CompletionShell::Fish => render_fish_completions(&project, &commands),
+        };
+        std::fs::write(completions_dir.join(format!("{}.{}", project, shell.extension())), script)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::docs::{FunctionSignature, SourceLocation};
+    use std::path::PathBuf;
+
+    fn function(name: &str, path: &str, visibility: Visibility) -> FunctionDoc {
+        FunctionDoc {
+            name: name.to_string(),
+            path: path.to_string(),
+            description: None,
+            docs: format!("{} does things.", name),
+            signature: FunctionSignature {
+                name: name.to_string(),
+                type_parameters: vec![],
+                parameters: vec![],
+                return_type: None,
+                is_async: false,
+                is_unsafe: false,
+            },
+            visibility,
+            source_location: SourceLocation { file: PathBuf::from("lib.rs"), line: 1, column: 1, span: 0 },
+            parameters: vec![],
+            return_type: None,
+            examples: vec![],
+            related: vec![],
+        }
+    }
+
+    fn module(name: &str, path: &str) -> ModuleDoc {
+        ModuleDoc {
+            name: name.to_string(),
+            path: path.to_string(),
+            description: Some(format!("the {} module", name)),
+            docs: format!("{} module docs.", name),
+            visibility: Visibility::Public,
+            source_location: SourceLocation { file: PathBuf::from("lib.rs"), line: 1, column: 1, span: 0 },
+            submodules: vec![],
+            items: vec![],
+            examples: vec![],
+            refname: crate::docs::xref::derive_refname(name),
+        }
+    }
+
+    #[test]
+    fn test_is_top_level_rejects_nested_module_path() {
+        assert!(is_top_level(&module("compiler", "compiler")));
+        assert!(!is_top_level(&module("lexer", "compiler::lexer")));
+    }
+
+    #[test]
+    fn test_functions_of_matches_direct_children_only() {
+        let api = ApiDocumentation {
+            modules: vec![module("compiler", "compiler")],
+            functions: vec![
+                function("compile", "compiler::compile", Visibility::Public),
+                function("tokenize", "compiler::lexer::tokenize", Visibility::Public),
+            ],
+            types: vec![],
+            constants: vec![],
+            macros: vec![],
+        };
+
+        let found = functions_of(&api, &api.modules[0]);
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].name, "compile");
+    }
+
+    #[test]
+    fn test_entry_points_excludes_private_and_nested_functions() {
+        let api = ApiDocumentation {
+            modules: vec![],
+            functions: vec![
+                function("run", "run", Visibility::Public),
+                function("hidden", "hidden", Visibility::Private),
+                function("nested", "compiler::nested", Visibility::Public),
+            ],
+            types: vec![],
+            constants: vec![],
+            macros: vec![],
+        };
+
+        assert_eq!(entry_points(&api), vec!["run"]);
+    }
+
+    #[test]
+    fn test_escape_roff_escapes_leading_macro_characters() {
+        let escaped = escape_roff(".SH not a heading\nordinary line");
+        assert_eq!(escaped, "\\&.SH not a heading\nordinary line");
+    }
+
+    #[test]
+    fn test_bash_completions_lists_every_command() {
+        let script = render_bash_completions("aether", &["compile", "run"]);
+        assert!(script.contains("compile run"));
+        assert!(script.contains("complete -F _aether_complete aether"));
+    }
+}
@@ -2,12 +2,21 @@
 //!
 //! Provides comprehensive documentation generation including API docs,
 //! tutorials, examples, and reference manuals with multiple output formats.
+//!
+//! Driven from the command line via `aether doc`, which builds a
+//! [`DocConfig`] from its flags and calls [`DocumentationGenerator::generate`].
 
 pub mod generator;
 pub mod parser;
 pub mod renderer;
 pub mod examples;
 pub mod tutorial;
+pub mod json_index;
+pub mod search;
+pub mod book;
+pub mod intra_doc_links;
+pub mod xref;
+pub mod manpage;
 
 use crate::error::SemanticError;
 use std::path::PathBuf;
@@ -31,6 +40,11 @@ pub struct DocumentationGenerator {
     
     /// Generated documentation
     documentation: Documentation,
+
+    /// One [`SearchIndex`] per enabled [`LanguageOptions`], keyed by its
+    /// `bcp47` identifier. Kept out of [`Documentation`] since it's a
+    /// per-render-pass view rather than part of the serialized doc tree.
+    search_indices: HashMap<String, SearchIndex>,
 }
 
 /// Documentation configuration
@@ -68,6 +82,56 @@ pub struct DocConfig {
     
     /// Search configuration
     pub search: SearchConfig,
+
+    /// Worker threads used to render pages in parallel. `None` lets the
+    /// renderer pick automatically (see [`renderer::Cache`]); `Some(1)`
+    /// forces fully sequential rendering.
+    pub threads: Option<usize>,
+
+    /// Path to an mdbook-style `SUMMARY.md` manifest. When set, the
+    /// referenced chapter tree is parsed, loaded, and preprocessed into a
+    /// [`book::Book`] alongside the generated API docs, tutorials, and
+    /// reference manual.
+    pub book_summary: Option<PathBuf>,
+
+    /// Languages to generate localized tutorials and a dedicated search
+    /// index for, each with its own tokenization/weighting. Empty by
+    /// default, in which case [`DocumentationGenerator`] falls back to a
+    /// single implicit `"en"` language built from `search`.
+    pub languages: Vec<LanguageOptions>,
+
+    /// Whether a dangling `@ref(name)` cross-reference fails
+    /// `render_documentation` outright. When `false`, broken references are
+    /// logged as warnings instead (see [`xref::find_broken_refs`]).
+    pub strict_xrefs: bool,
+
+    /// Whether `generate` compiles and runs every documented example (see
+    /// [`DocumentationGenerator::verify_examples`]), failing the build if
+    /// one no longer compiles, panics unexpectedly, or its captured stdout
+    /// no longer matches the declared expected output.
+    pub verify_examples: bool,
+}
+
+/// A single language edition of the generated docs: its own title,
+/// description, and [`SearchConfig`] so term weighting and tokenization
+/// can be tuned independently of other languages.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LanguageOptions {
+    /// BCP-47 language identifier, e.g. `"en"` or `"ja-JP"`
+    pub bcp47: String,
+
+    /// Localized project title shown in this language's tutorials
+    pub title: String,
+
+    /// Localized project description
+    pub description: String,
+
+    /// Whether to build a dedicated [`SearchIndex`] for this language
+    pub build_search_index: bool,
+
+    /// Search configuration for this language (weights, index type, stop
+    /// words are tokenized per `SearchConfig::index_type`)
+    pub search: SearchConfig,
 }
 
 /// Output formats for documentation
@@ -105,6 +169,18 @@ pub enum OutputFormat {
         pretty: bool,
         /// Include source locations
         include_source: bool,
+        /// Also emit the stable, Id-indexed item graph (see
+        /// [`crate::docs::json_index`]) alongside the plain tree dump
+        item_graph: bool,
+    },
+
+    /// Man pages and shell completions, generated from the API reference
+    /// (see [`crate::docs::manpage`])
+    ManPage {
+        /// Man page section (1-8)
+        section: u8,
+        /// Also emit bash/zsh/fish completion scripts
+        completions: bool,
     },
 }
 
@@ -178,6 +254,17 @@ pub struct SearchWeights {
     pub tags: f32,
 }
 
+impl Default for SearchWeights {
+    fn default() -> Self {
+        Self {
+            title: 2.0,
+            description: 1.5,
+            content: 1.0,
+            tags: 1.2,
+        }
+    }
+}
+
 /// Complete documentation structure
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Documentation {
@@ -198,6 +285,19 @@ pub struct Documentation {
     
     /// Search index
     pub search_index: Option<SearchIndex>,
+
+    /// Authored long-form book, parsed from `DocConfig::book_summary`
+    pub book: Option<book::Book>,
+
+    /// Intra-doc links that didn't resolve, populated by
+    /// [`DocumentationGenerator::generate_api_documentation`]
+    pub link_warnings: Vec<intra_doc_links::LinkWarning>,
+
+    /// `refname -> target` table for `@ref(name)` cross-references, built
+    /// from every module/example/reference-section plus (per render pass)
+    /// this edition's tutorials; see
+    /// [`DocumentationGenerator::render_documentation`].
+    pub xref_table: xref::XRefTable,
 }
 
 /// Project metadata
@@ -279,6 +379,11 @@ pub struct ModuleDoc {
     
     /// Examples
     pub examples: Vec<CodeExample>,
+
+    /// Stable cross-reference name, resolved from `@ref(name)` in other
+    /// documentable nodes' `content` fields; see
+    /// [`xref::XRefTable`](crate::docs::xref::XRefTable).
+    pub refname: String,
 }
 
 /// Function documentation
@@ -459,7 +564,7 @@ pub struct ItemSummary {
 }
 
 /// Item kinds
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum ItemKind {
     Function,
     Type,
@@ -670,6 +775,16 @@ pub struct Tutorial {
     
     /// Tags
     pub tags: Vec<String>,
+
+    /// BCP-47 language this tutorial edition was generated for (`"en"`,
+    /// the implicit language, for projects with no `DocConfig::languages`
+    /// configured), or `None` for a tutorial that every language edition
+    /// includes
+    pub language: Option<String>,
+
+    /// Stable cross-reference name; see
+    /// [`xref::XRefTable`](crate::docs::xref::XRefTable).
+    pub refname: String,
 }
 
 /// Tutorial section
@@ -742,6 +857,10 @@ pub struct Example {
     
     /// Tags
     pub tags: Vec<String>,
+
+    /// Stable cross-reference name; see
+    /// [`xref::XRefTable`](crate::docs::xref::XRefTable).
+    pub refname: String,
 }
 
 /// Reference manual
@@ -774,6 +893,10 @@ pub struct ManualSection {
     
     /// Cross-references
     pub references: Vec<CrossReference>,
+
+    /// Stable cross-reference name; see
+    /// [`xref::XRefTable`](crate::docs::xref::XRefTable).
+    pub refname: String,
 }
 
 /// Appendix
@@ -836,15 +959,26 @@ pub enum ReferenceType {
     Example,
 }
 
-/// Search index
+/// Search index: a BM25-ranked inverted index over [`SearchDocument`]s. See
+/// [`search`](crate::docs::search) for how `postings`/`idf` are built and
+/// queried.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SearchIndex {
     /// Indexed documents
     pub documents: Vec<SearchDocument>,
-    
-    /// Term index
-    pub terms: HashMap<String, Vec<usize>>,
-    
+
+    /// Inverted index: term -> doc index -> per-field term frequency
+    pub postings: HashMap<String, HashMap<usize, search::FieldFrequencies>>,
+
+    /// Per-document, per-field token counts
+    pub doc_lengths: Vec<search::DocumentLength>,
+
+    /// Corpus-wide average per-field document length (BM25 `avgdl`)
+    pub avg_doc_lengths: search::AverageDocumentLength,
+
+    /// Inverse document frequency per term (BM25 `IDF(t)`)
+    pub idf: HashMap<String, f64>,
+
     /// Metadata
     pub metadata: SearchMetadata,
 }
@@ -854,19 +988,22 @@ pub struct SearchIndex {
 pub struct SearchDocument {
     /// Document ID
     pub id: String,
-    
+
     /// Document title
     pub title: String,
-    
+
     /// Document URL
     pub url: String,
-    
+
+    /// Document description
+    pub description: String,
+
     /// Document content
     pub content: String,
-    
+
     /// Document type
     pub doc_type: DocumentType,
-    
+
     /// Tags
     pub tags: Vec<String>,
 }
@@ -935,14 +1072,18 @@ impl DocumentationGenerator {
                 index: vec![],
             },
             search_index: None,
+            book: None,
+            link_warnings: vec![],
+            xref_table: xref::XRefTable::default(),
         };
-        
+
         Ok(Self {
             config,
             parser,
             renderer,
             examples,
             documentation,
+            search_indices: HashMap::new(),
         })
     }
     
@@ -963,15 +1104,26 @@ impl DocumentationGenerator {
         
         // Generate reference manual
         self.generate_reference_manual()?;
-        
+
+        // Load the authored book, if configured
+        if self.config.book_summary.is_some() {
+            self.generate_book()?;
+        }
+
         // Build search index
         if self.config.search.enabled {
             self.build_search_index()?;
         }
-        
+
+        // Compile and run every documented example, catching ones that
+        // have rotted since they were written
+        if self.config.verify_examples {
+            self.verify_examples()?;
+        }
+
         // Render documentation in all formats
         self.render_documentation()?;
-        
+
         Ok(())
     }
     
@@ -979,28 +1131,73 @@ impl DocumentationGenerator {
     pub fn generate_api_documentation(&mut self) -> Result<(), SemanticError> {
         for source_dir in &self.config.source_dirs {
             let modules = self.parser.parse_directory(source_dir)?;
-            
+
             for module in modules {
                 self.documentation.api.modules.push(module);
             }
         }
-        
+
         // Extract functions, types, constants, and macros from modules
         self.extract_api_items()?;
-        
+
+        // Now that the full API graph is built, resolve intra-doc links
+        // (`[Type::method]`) into hyperlinks in place.
+        self.resolve_intra_doc_links();
+
         Ok(())
     }
+
+    /// Resolve intra-doc links across the API surface (see
+    /// [`intra_doc_links`]), logging a warning for each one that couldn't
+    /// be resolved.
+    fn resolve_intra_doc_links(&mut self) {
+        let graph = json_index::ItemGraph::build(&self.documentation);
+        let warnings = intra_doc_links::resolve_links(&mut self.documentation, &graph);
+        for warning in &warnings {
+            eprintln!("Warning: {}", warning);
+        }
+        self.documentation.link_warnings = warnings;
+    }
     
-    /// Generate tutorials
+    /// Generate one edition of each tutorial per configured language (see
+    /// `DocConfig::languages`), tagging each with its `bcp47` identifier
+    /// and overriding the title from `LanguageOptions` where given.
     pub fn generate_tutorials(&mut self) -> Result<(), SemanticError> {
-        let tutorial_content = self.create_language_tutorial()?;
-        self.documentation.tutorials.push(tutorial_content);
-        
-        let advanced_tutorial = self.create_advanced_tutorial()?;
-        self.documentation.tutorials.push(advanced_tutorial);
-        
+        for language in self.effective_languages() {
+            let mut tutorial_content = self.create_language_tutorial()?;
+            tutorial_content.language = Some(language.bcp47.clone());
+            if !language.title.is_empty() {
+                tutorial_content.title = language.title.clone();
+            }
+            if !language.description.is_empty() {
+                tutorial_content.description = language.description.clone();
+            }
+            self.documentation.tutorials.push(tutorial_content);
+
+            let mut advanced_tutorial = self.create_advanced_tutorial()?;
+            advanced_tutorial.language = Some(language.bcp47.clone());
+            self.documentation.tutorials.push(advanced_tutorial);
+        }
+
         Ok(())
     }
+
+    /// The languages to generate tutorials/search indices for: the
+    /// configured `DocConfig::languages`, or a single implicit `"en"`
+    /// language built from `search` when none are configured.
+    fn effective_languages(&self) -> Vec<LanguageOptions> {
+        if self.config.languages.is_empty() {
+            vec![LanguageOptions {
+                bcp47: "en".to_string(),
+                title: String::new(),
+                description: String::new(),
+                build_search_index: self.config.search.enabled,
+                search: self.config.search.clone(),
+            }]
+        } else {
+            self.config.languages.clone()
+        }
+    }
     
     /// Generate examples
     pub fn generate_examples(&mut self) -> Result<(), SemanticError> {
@@ -1027,86 +1224,184 @@ impl DocumentationGenerator {
         Ok(())
     }
     
-    /// Build search index
+    /// Load and preprocess the authored book from `config.book_summary`
+    pub fn generate_book(&mut self) -> Result<(), SemanticError> {
+        let summary_path = self.config.book_summary.clone().ok_or_else(|| SemanticError::Internal {
+            message: "generate_book called without a configured book_summary".to_string(),
+        })?;
+
+        let mut book = book::Book::load(&summary_path)?;
+
+        let root_dir = summary_path.parent().unwrap_or_else(|| std::path::Path::new(".")).to_path_buf();
+        let preprocessors: Vec<Box<dyn book::DocPreprocessor>> = vec![Box::new(book::IncludePreprocessor { root_dir })];
+        book.preprocess(&preprocessors)?;
+
+        self.documentation.book = Some(book);
+
+        Ok(())
+    }
+
+    /// Compile and run every example reachable from the generated
+    /// documentation (see [`examples::run_doctests`]), failing with the
+    /// full list of mismatches if any example didn't pass. Doc comments
+    /// turn into doctests this way, the same as `Example`s.
+    pub fn verify_examples(&self) -> Result<(), SemanticError> {
+        let report = examples::run_doctests(&self.documentation)?;
+        if report.all_passed() {
+            return Ok(());
+        }
+
+        let failures: Vec<String> = report
+            .results
+            .iter()
+            .filter(|r| r.is_failure())
+            .map(|r| format!("{}: {:?}", r.name, r.outcome))
+            .collect();
+
+        Err(SemanticError::Internal {
+            message: format!("{} example(s) failed verification: {}", failures.len(), failures.join("; ")),
+        })
+    }
+
+    /// Build one [`SearchIndex`] per language with `build_search_index`
+    /// enabled (see `DocConfig::languages`), storing each under its
+    /// `bcp47` identifier.
     pub fn build_search_index(&mut self) -> Result<(), SemanticError> {
+        for language in self.effective_languages() {
+            if !language.build_search_index {
+                continue;
+            }
+            let index = self.build_search_index_for_language(&language);
+            self.search_indices.insert(language.bcp47.clone(), index);
+        }
+
+        Ok(())
+    }
+
+    /// Build a single language's [`SearchIndex`]: every API module (shared
+    /// across languages) plus only that language's tutorial editions.
+    fn build_search_index_for_language(&self, language: &LanguageOptions) -> SearchIndex {
         let mut documents = Vec::new();
         let mut doc_id = 0;
-        
+
         // Index API documentation
         for module in &self.documentation.api.modules {
             documents.push(SearchDocument {
                 id: format!("module_{}", doc_id),
                 title: module.name.clone(),
                 url: format!("api/{}.html", module.path),
-                content: format!("{} {}", module.description.as_deref().unwrap_or(""), module.docs),
+                description: module.description.clone().unwrap_or_default(),
+                content: module.docs.clone(),
                 doc_type: DocumentType::Module,
                 tags: vec!["api".to_string(), "module".to_string()],
             });
             doc_id += 1;
         }
-        
-        // Index tutorials
+
+        // Index this language's tutorials
         for tutorial in &self.documentation.tutorials {
+            if tutorial.language.as_deref().is_some_and(|l| l != language.bcp47) {
+                continue;
+            }
             documents.push(SearchDocument {
                 id: format!("tutorial_{}", doc_id),
                 title: tutorial.title.clone(),
                 url: format!("tutorials/{}.html", tutorial.title.to_lowercase().replace(' ', "_")),
-                content: format!("{} {}", tutorial.description, tutorial.content),
+                description: tutorial.description.clone(),
+                content: tutorial.content.clone(),
                 doc_type: DocumentType::Tutorial,
                 tags: tutorial.tags.clone(),
             });
             doc_id += 1;
         }
-        
-        // Build term index
-        let mut terms = HashMap::new();
-        for (idx, doc) in documents.iter().enumerate() {
-            let words: Vec<&str> = doc.content.split_whitespace().collect();
-            for word in words {
-                let normalized_word = word.to_lowercase();
-                terms.entry(normalized_word)
-                    .or_insert_with(Vec::new)
-                    .push(idx);
-            }
-        }
-        
-        self.documentation.search_index = Some(SearchIndex {
+
+        // Build the BM25 inverted index: per-field term frequencies, per-field
+        // document lengths, corpus averages, and the IDF table.
+        let (postings, doc_lengths, avg_doc_lengths, idf) = search::build_index(&documents);
+        let total_terms = postings.len();
+        // Size of the payload the client-side search actually downloads
+        // (see `render_json`'s `serde_json::to_string(search_index)`).
+        let index_size = serde_json::to_vec(&(&documents, &postings, &doc_lengths, &avg_doc_lengths, &idf))
+            .map(|bytes| bytes.len())
+            .unwrap_or(0);
+
+        SearchIndex {
             documents,
-            terms,
+            postings,
+            doc_lengths,
+            avg_doc_lengths,
+            idf,
             metadata: SearchMetadata {
                 total_documents: doc_id,
-                total_terms: 0, // Would be calculated properly
-                index_size: 0,  // Would be calculated properly
+                total_terms,
+                index_size,
                 last_updated: std::time::SystemTime::now(),
             },
-        });
-        
-        Ok(())
+        }
     }
     
     /// Render documentation in all configured formats
+    /// Render documentation in all configured formats, once per language.
+    /// With more than one language configured, each gets its own
+    /// `<output_dir>/<bcp47>` subtree carrying only that language's
+    /// tutorials and search index; API docs, examples, and the reference
+    /// manual are shared across languages.
     pub fn render_documentation(&mut self) -> Result<(), SemanticError> {
         std::fs::create_dir_all(&self.config.output_dir)?;
-        
-        for format in &self.config.output_formats {
-            match format {
-                OutputFormat::Html { .. } => {
-                    self.renderer.render_html(&self.documentation, &self.config.output_dir)?;
-                }
-                OutputFormat::Markdown { .. } => {
-                    self.renderer.render_markdown(&self.documentation, &self.config.output_dir)?;
-                }
-                OutputFormat::Pdf { .. } => {
-                    self.renderer.render_pdf(&self.documentation, &self.config.output_dir)?;
-                }
-                OutputFormat::Json { .. } => {
-                    self.renderer.render_json(&self.documentation, &self.config.output_dir)?;
+
+        let languages = self.effective_languages();
+        let multi_language = languages.len() > 1;
+
+        for language in &languages {
+            let output_dir =
+                if multi_language { self.config.output_dir.join(&language.bcp47) } else { self.config.output_dir.clone() };
+            std::fs::create_dir_all(&output_dir)?;
+
+            let mut language_docs = self.documentation_for_language(language);
+            xref::resolve_and_rewrite(&mut language_docs, self.config.strict_xrefs)?;
+
+            for format in &self.config.output_formats {
+                match format {
+                    OutputFormat::Html { .. } => {
+                        self.renderer.render_html(&language_docs, &output_dir)?;
+                    }
+                    OutputFormat::Markdown { .. } => {
+                        self.renderer.render_markdown(&language_docs, &output_dir)?;
+                    }
+                    OutputFormat::Pdf { .. } => {
+                        self.renderer.render_pdf(&language_docs, &output_dir)?;
+                    }
+                    OutputFormat::Json { item_graph, .. } => {
+                        self.renderer.render_json(&language_docs, &output_dir, *item_graph)?;
+                    }
+                    OutputFormat::ManPage { section, completions } => {
+                        self.renderer.render_man_pages(&language_docs, &output_dir, *section)?;
+                        if *completions {
+                            self.renderer.render_completions(&language_docs, &output_dir)?;
+                        }
+                    }
                 }
             }
         }
-        
+
         Ok(())
     }
+
+    /// A snapshot of `self.documentation` scoped to `language`: its
+    /// tutorials filtered to this language (or untagged), and its search
+    /// index swapped in from `self.search_indices`.
+    fn documentation_for_language(&self, language: &LanguageOptions) -> Documentation {
+        let mut docs = self.documentation.clone();
+        docs.tutorials = self
+            .documentation
+            .tutorials
+            .iter()
+            .filter(|t| t.language.as_deref().is_none_or(|l| l == language.bcp47))
+            .cloned()
+            .collect();
+        docs.search_index = self.search_indices.get(&language.bcp47).cloned();
+        docs
+    }
     
     // Helper methods for generating content
     
@@ -1142,9 +1437,11 @@ impl DocumentationGenerator {
             difficulty: DifficultyLevel::Beginner,
             estimated_time: Some("2 hours".to_string()),
             tags: vec!["tutorial".to_string(), "beginner".to_string()],
+            language: None,
+            refname: xref::derive_refname("AetherScript Language Tutorial"),
         })
     }
-    
+
     fn create_advanced_tutorial(&self) -> Result<Tutorial, SemanticError> {
         Ok(Tutorial {
             title: "Advanced AetherScript Concepts".to_string(),
@@ -1155,24 +1452,28 @@ impl DocumentationGenerator {
             difficulty: DifficultyLevel::Advanced,
             estimated_time: Some("4 hours".to_string()),
             tags: vec!["tutorial".to_string(), "advanced".to_string()],
+            language: None,
+            refname: xref::derive_refname("Advanced AetherScript Concepts"),
         })
     }
-    
+
     fn create_syntax_section(&self) -> Result<ManualSection, SemanticError> {
         Ok(ManualSection {
             title: "Language Syntax".to_string(),
             content: "Complete syntax specification for AetherScript".to_string(),
             subsections: vec![],
             references: vec![],
+            refname: xref::derive_refname("Language Syntax"),
         })
     }
-    
+
     fn create_stdlib_section(&self) -> Result<ManualSection, SemanticError> {
         Ok(ManualSection {
             title: "Standard Library".to_string(),
             content: "Reference for the AetherScript standard library".to_string(),
             subsections: vec![],
             references: vec![],
+            refname: xref::derive_refname("Standard Library"),
         })
     }
     
@@ -1203,6 +1504,11 @@ impl Default for DocConfig {
             output_formats: vec![],
             theme: ThemeConfig::default(),
             search: SearchConfig::default(),
+            threads: None,
+            book_summary: None,
+            languages: vec![],
+            strict_xrefs: true,
+            verify_examples: false,
         }
     }
 }
@@ -1227,12 +1533,7 @@ impl Default for SearchConfig {
             enabled: true,
             index_type: SearchIndexType::ClientSide,
             max_results: 50,
-            weights: SearchWeights {
-                title: 2.0,
-                description: 1.5,
-                content: 1.0,
-                tags: 1.2,
-            },
+            weights: SearchWeights::default(),
         }
     }
 }
@@ -1286,4 +1587,55 @@ mod tests {
         assert_eq!(tutorial.title, "AetherScript Language Tutorial");
         assert!(matches!(tutorial.difficulty, DifficultyLevel::Beginner));
     }
+
+    #[test]
+    fn test_effective_languages_falls_back_to_single_implicit_language() {
+        let config = DocConfig::default();
+        let generator = DocumentationGenerator::new(config).unwrap();
+
+        let languages = generator.effective_languages();
+        assert_eq!(languages.len(), 1);
+        assert_eq!(languages[0].bcp47, "en");
+    }
+
+    #[test]
+    fn test_per_language_tutorials_and_search_indices() {
+        let mut config = DocConfig::default();
+        config.languages = vec![
+            LanguageOptions {
+                bcp47: "en".to_string(),
+                title: "AetherScript".to_string(),
+                description: String::new(),
+                build_search_index: true,
+                search: SearchConfig::default(),
+            },
+            LanguageOptions {
+                bcp47: "ja-JP".to_string(),
+                title: "AetherScript 日本語版".to_string(),
+                description: String::new(),
+                build_search_index: true,
+                search: SearchConfig::default(),
+            },
+        ];
+
+        let mut generator = DocumentationGenerator::new(config).unwrap();
+        generator.generate_tutorials().unwrap();
+        generator.build_search_index().unwrap();
+
+        assert_eq!(generator.documentation.tutorials.len(), 4);
+        assert!(generator.documentation.tutorials.iter().any(|t| t.language.as_deref() == Some("en") && t.title == "AetherScript"));
+        assert!(generator
+            .documentation
+            .tutorials
+            .iter()
+            .any(|t| t.language.as_deref() == Some("ja-JP") && t.title == "AetherScript 日本語版"));
+
+        assert_eq!(generator.search_indices.len(), 2);
+        assert!(generator.search_indices.contains_key("en"));
+        assert!(generator.search_indices.contains_key("ja-JP"));
+
+        let en_docs = generator.documentation_for_language(&generator.effective_languages()[0]);
+        assert_eq!(en_docs.tutorials.len(), 2);
+        assert!(en_docs.tutorials.iter().all(|t| t.language.as_deref() == Some("en")));
+    }
 }
\ No newline at end of file
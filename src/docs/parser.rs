@@ -175,6 +175,7 @@ impl DocParser {
         
         // Extract documentation for each item
         let mut module_doc = ModuleDoc {
+            refname: crate::docs::xref::derive_refname(&module_name),
             name: module_name.clone(),
             path: module_name,
             description: None,
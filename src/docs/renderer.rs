@@ -20,23 +20,42 @@
 use crate::docs::OutputFormat;
 use crate::error::SemanticError;
 use crate::docs::{
-    Documentation, DocConfig, ModuleDoc,
+    CodeExample, Documentation, DocConfig, ExampleType, ModuleDoc,
     Tutorial, Example, SearchIndex, ThemeConfig
 };
+use crate::docs::book;
+use rayon::prelude::*;
 use std::path::PathBuf;
 use std::collections::HashMap;
+use syntect::easy::HighlightLines;
+use syntect::highlighting::ThemeSet;
+use syntect::html::{styled_line_to_highlighted_html, IncludeBackground};
+use syntect::parsing::{SyntaxDefinition, SyntaxSet};
+use syntect::util::{as_24_bit_terminal_escaped, LinesWithEndings};
 
 /// Documentation renderer
 #[derive(Debug)]
 pub struct DocRenderer {
     /// Template engine for rendering
     template_engine: TemplateEngine,
-    
+
     /// Asset manager for static files
     asset_manager: AssetManager,
-    
+
     /// Output format
     output_format: OutputFormat,
+
+    /// Worker threads for the parallel HTML page render fan-out; `None`
+    /// lets rayon pick automatically. See [`Cache`].
+    threads: Option<usize>,
+
+    /// Code block highlighter, built once and shared by reference through
+    /// [`Cache`] across the render thread pool.
+    highlighter: SyntaxHighlighter,
+
+    /// Theme configuration driving `<pre>` styling for highlighted code
+    /// blocks.
+    theme: ThemeConfig,
 }
 
 /// Template engine for rendering documentation
@@ -141,6 +160,129 @@ pub struct AssetProcessor {
 pub struct ThemeManager {
 }
 
+/// `sublime-syntax` definition for AetherScript, registered with the
+/// [`SyntaxHighlighter`]'s [`SyntaxSet`] so `(println ...)` s-expression
+/// forms highlight as keywords/strings/comments instead of falling back to
+/// plain text.
+const AETHER_SYNTAX: &str = r#"
+%YAML 1.2
+---
+name: AetherScript
+file_extensions: [aether, aes]
+scope: source.aetherscript
+contexts:
+  main:
+    - match: ';.*$'
+      scope: comment.line.semicolon.aetherscript
+    - match: '"'
+      scope: punctuation.definition.string.begin.aetherscript
+      push: string
+    - match: '[()]'
+      scope: punctuation.section.parens.aetherscript
+    - match: '\b(println|print|def|defn|fn|let|if|else|match|loop|while|return|true|false|nil)\b'
+      scope: keyword.control.aetherscript
+    - match: '-?\b\d+(\.\d+)?\b'
+      scope: constant.numeric.aetherscript
+  string:
+    - meta_scope: string.quoted.double.aetherscript
+    - match: '\\.'
+      scope: constant.character.escape.aetherscript
+    - match: '"'
+      scope: punctuation.definition.string.end.aetherscript
+      pop: true
+"#;
+
+/// Syntax-highlights [`CodeExample`](crate::docs::CodeExample) blocks for
+/// HTML (and ANSI terminal preview) rendering, backed by `syntect`.
+///
+/// Parsing the bundled `.sublime-syntax`/`.tmTheme` defaults is expensive,
+/// so a single instance is built once in [`DocRenderer::new`] and shared by
+/// reference through [`Cache`] across the render thread pool, rather than
+/// re-parsed per page.
+#[derive(Debug)]
+pub struct SyntaxHighlighter {
+    syntax_set: SyntaxSet,
+    theme_set: ThemeSet,
+}
+
+impl SyntaxHighlighter {
+    /// Load the bundled syntax/theme defaults and register [`AETHER_SYNTAX`]
+    /// so AetherScript examples highlight correctly.
+    fn new() -> Result<Self, SemanticError> {
+        let mut builder = SyntaxSet::load_defaults_newlines().into_builder();
+        let aether_syntax = SyntaxDefinition::load_from_str(AETHER_SYNTAX, true, Some("aetherscript"))
+            .map_err(|e| SemanticError::Internal {
+                message: format!("Failed to load AetherScript syntax definition: {}", e),
+            })?;
+        builder.add(aether_syntax);
+
+        Ok(Self {
+            syntax_set: builder.build(),
+            theme_set: ThemeSet::load_defaults(),
+        })
+    }
+
+    /// Resolve `language` (a [`CodeExample::language`](crate::docs::CodeExample::language)
+    /// token, e.g. `"aetherscript"`) to a loaded syntax, falling back to
+    /// plain text when unknown.
+    fn syntax_for(&self, language: &str) -> &syntect::parsing::SyntaxReference {
+        self.syntax_set
+            .find_syntax_by_token(language)
+            .unwrap_or_else(|| self.syntax_set.find_syntax_plain_text())
+    }
+
+    /// Resolve a theme by the name carried in `OutputFormat::Html`'s
+    /// `theme` field (e.g. `"default"`, `"dark"`), falling back to
+    /// `InspiredGitHub` when unrecognized.
+    fn theme_for(&self, theme_name: &str) -> &syntect::highlighting::Theme {
+        let key = match theme_name {
+            "dark" => "base16-ocean.dark",
+            _ => "InspiredGitHub",
+        };
+        self.theme_set
+            .themes
+            .get(key)
+            .unwrap_or_else(|| &self.theme_set.themes["InspiredGitHub"])
+    }
+
+    /// Highlight `code` into `<span style=...>`-wrapped HTML (no wrapping
+    /// `<pre>`/`<code>` tags — callers own the surrounding markup so they
+    /// can apply `ThemeConfig::code_font_family`).
+    pub fn highlight_html(&self, code: &str, language: &str, theme_name: &str) -> String {
+        let syntax = self.syntax_for(language);
+        let theme = self.theme_for(theme_name);
+        let mut highlighter = HighlightLines::new(syntax, theme);
+
+        let mut html = String::new();
+        for line in LinesWithEndings::from(code) {
+            let Ok(ranges) = highlighter.highlight_line(line, &self.syntax_set) else {
+                html.push_str(line);
+                continue;
+            };
+            if let Ok(escaped) = styled_line_to_highlighted_html(&ranges[..], IncludeBackground::No) {
+                html.push_str(&escaped);
+            }
+        }
+        html
+    }
+
+    /// Highlight `code` into an ANSI-escaped string for a terminal preview.
+    pub fn highlight_ansi(&self, code: &str, language: &str, theme_name: &str) -> String {
+        let syntax = self.syntax_for(language);
+        let theme = self.theme_for(theme_name);
+        let mut highlighter = HighlightLines::new(syntax, theme);
+
+        let mut ansi = String::new();
+        for line in LinesWithEndings::from(code) {
+            if let Ok(ranges) = highlighter.highlight_line(line, &self.syntax_set) {
+                ansi.push_str(&as_24_bit_terminal_escaped(&ranges[..], false));
+            }
+        }
+        ansi.push_str("\x1b[0m");
+        ansi
+    }
+}
+
 /// Theme definition
 #[derive(Debug, Clone)]
 pub struct Theme {
@@ -163,23 +305,326 @@ pub struct Theme {
     pub custom_js: Option<String>,
 }
 
-/// Rendering context
+/// Lightweight, cloneable description of the page a single render worker is
+/// producing. Created fresh per page and combined with the shared [`Cache`]
+/// rather than carrying the whole [`Documentation`] or [`SearchIndex`]
+/// across the thread pool.
 #[derive(Debug, Clone)]
 pub struct RenderContext {
-    /// Current module path
-    pub module_path: String,
-    
-    /// Base URL
+    /// Id of the page being rendered, e.g. `"api/my_module"`
+    pub page_id: String,
+
+    /// Relative URL prefix back to the documentation root
     pub base_url: String,
-    
-    /// Template variables
+
+    /// Template variables specific to this page
     pub variables: HashMap<String, String>,
-    
-    /// Navigation structure
+}
+
+impl RenderContext {
+    /// Start a context for the page at `page_id`, deriving `base_url` from
+    /// its nesting depth (e.g. `"api/my_module"` -> `"../"`).
+    fn for_page(page_id: impl Into<String>) -> Self {
+        let page_id = page_id.into();
+        let depth = page_id.matches('/').count();
+        Self {
+            base_url: "../".repeat(depth),
+            page_id,
+            variables: HashMap::new(),
+        }
+    }
+}
+
+/// Immutable, `Send + Sync` snapshot of the fully-resolved documentation
+/// set. Built once via [`Cache::build`] right after
+/// `generate_api_documentation` and then shared *by reference* across the
+/// render thread pool in [`DocRenderer::render_html`]: every worker pairs it
+/// with its own page-specific [`RenderContext`] instead of re-walking or
+/// cloning the whole [`Documentation`].
+#[derive(Debug)]
+pub struct Cache<'a> {
+    /// Stable Id-indexed item graph, for cross-reference resolution
+    pub item_graph: crate::docs::json_index::ItemGraph,
+
+    /// Resolved output URL for every documented item, keyed by kind and
+    /// name or title (e.g. `"module_my_module"`, `"tutorial_Getting Started"`)
+    pub urls: HashMap<String, String>,
+
+    /// Navigation tree shared by every rendered page
     pub navigation: NavigationTree,
-    
-    /// Search index
-    pub search_index: Option<SearchIndex>,
+
+    /// Code block highlighter, borrowed from [`DocRenderer`] so its
+    /// `SyntaxSet`/`ThemeSet` are parsed once per render, not per page.
+    highlighter: &'a SyntaxHighlighter,
+
+    /// Syntect theme name to highlight with, see
+    /// [`DocRenderer::syntax_theme_name`]
+    syntax_theme: &'a str,
+
+    /// Theme configuration, for `ThemeConfig::code_font_family`
+    theme: &'a ThemeConfig,
+
+    /// Whether to render runnable [`CodeExample`]s as an editable
+    /// playground instead of static highlighted code, mirrored from
+    /// `OutputFormat::Html { javascript, .. }`; see [`render_code_example`](Cache::render_code_example).
+    playground: bool,
+}
+
+impl<'a> Cache<'a> {
+    /// Crawl `docs` once into an immutable snapshot that every render
+    /// worker can share by reference.
+    pub fn build(
+        docs: &Documentation,
+        highlighter: &'a SyntaxHighlighter,
+        syntax_theme: &'a str,
+        theme: &'a ThemeConfig,
+        playground: bool,
+    ) -> Self {
+        let item_graph = crate::docs::json_index::ItemGraph::build(docs);
+        let urls = Self::build_urls(docs);
+        let navigation = Self::build_navigation(docs, &urls);
+
+        Self {
+            item_graph,
+            urls,
+            navigation,
+            highlighter,
+            syntax_theme,
+            theme,
+            playground,
+        }
+    }
+
+    /// Highlight a single [`CodeExample`] into an HTML fragment:
+    /// title/description, then its code. A runnable example (`Basic` or
+    /// `Test`) renders as an editable playground when `self.playground` is
+    /// set (see [`DocConfig::output_format`]`::Html::javascript`);
+    /// otherwise it's wrapped in a `<pre>` styled with
+    /// `ThemeConfig::code_font_family`, same as a non-runnable example.
+    fn render_code_example(&self, example: &CodeExample) -> String {
+        let mut html = String::new();
+        html.push_str("<div class=\"code-example\">\n");
+        if let Some(title) = &example.title {
+            html.push_str(&format!("<h4>{}</h4>\n", title));
+        }
+        if let Some(description) = &example.description {
+            html.push_str(&format!("<p>{}</p>\n", description));
+        }
+
+        if self.playground && is_runnable(example) {
+            let id = code_example_id(example);
+            html.push_str(&format!(
+                "<div class=\"playground\" data-example-id=\"{id}\">\n\
+                 <textarea class=\"playground-source\" spellcheck=\"false\">{code}</textarea>\n\
+                 <div class=\"playground-controls\"><button class=\"playground-run\" data-example-id=\"{id}\">Run</button></div>\n\
+                 <pre class=\"playground-output\" data-example-id=\"{id}\"></pre>\n\
+                 </div>\n",
+                id = id,
+                code = html_escape(&example.code),
+            ));
+        } else {
+            let highlighted = self.highlighter.highlight_html(&example.code, &example.language, self.syntax_theme);
+            html.push_str(&format!(
+                "<pre style=\"font-family: {};\"><code class=\"language-{}\">{}</code></pre>\n",
+                self.theme.code_font_family, example.language, highlighted,
+            ));
+        }
+
+        if let Some(output) = &example.output {
+            html.push_str(&format!("<pre class=\"output\">{}</pre>\n", output));
+        }
+        html.push_str("</div>\n");
+        html
+    }
+
+    fn build_urls(docs: &Documentation) -> HashMap<String, String> {
+        let mut urls = HashMap::new();
+
+        for module in &docs.api.modules {
+            urls.insert(format!("module_{}", module.name), format!("api/{}.html", module.name));
+        }
+        for tutorial in &docs.tutorials {
+            let slug = tutorial.title.to_lowercase().replace(' ', "_");
+            urls.insert(format!("tutorial_{}", tutorial.title), format!("tutorials/{}.html", slug));
+        }
+        for example in &docs.examples {
+            let slug = example.name.to_lowercase().replace(' ', "_");
+            urls.insert(format!("example_{}", example.name), format!("examples/{}.html", slug));
+        }
+        for section in &docs.reference.sections {
+            let slug = section.title.to_lowercase().replace(' ', "_");
+            urls.insert(format!("reference_{}", section.title), format!("reference/{}.html", slug));
+        }
+
+        urls
+    }
+
+    fn build_navigation(docs: &Documentation, urls: &HashMap<String, String>) -> NavigationTree {
+        let mut items = Vec::new();
+
+        for module in &docs.api.modules {
+            items.push(NavigationItem {
+                title: module.name.clone(),
+                url: urls.get(&format!("module_{}", module.name)).cloned().unwrap_or_default(),
+                item_type: NavigationType::Module,
+                children: Vec::new(),
+                active: false,
+            });
+        }
+        for tutorial in &docs.tutorials {
+            items.push(NavigationItem {
+                title: tutorial.title.clone(),
+                url: urls.get(&format!("tutorial_{}", tutorial.title)).cloned().unwrap_or_default(),
+                item_type: NavigationType::Tutorial,
+                children: Vec::new(),
+                active: false,
+            });
+        }
+        for example in &docs.examples {
+            items.push(NavigationItem {
+                title: example.name.clone(),
+                url: urls.get(&format!("example_{}", example.name)).cloned().unwrap_or_default(),
+                item_type: NavigationType::Example,
+                children: Vec::new(),
+                active: false,
+            });
+        }
+
+        NavigationTree { items, active_item: None }
+    }
+
+    /// Render a single module's API page.
+    fn render_module_page(&self, module: &ModuleDoc, output_dir: &PathBuf) -> Result<(), SemanticError> {
+        let mut ctx = RenderContext::for_page(format!("api/{}", module.name));
+        ctx.variables.insert("module_name".to_string(), module.name.clone());
+        ctx.variables.insert(
+            "module_description".to_string(),
+            module.description.as_deref().unwrap_or("").to_string(),
+        );
+        ctx.variables.insert(
+            "examples_html".to_string(),
+            module.examples.iter().map(|e| self.render_code_example(e)).collect(),
+        );
+
+        let rendered = render_template("module", &ctx.variables)?;
+        std::fs::write(output_dir.join(format!("{}.html", module.name)), rendered)?;
+        Ok(())
+    }
+
+    /// Render a single tutorial page.
+    fn render_tutorial_page(&self, tutorial: &Tutorial, output_dir: &PathBuf) -> Result<(), SemanticError> {
+        let mut ctx = RenderContext::for_page(format!("tutorials/{}", tutorial.title));
+        ctx.variables.insert("title".to_string(), tutorial.title.clone());
+        ctx.variables.insert("description".to_string(), tutorial.description.clone());
+        ctx.variables.insert("content".to_string(), tutorial.content.clone());
+        ctx.variables.insert(
+            "examples_html".to_string(),
+            tutorial
+                .sections
+                .iter()
+                .flat_map(|section| &section.examples)
+                .map(|e| self.render_code_example(e))
+                .collect(),
+        );
+
+        let rendered = render_template("tutorial", &ctx.variables)?;
+        let filename = format!("{}.html", tutorial.title.to_lowercase().replace(' ', "_"));
+        std::fs::write(output_dir.join(filename), rendered)?;
+        Ok(())
+    }
+
+    /// Render a single example page.
+    fn render_example_page(&self, example: &Example, output_dir: &PathBuf) -> Result<(), SemanticError> {
+        let mut ctx = RenderContext::for_page(format!("examples/{}", example.name));
+        ctx.variables.insert("name".to_string(), example.name.clone());
+        ctx.variables.insert("description".to_string(), example.description.clone());
+        ctx.variables.insert("source_code".to_string(), example.source_code.clone());
+        ctx.variables.insert(
+            "highlighted_code".to_string(),
+            self.highlighter.highlight_html(&example.source_code, "aetherscript", self.syntax_theme),
+        );
+
+        let rendered = render_template("example", &ctx.variables)?;
+        let filename = format!("{}.html", example.name.to_lowercase().replace(' ', "_"));
+        std::fs::write(output_dir.join(filename), rendered)?;
+        Ok(())
+    }
+
+    /// Render a single reference manual section page.
+    fn render_reference_page(&self, section: &crate::docs::ManualSection, output_dir: &PathBuf) -> Result<(), SemanticError> {
+        let _ctx = RenderContext::for_page(format!("reference/{}", section.title));
+        let content = format!("<h1>{}</h1>\n<div>{}</div>", section.title, section.content);
+        let filename = format!("{}.html", section.title.to_lowercase().replace(' ', "_"));
+        std::fs::write(output_dir.join(filename), content)?;
+        Ok(())
+    }
+}
+
+/// Url-safe slug for a book chapter, derived from its source path's file
+/// stem (e.g. `basics/variables.md` -> `variables`).
+fn book_chapter_slug(chapter: &book::Chapter) -> String {
+    chapter
+        .path
+        .file_stem()
+        .and_then(|stem| stem.to_str())
+        .unwrap_or("chapter")
+        .to_lowercase()
+        .replace(' ', "_")
+}
+
+/// Render the sidebar table of contents shared by every chapter page, in
+/// reading order.
+fn render_book_sidebar(chapters: &[&book::Chapter]) -> String {
+    let mut sidebar = String::from("<ul class=\"book-sidebar\">");
+    for chapter in chapters {
+        sidebar.push_str(&format!(
+            "<li><a href=\"{}.html\">{}</a></li>",
+            book_chapter_slug(chapter),
+            chapter.title
+        ));
+    }
+    sidebar.push_str("</ul>");
+    sidebar
+}
+
+/// Render a single book chapter page, linking back to its previous and
+/// next chapter in reading order.
+fn render_book_chapter_page(
+    chapter: &book::Chapter,
+    prev: Option<&book::Chapter>,
+    next: Option<&book::Chapter>,
+    sidebar: &str,
+    output_dir: &PathBuf,
+) -> Result<(), SemanticError> {
+    let mut ctx = RenderContext::for_page(format!("book/{}", book_chapter_slug(chapter)));
+    ctx.variables.insert("chapter_title".to_string(), chapter.title.clone());
+    ctx.variables.insert("chapter_content".to_string(), chapter.content.clone());
+    ctx.variables.insert("sidebar".to_string(), sidebar.to_string());
+    ctx.variables.insert(
+        "prev_link".to_string(),
+        prev.map(|c| format!("<a href=\"{}.html\">&laquo; {}</a>", book_chapter_slug(c), c.title))
+            .unwrap_or_default(),
+    );
+    ctx.variables.insert(
+        "next_link".to_string(),
+        next.map(|c| format!("<a href=\"{}.html\">{} &raquo;</a>", book_chapter_slug(c), c.title))
+            .unwrap_or_default(),
+    );
+
+    let rendered = render_template("book_chapter", &ctx.variables)?;
+    std::fs::write(output_dir.join(format!("{}.html", book_chapter_slug(chapter))), rendered)?;
+    Ok(())
+}
+
+/// Build the thread pool the HTML page render fan-out runs on. `None` lets
+/// rayon size it automatically (`RAYON_NUM_THREADS` or the number of CPUs).
+fn build_thread_pool(threads: Option<usize>) -> Result<rayon::ThreadPool, SemanticError> {
+    rayon::ThreadPoolBuilder::new()
+        .num_threads(threads.unwrap_or(0))
+        .build()
+        .map_err(|e| SemanticError::Internal {
+            message: format!("Failed to build render thread pool: {}", e),
+        })
 }
 
 /// Navigation tree structure
@@ -230,27 +675,28 @@ pub struct HtmlRenderer<'a> {
     doc: &'a Documentation,
 }
 
-impl<'a> HtmlRenderer<'a> {
-    /// Render a template
-    fn render_template(&self, template_name: &str, context: &HashMap<String, String>) -> Result<String, SemanticError> {
-        // Simple template rendering - in a real implementation, this would use a template engine
-        let template = match template_name {
-            "index" => "<html><body><h1>{{project_name}}</h1><p>{{project_description}}</p></body></html>",
-            "module" => "<html><body><h1>{{module_name}}</h1><p>{{module_description}}</p></body></html>",
-            "tutorial" => "<html><body><h1>{{tutorial_title}}</h1><div>{{tutorial_content}}</div></body></html>",
-            "example" => "<html><body><h1>{{example_title}}</h1><code>{{example_code}}</code></body></html>",
-            _ => return Err(SemanticError::Internal {
-                message: format!("Unknown template: {}", template_name),
-            }),
-        };
-        
-        let mut result = template.to_string();
-        for (key, value) in context {
-            result = result.replace(&format!("{{{{{}}}}}", key), value);
-        }
-        
-        Ok(result)
+/// Render `template_name` against `context`. Stateless, so both
+/// [`HtmlRenderer`]'s single-threaded pages and [`Cache`]'s parallel page
+/// workers can call it without synchronization.
+fn render_template(template_name: &str, context: &HashMap<String, String>) -> Result<String, SemanticError> {
+    // Simple template rendering - in a real implementation, this would use a template engine
+    let template = match template_name {
+        "index" => "<html><body><h1>{{project_name}}</h1><p>{{project_description}}</p></body></html>",
+        "module" => "<html><body><h1>{{module_name}}</h1><p>{{module_description}}</p>{{examples_html}}</body></html>",
+        "tutorial" => "<html><body><h1>{{title}}</h1><div>{{content}}</div>{{examples_html}}</body></html>",
+        "example" => "<html><body><h1>{{name}}</h1><p>{{description}}</p><pre>{{highlighted_code}}</pre></body></html>",
+        "book_chapter" => "<html><body><nav>{{sidebar}}</nav><main><h1>{{chapter_title}}</h1><div>{{chapter_content}}</div><footer>{{prev_link}} {{next_link}}</footer></main></body></html>",
+        _ => return Err(SemanticError::Internal {
+            message: format!("Unknown template: {}", template_name),
+        }),
+    };
+
+    let mut result = template.to_string();
+    for (key, value) in context {
+        result = result.replace(&format!("{{{{{}}}}}", key), value);
     }
+
+    Ok(result)
 }
 
 /// Markdown renderer
@@ -367,49 +813,99 @@ impl DocRenderer {
     pub fn new(config: &DocConfig) -> Result<Self, SemanticError> {
         let templates = TemplateEngine::new(&config.output_dir)?;
         let assets = AssetManager::new(&config.output_dir)?;
-        let themes = ThemeManager::new()?;
-        
+        let _themes = ThemeManager::new()?;
+        let highlighter = SyntaxHighlighter::new()?;
+
         Ok(Self {
             template_engine: templates,
             asset_manager: assets,
             output_format: config.output_format.clone(),
+            threads: config.threads,
+            highlighter,
+            theme: config.theme.clone(),
         })
     }
-    
-    /// Render documentation to HTML
+
+    /// Name of the syntect theme to highlight code blocks with, derived
+    /// from the HTML output format's `theme` field (e.g. `"default"`,
+    /// `"dark"`); other output formats fall back to `"default"`.
+    fn syntax_theme_name(&self) -> &str {
+        match &self.output_format {
+            OutputFormat::Html { theme, .. } => theme.as_str(),
+            _ => "default",
+        }
+    }
+
+    /// Render documentation to HTML.
+    ///
+    /// Crawls `docs` once into an immutable [`Cache`], renders the pages
+    /// that need to stay single-threaded (the index page, assets, the
+    /// search index), then fans the independent per-module/tutorial/
+    /// example/reference-section pages out across a thread pool. Each page
+    /// is written to its own file, so the result is identical regardless of
+    /// thread count or scheduling order.
     pub fn render_html(&mut self, docs: &Documentation, output_dir: &PathBuf) -> Result<(), SemanticError> {
         let html_dir = output_dir.join("html");
         std::fs::create_dir_all(&html_dir)?;
-        
+        let api_dir = html_dir.join("api");
+        std::fs::create_dir_all(&api_dir)?;
+        let tutorials_dir = html_dir.join("tutorials");
+        std::fs::create_dir_all(&tutorials_dir)?;
+        let examples_dir = html_dir.join("examples");
+        std::fs::create_dir_all(&examples_dir)?;
+        let reference_dir = html_dir.join("reference");
+        std::fs::create_dir_all(&reference_dir)?;
+
+        // Phase 1: crawl `docs` once into an immutable, Send + Sync Cache
+        // shared by reference across every render worker below.
+        let syntax_theme = self.syntax_theme_name().to_string();
+        let playground = matches!(self.output_format, OutputFormat::Html { javascript: true, .. });
+        let cache = Cache::build(docs, &self.highlighter, &syntax_theme, &self.theme, playground);
+
         let mut renderer = HtmlRenderer { doc: docs };
-        
-        // Render main index page
         renderer.render_index(docs, &html_dir)?;
-        
-        // Render API documentation
-        renderer.render_api_docs(&docs.api, &html_dir)?;
-        
-        // Render tutorials
-        for tutorial in &docs.tutorials {
-            renderer.render_tutorial(tutorial, &html_dir)?;
-        }
-        
-        // Render examples
-        for example in &docs.examples {
-            renderer.render_example(example, &html_dir)?;
-        }
-        
-        // Render reference manual
-        renderer.render_reference(&docs.reference, &html_dir)?;
-        
-        // Copy assets
         self.asset_manager.copy_to_output(&html_dir)?;
-        
-        // Generate search index
         if let Some(ref search_index) = docs.search_index {
             renderer.render_search_index(search_index, &html_dir)?;
         }
-        
+        if playground {
+            renderer.render_playground_manifest(docs, &html_dir)?;
+        }
+
+        // Phase 2: render each module/tutorial/example/reference page
+        // independently across the thread pool.
+        let pool = build_thread_pool(self.threads)?;
+        pool.install(|| -> Result<(), SemanticError> {
+            docs.api.modules
+                .par_iter()
+                .try_for_each(|module| cache.render_module_page(module, &api_dir))?;
+            docs.tutorials
+                .par_iter()
+                .try_for_each(|tutorial| cache.render_tutorial_page(tutorial, &tutorials_dir))?;
+            docs.examples
+                .par_iter()
+                .try_for_each(|example| cache.render_example_page(example, &examples_dir))?;
+            docs.reference.sections
+                .par_iter()
+                .try_for_each(|section| cache.render_reference_page(section, &reference_dir))?;
+            if let Some(ref book) = docs.book {
+                let chapters = book.flatten();
+                let book_dir = html_dir.join("book");
+                std::fs::create_dir_all(&book_dir)?;
+                let sidebar = render_book_sidebar(&chapters);
+
+                chapters
+                    .par_iter()
+                    .enumerate()
+                    .try_for_each(|(i, chapter)| {
+                        let prev = if i > 0 { Some(chapters[i - 1]) } else { None };
+                        let next = chapters.get(i + 1).copied();
+                        render_book_chapter_page(chapter, prev, next, &sidebar, &book_dir)
+                    })?;
+            }
+            Ok(())
+        })?;
+
         Ok(())
     }
     
@@ -454,7 +950,7 @@ impl DocRenderer {
     }
     
     /// Render documentation to JSON
-    pub fn render_json(&mut self, docs: &Documentation, output_dir: &PathBuf) -> Result<(), SemanticError> {
+    pub fn render_json(&mut self, docs: &Documentation, output_dir: &PathBuf, item_graph: bool) -> Result<(), SemanticError> {
         let json_dir = output_dir.join("json");
         std::fs::create_dir_all(&json_dir)?;
         
@@ -466,14 +962,29 @@ impl DocRenderer {
         
         let json_path = json_dir.join("documentation.json");
         std::fs::write(json_path, json_content)?;
-        
+
         // Also create separate files for different sections
         self.render_api_json(&docs.api, &json_dir)?;
         self.render_tutorials_json(&docs.tutorials, &json_dir)?;
         self.render_examples_json(&docs.examples, &json_dir)?;
-        
+
+        // Optionally emit the stable, Id-indexed item graph for tooling
+        if item_graph {
+            self.render_item_graph_json(docs, &json_dir)?;
+        }
+
         Ok(())
     }
+
+    /// Render man pages for the API reference
+    pub fn render_man_pages(&mut self, docs: &Documentation, output_dir: &PathBuf, section: u8) -> Result<(), SemanticError> {
+        crate::docs::manpage::render_man_pages(docs, output_dir, section)
+    }
+
+    /// Render bash/zsh/fish shell completion scripts
+    pub fn render_completions(&mut self, docs: &Documentation, output_dir: &PathBuf) -> Result<(), SemanticError> {
+        crate::docs::manpage::render_completions(docs, output_dir)
+    }
     
     // Helper methods for JSON rendering
     
@@ -506,6 +1017,17 @@ impl DocRenderer {
         std::fs::write(output_dir.join("examples.json"), examples_json)?;
         Ok(())
     }
+
+    fn render_item_graph_json(&self, docs: &Documentation, output_dir: &PathBuf) -> Result<(), SemanticError> {
+        let graph = crate::docs::json_index::ItemGraph::build(docs);
+        let graph_json = serde_json::to_string_pretty(&graph)
+            .map_err(|e| SemanticError::Internal {
+                message: format!("Failed to serialize item graph to JSON: {}", e),
+            })?;
+
+        std::fs::write(output_dir.join("item-graph.json"), graph_json)?;
+        Ok(())
+    }
 }
 
 impl TemplateEngine {
@@ -969,91 +1491,15 @@ impl<'a> HtmlRenderer<'a> {
     fn render_index(&mut self, docs: &Documentation, output_dir: &PathBuf) -> Result<(), SemanticError> {
         let mut context = HashMap::new();
         context.insert("project_name".to_string(), docs.metadata.name.clone());
-        context.insert("project_description".to_string(), 
+        context.insert("project_description".to_string(),
             docs.metadata.description.as_deref().unwrap_or("").to_string());
-        
-        let rendered = self.render_template("index", &context)?;
+
+        let rendered = render_template("index", &context)?;
         std::fs::write(output_dir.join("index.html"), rendered)?;
-        
-        Ok(())
-    }
-    
-    fn render_api_docs(&mut self, api: &crate::docs::ApiDocumentation, output_dir: &PathBuf) -> Result<(), SemanticError> {
-        let api_dir = output_dir.join("api");
-        std::fs::create_dir_all(&api_dir)?;
-        
-        // Render each module
-        for module in &api.modules {
-            self.render_module_doc(module, &api_dir)?;
-        }
-        
-        Ok(())
-    }
-    
-    fn render_module_doc(&mut self, module: &ModuleDoc, output_dir: &PathBuf) -> Result<(), SemanticError> {
-        let mut context = HashMap::new();
-        context.insert("module_name".to_string(), module.name.clone());
-        context.insert("module_description".to_string(), module.description.as_deref().unwrap_or("").to_string());
-        
-        let rendered = self.render_template("module", &context)?;
-        let filename = format!("{}.html", module.name);
-        std::fs::write(output_dir.join(filename), rendered)?;
-        
-        Ok(())
-    }
-    
-    fn render_tutorial(&mut self, tutorial: &Tutorial, output_dir: &PathBuf) -> Result<(), SemanticError> {
-        let tutorials_dir = output_dir.join("tutorials");
-        std::fs::create_dir_all(&tutorials_dir)?;
-        
-        let mut context = HashMap::new();
-        context.insert("title".to_string(), tutorial.title.clone());
-        context.insert("description".to_string(), tutorial.description.clone());
-        context.insert("content".to_string(), tutorial.content.clone());
-        
-        let rendered = self.render_template("tutorial", &context)?;
-        let filename = format!("{}.html", tutorial.title.to_lowercase().replace(' ', "_"));
-        std::fs::write(tutorials_dir.join(filename), rendered)?;
-        
-        Ok(())
-    }
-    
-    fn render_example(&mut self, example: &Example, output_dir: &PathBuf) -> Result<(), SemanticError> {
-        let examples_dir = output_dir.join("examples");
-        std::fs::create_dir_all(&examples_dir)?;
-        
-        let mut context = HashMap::new();
-        context.insert("name".to_string(), example.name.clone());
-        context.insert("description".to_string(), example.description.clone());
-        context.insert("source_code".to_string(), example.source_code.clone());
-        
-        let rendered = self.render_template("example", &context)?;
-        let filename = format!("{}.html", example.name.to_lowercase().replace(' ', "_"));
-        std::fs::write(examples_dir.join(filename), rendered)?;
-        
-        Ok(())
-    }
-    
-    fn render_reference(&mut self, reference: &crate::docs::ReferenceManual, output_dir: &PathBuf) -> Result<(), SemanticError> {
-        let reference_dir = output_dir.join("reference");
-        std::fs::create_dir_all(&reference_dir)?;
-        
-        // Render reference sections
-        for section in &reference.sections {
-            self.render_reference_section(section, &reference_dir)?;
-        }
-        
-        Ok(())
-    }
-    
-    fn render_reference_section(&mut self, section: &crate::docs::ManualSection, output_dir: &PathBuf) -> Result<(), SemanticError> {
-        let content = format!("<h1>{}</h1>\n<div>{}</div>", section.title, section.content);
-        let filename = format!("{}.html", section.title.to_lowercase().replace(' ', "_"));
-        std::fs::write(output_dir.join(filename), content)?;
-        
+
         Ok(())
     }
-    
+
     fn render_search_index(&mut self, search_index: &SearchIndex, output_dir: &PathBuf) -> Result<(), SemanticError> {
         let search_data = serde_json::to_string(search_index)
             .map_err(|e| SemanticError::Internal {
@@ -1061,11 +1507,64 @@ impl<'a> HtmlRenderer<'a> {
             })?;
         
         std::fs::write(output_dir.join("search-index.json"), search_data)?;
-        
+
+        Ok(())
+    }
+
+    /// Write the `example id -> source` manifest the playground's "Run"
+    /// button reads from, covering every runnable [`CodeExample`] reachable
+    /// from a module or tutorial page (see [`build_playground_manifest`]).
+    fn render_playground_manifest(&mut self, docs: &Documentation, output_dir: &PathBuf) -> Result<(), SemanticError> {
+        let manifest = build_playground_manifest(docs);
+        let manifest_json = serde_json::to_string(&manifest).map_err(|e| SemanticError::Internal {
+            message: format!("Failed to serialize playground manifest: {}", e),
+        })?;
+
+        std::fs::write(output_dir.join("playground.json"), manifest_json)?;
+
         Ok(())
     }
 }
 
+/// Whether a [`CodeExample`] should be treated as interactive rather than
+/// static highlighted code: a basic snippet or a doctest, both of which
+/// [`crate::docs::examples::run_doctests`] can compile and execute.
+fn is_runnable(example: &CodeExample) -> bool {
+    matches!(example.example_type, ExampleType::Basic | ExampleType::Test)
+}
+
+/// Stable id for a [`CodeExample`], shared between the markup
+/// [`Cache::render_code_example`] emits and [`build_playground_manifest`]
+/// so the companion script can look up source by `data-example-id`.
+fn code_example_id(example: &CodeExample) -> String {
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::{Hash, Hasher};
+
+    let mut hasher = DefaultHasher::new();
+    example.title.hash(&mut hasher);
+    example.code.hash(&mut hasher);
+    format!("example-{:x}", hasher.finish())
+}
+
+/// Escape `&`, `<`, and `>` so source code can be embedded as the literal
+/// text content of a `<textarea>`.
+fn html_escape(text: &str) -> String {
+    text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;")
+}
+
+/// Every runnable [`CodeExample`] reachable from a rendered module or
+/// tutorial page, keyed by [`code_example_id`].
+fn build_playground_manifest(docs: &Documentation) -> HashMap<String, String> {
+    docs.api
+        .modules
+        .iter()
+        .flat_map(|module| module.examples.iter())
+        .chain(docs.tutorials.iter().flat_map(|tutorial| tutorial.sections.iter().flat_map(|section| section.examples.iter())))
+        .filter(|example| is_runnable(example))
+        .map(|example| (code_example_id(example), example.code.clone()))
+        .collect()
+}
+
 impl MarkdownRenderer {
     fn new() -> Result<Self, SemanticError> {
         let options = MarkdownOptions {
@@ -1267,9 +1766,19 @@ impl PdfRenderer {
             content.push_str("\n\n");
         }
         
+        // Add the authored book, if any
+        if let Some(ref book) = docs.book {
+            content.push_str("# Book\n\n");
+            for chapter in book.flatten() {
+                content.push_str(&format!("## {}\n\n", chapter.title));
+                content.push_str(&chapter.content);
+                content.push_str("\n\n");
+            }
+        }
+
         // Save as text file (placeholder for PDF generation)
         std::fs::write(output_path, content)?;
-        
+
         Ok(())
     }
 }
@@ -0,0 +1,372 @@
+// Copyright 2025 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! BM25-ranked inverted search index
+//!
+//! Builds a per-field inverted index over [`SearchDocument`]s and scores
+//! queries against it with the standard Okapi BM25 recurrence, weighted per
+//! field by [`SearchWeights`]. The resulting [`SearchIndex`] serializes into
+//! a compact JSON blob that the HTML `ClientSide` search can load and query
+//! entirely in the browser; `ServerSide`/`Elasticsearch` integrations can
+//! reuse the same [`tokenize`] function to build a compatible token stream.
+
+use crate::docs::{SearchDocument, SearchIndex, SearchWeights};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// BM25 term-frequency saturation parameter
+const K1: f64 = 1.2;
+
+/// BM25 document-length normalization parameter
+const B: f64 = 0.75;
+
+/// The fields a [`SearchDocument`] is indexed and scored on
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SearchField {
+    Title,
+    Description,
+    Content,
+    Tags,
+}
+
+const ALL_FIELDS: [SearchField; 4] = [
+    SearchField::Title,
+    SearchField::Description,
+    SearchField::Content,
+    SearchField::Tags,
+];
+
+/// Per-field term frequency for a single document
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+pub struct FieldFrequencies {
+    pub title: u32,
+    pub description: u32,
+    pub content: u32,
+    pub tags: u32,
+}
+
+impl FieldFrequencies {
+    fn increment(&mut self, field: SearchField) {
+        match field {
+            SearchField::Title => self.title += 1,
+            SearchField::Description => self.description += 1,
+            SearchField::Content => self.content += 1,
+            SearchField::Tags => self.tags += 1,
+        }
+    }
+
+    fn get(&self, field: SearchField) -> u32 {
+        match field {
+            SearchField::Title => self.title,
+            SearchField::Description => self.description,
+            SearchField::Content => self.content,
+            SearchField::Tags => self.tags,
+        }
+    }
+}
+
+/// Per-field token count for a single document
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+pub struct DocumentLength {
+    pub title: usize,
+    pub description: usize,
+    pub content: usize,
+    pub tags: usize,
+}
+
+impl DocumentLength {
+    fn get(&self, field: SearchField) -> usize {
+        match field {
+            SearchField::Title => self.title,
+            SearchField::Description => self.description,
+            SearchField::Content => self.content,
+            SearchField::Tags => self.tags,
+        }
+    }
+}
+
+/// Average per-field document length across the corpus, used as `avgdl` in
+/// the BM25 recurrence
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+pub struct AverageDocumentLength {
+    pub title: f64,
+    pub description: f64,
+    pub content: f64,
+    pub tags: f64,
+}
+
+impl AverageDocumentLength {
+    fn from_lengths(lengths: &[DocumentLength]) -> Self {
+        if lengths.is_empty() {
+            return Self::default();
+        }
+
+        let n = lengths.len() as f64;
+        Self {
+            title: lengths.iter().map(|l| l.title).sum::<usize>() as f64 / n,
+            description: lengths.iter().map(|l| l.description).sum::<usize>() as f64 / n,
+            content: lengths.iter().map(|l| l.content).sum::<usize>() as f64 / n,
+            tags: lengths.iter().map(|l| l.tags).sum::<usize>() as f64 / n,
+        }
+    }
+
+    fn get(&self, field: SearchField) -> f64 {
+        match field {
+            SearchField::Title => self.title,
+            SearchField::Description => self.description,
+            SearchField::Content => self.content,
+            SearchField::Tags => self.tags,
+        }
+    }
+}
+
+/// A scored search result
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SearchHit {
+    pub doc_index: usize,
+    pub score: f64,
+}
+
+/// Lowercase and split `text` on runs of non-alphanumeric characters,
+/// discarding empty tokens. Shared by the client-side BM25 index and any
+/// server-side/Elasticsearch integration that wants a compatible token
+/// stream.
+pub fn tokenize(text: &str) -> Vec<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|s| !s.is_empty())
+        .map(|s| s.to_lowercase())
+        .collect()
+}
+
+/// Build the inverted index, per-document field lengths, corpus averages,
+/// and IDF table for `documents`.
+pub fn build_index(
+    documents: &[SearchDocument],
+) -> (
+    HashMap<String, HashMap<usize, FieldFrequencies>>,
+    Vec<DocumentLength>,
+    AverageDocumentLength,
+    HashMap<String, f64>,
+) {
+    let mut postings: HashMap<String, HashMap<usize, FieldFrequencies>> = HashMap::new();
+    let mut doc_lengths = Vec::with_capacity(documents.len());
+
+    for (doc_idx, doc) in documents.iter().enumerate() {
+        let tags_text = doc.tags.join(" ");
+        let fields: [(SearchField, &str); 4] = [
+            (SearchField::Title, doc.title.as_str()),
+            (SearchField::Description, doc.description.as_str()),
+            (SearchField::Content, doc.content.as_str()),
+            (SearchField::Tags, tags_text.as_str()),
+        ];
+
+        let mut length = DocumentLength::default();
+        for (field, text) in fields {
+            let tokens = tokenize(text);
+            match field {
+                SearchField::Title => length.title = tokens.len(),
+                SearchField::Description => length.description = tokens.len(),
+                SearchField::Content => length.content = tokens.len(),
+                SearchField::Tags => length.tags = tokens.len(),
+            }
+
+            for token in tokens {
+                postings
+                    .entry(token)
+                    .or_default()
+                    .entry(doc_idx)
+                    .or_default()
+                    .increment(field);
+            }
+        }
+
+        doc_lengths.push(length);
+    }
+
+    let avg_doc_lengths = AverageDocumentLength::from_lengths(&doc_lengths);
+
+    let n = documents.len() as f64;
+    let idf = postings
+        .iter()
+        .map(|(term, docs_containing)| {
+            let df = docs_containing.len() as f64;
+            let idf = ((n - df + 0.5) / (df + 0.5) + 1.0).ln();
+            (term.clone(), idf)
+        })
+        .collect();
+
+    (postings, doc_lengths, avg_doc_lengths, idf)
+}
+
+/// Score `query` against `index` using BM25, weighting each field's
+/// contribution by `weights` before summing, and return up to
+/// `max_results` hits sorted by descending score.
+pub fn search(
+    index: &SearchIndex,
+    query: &str,
+    weights: &SearchWeights,
+    max_results: usize,
+) -> Vec<SearchHit> {
+    let mut scores: HashMap<usize, f64> = HashMap::new();
+
+    for term in tokenize(query) {
+        let Some(&term_idf) = index.idf.get(&term) else {
+            continue;
+        };
+        let Some(docs_containing) = index.postings.get(&term) else {
+            continue;
+        };
+
+        for (&doc_idx, freqs) in docs_containing {
+            let length = &index.doc_lengths[doc_idx];
+            let mut doc_score = 0.0;
+
+            for field in ALL_FIELDS {
+                let f = freqs.get(field) as f64;
+                if f == 0.0 {
+                    continue;
+                }
+
+                let weight = field_weight(weights, field) as f64;
+                let dl = length.get(field) as f64;
+                let avgdl = index.avg_doc_lengths.get(field).max(f64::EPSILON);
+                let denom = f + K1 * (1.0 - B + B * dl / avgdl);
+                doc_score += weight * term_idf * (f * (K1 + 1.0)) / denom;
+            }
+
+            *scores.entry(doc_idx).or_insert(0.0) += doc_score;
+        }
+    }
+
+    let mut hits: Vec<SearchHit> = scores
+        .into_iter()
+        .map(|(doc_index, score)| SearchHit { doc_index, score })
+        .collect();
+
+    hits.sort_by(|a, b| b.score.partial_cmp(&a.score).unwrap_or(std::cmp::Ordering::Equal));
+    hits.truncate(max_results);
+    hits
+}
+
+fn field_weight(weights: &SearchWeights, field: SearchField) -> f32 {
+    match field {
+        SearchField::Title => weights.title,
+        SearchField::Description => weights.description,
+        SearchField::Content => weights.content,
+        SearchField::Tags => weights.tags,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::docs::DocumentType;
+
+    fn doc(title: &str, description: &str, content: &str, tags: &[&str]) -> SearchDocument {
+        SearchDocument {
+            id: title.to_string(),
+            title: title.to_string(),
+            url: format!("{}.html", title),
+            description: description.to_string(),
+            content: content.to_string(),
+            doc_type: DocumentType::Function,
+            tags: tags.iter().map(|t| t.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn test_tokenize_lowercases_and_strips_punctuation() {
+        assert_eq!(tokenize("Hello, World!"), vec!["hello", "world"]);
+        assert_eq!(tokenize("foo::bar_baz"), vec!["foo", "bar", "baz"]);
+    }
+
+    #[test]
+    fn test_search_ranks_exact_title_match_first() {
+        let documents = vec![
+            doc("parse_int", "parses an integer", "parses an integer from a string", &["parsing"]),
+            doc("format_string", "formats a value", "the word parse appears once here", &["formatting"]),
+        ];
+
+        let (postings, doc_lengths, avg_doc_lengths, idf) = build_index(&documents);
+        let index = SearchIndex {
+            documents,
+            postings,
+            doc_lengths,
+            avg_doc_lengths,
+            idf,
+            metadata: crate::docs::SearchMetadata {
+                total_documents: 2,
+                total_terms: 0,
+                index_size: 0,
+                last_updated: std::time::SystemTime::UNIX_EPOCH,
+            },
+        };
+
+        let weights = SearchWeights { title: 2.0, description: 1.5, content: 1.0, tags: 1.2 };
+        let hits = search(&index, "parse", &weights, 10);
+
+        assert_eq!(hits.len(), 2);
+        assert_eq!(hits[0].doc_index, 0);
+        assert!(hits[0].score > hits[1].score);
+    }
+
+    #[test]
+    fn test_search_respects_max_results() {
+        let documents = vec![
+            doc("a", "", "rust programming", &[]),
+            doc("b", "", "rust programming", &[]),
+            doc("c", "", "rust programming", &[]),
+        ];
+        let (postings, doc_lengths, avg_doc_lengths, idf) = build_index(&documents);
+        let index = SearchIndex {
+            documents,
+            postings,
+            doc_lengths,
+            avg_doc_lengths,
+            idf,
+            metadata: crate::docs::SearchMetadata {
+                total_documents: 3,
+                total_terms: 0,
+                index_size: 0,
+                last_updated: std::time::SystemTime::UNIX_EPOCH,
+            },
+        };
+
+        let weights = SearchWeights::default();
+        let hits = search(&index, "rust", &weights, 2);
+        assert_eq!(hits.len(), 2);
+    }
+
+    #[test]
+    fn test_search_unknown_term_returns_no_hits() {
+        let documents = vec![doc("a", "", "rust programming", &[])];
+        let (postings, doc_lengths, avg_doc_lengths, idf) = build_index(&documents);
+        let index = SearchIndex {
+            documents,
+            postings,
+            doc_lengths,
+            avg_doc_lengths,
+            idf,
+            metadata: crate::docs::SearchMetadata {
+                total_documents: 1,
+                total_terms: 0,
+                index_size: 0,
+                last_updated: std::time::SystemTime::UNIX_EPOCH,
+            },
+        };
+
+        let weights = SearchWeights::default();
+        assert!(search(&index, "nonexistent", &weights, 10).is_empty());
+    }
+}
@@ -0,0 +1,400 @@
+// Copyright 2025 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Cross-reference subsystem
+//!
+//! Every module, tutorial, example, and reference-manual section carries a
+//! stable `refname`, assigned from its name/title (see [`derive_refname`])
+//! and validated at registration time: trimmed, then rejected if empty or
+//! containing whitespace, ASCII punctuation, or control codepoints (see
+//! [`validate_refname`]).
+//!
+//! Doc content can link to a refname with the inline `@ref(name)` syntax.
+//! [`resolve_and_rewrite`] builds the `refname -> target` table (see
+//! [`build_table`]), rewrites every resolvable `@ref(name)` into a Markdown
+//! link in place, and fails (or warns, per `DocConfig::strict_xrefs`) with
+//! the full list of dangling refnames and the sections that reference them.
+
+use crate::docs::{Documentation, ManualSection};
+use crate::error::SemanticError;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt;
+
+/// `refname -> target URL` table built once per render pass; see
+/// [`build_table`].
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct XRefTable(HashMap<String, String>);
+
+impl XRefTable {
+    /// The target URL registered for `name`, if any.
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.0.get(name).map(String::as_str)
+    }
+
+    /// Number of registered refnames.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Whether no refnames have been registered.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+/// Derive a stable refname from a node's name or title: lowercase, keeping
+/// only ASCII alphanumerics so the result always passes
+/// [`validate_refname`] regardless of spacing or punctuation in the source
+/// text.
+pub fn derive_refname(name: &str) -> String {
+    name.chars().filter(|c| c.is_ascii_alphanumeric()).collect::<String>().to_lowercase()
+}
+
+/// Trim `name` and reject it if empty or containing whitespace, ASCII
+/// punctuation, or control codepoints.
+pub fn validate_refname(name: &str) -> Result<String, SemanticError> {
+    let trimmed = name.trim();
+    if trimmed.is_empty() {
+        return Err(SemanticError::Internal { message: "refname cannot be empty".to_string() });
+    }
+    if let Some(c) = trimmed.chars().find(|c| c.is_whitespace() || c.is_ascii_punctuation() || c.is_control()) {
+        return Err(SemanticError::Internal {
+            message: format!(
+                "invalid refname '{}': contains disallowed character '{}' (refnames must not contain whitespace, ASCII punctuation, or control characters)",
+                trimmed, c.escape_debug()
+            ),
+        });
+    }
+    Ok(trimmed.to_string())
+}
+
+/// Validate `name` and register it in `table` pointing at `target`,
+/// rejecting a refname that's already registered.
+fn register(table: &mut XRefTable, name: &str, target: &str) -> Result<(), SemanticError> {
+    let name = validate_refname(name)?;
+    if table.0.contains_key(&name) {
+        return Err(SemanticError::Internal { message: format!("duplicate cross-reference name '{}'", name) });
+    }
+    table.0.insert(name, target.to_string());
+    Ok(())
+}
+
+/// Build the `refname -> target` table for every documentable node in
+/// `documentation`, using the same relative URLs the HTML renderer writes
+/// pages to. Reference subsections get a URL of the same shape, but the
+/// HTML renderer writes a page only for top-level reference sections.
+pub fn build_table(documentation: &Documentation) -> Result<XRefTable, SemanticError> {
+    let mut table = XRefTable::default();
+
+    for module in &documentation.api.modules {
+        register(&mut table, &module.refname, &format!("api/{}.html", module.name))?;
+    }
+    for tutorial in &documentation.tutorials {
+        let slug = tutorial.title.to_lowercase().replace(' ', "_");
+        register(&mut table, &tutorial.refname, &format!("tutorials/{}.html", slug))?;
+    }
+    for example in &documentation.examples {
+        let slug = example.name.to_lowercase().replace(' ', "_");
+        register(&mut table, &example.refname, &format!("examples/{}.html", slug))?;
+    }
+    for section in &documentation.reference.sections {
+        register_section(&mut table, section)?;
+    }
+
+    Ok(table)
+}
+
+fn register_section(table: &mut XRefTable, section: &ManualSection) -> Result<(), SemanticError> {
+    let slug = section.title.to_lowercase().replace(' ', "_");
+    register(table, &section.refname, &format!("reference/{}.html", slug))?;
+    for subsection in &section.subsections {
+        register_section(table, subsection)?;
+    }
+    Ok(())
+}
+
+/// One `@ref(name)` that didn't resolve against the table, with enough
+/// context to find it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BrokenRef {
+    /// The refname written inside `@ref(...)`
+    pub refname: String,
+    /// The documentable node whose content contains the reference, e.g.
+    /// `` module `foo` ``
+    pub section: String,
+}
+
+impl fmt::Display for BrokenRef {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unresolved cross-reference `@ref({})` in {}", self.refname, self.section)
+    }
+}
+
+/// Find every `@ref(name)` in `text`, returning `(start, end, name)` byte
+/// spans (`end` exclusive, covering the whole `@ref(...)`).
+fn find_ref_spans(text: &str) -> Vec<(usize, usize, String)> {
+    let mut spans = Vec::new();
+    let mut i = 0;
+
+    while let Some(rel_start) = text[i..].find("@ref(") {
+        let start = i + rel_start;
+        let after = start + "@ref(".len();
+        let Some(rel_end) = text[after..].find(')') else { break };
+        let end = after + rel_end + 1;
+        spans.push((start, end, text[after..end - 1].to_string()));
+        i = end;
+    }
+
+    spans
+}
+
+/// Rewrite every resolvable `@ref(name)` in `text` into a `[name](url)`
+/// Markdown link in place.
+fn rewrite_refs(text: &mut String, table: &XRefTable) {
+    for (start, end, name) in find_ref_spans(text).into_iter().rev() {
+        if let Some(url) = table.get(&name) {
+            text.replace_range(start..end, &format!("[{}]({})", name, url));
+        }
+    }
+}
+
+/// Collect every `@ref(name)` in `text` that doesn't resolve against
+/// `table`, labeling each with `section`.
+fn collect_broken(text: &str, table: &XRefTable, section: &str, out: &mut Vec<BrokenRef>) {
+    for (_, _, name) in find_ref_spans(text) {
+        if table.get(&name).is_none() {
+            out.push(BrokenRef { refname: name, section: section.to_string() });
+        }
+    }
+}
+
+fn collect_section_broken(section: &ManualSection, table: &XRefTable, out: &mut Vec<BrokenRef>) {
+    collect_broken(&section.content, table, &format!("reference section `{}`", section.title), out);
+    for subsection in &section.subsections {
+        collect_section_broken(subsection, table, out);
+    }
+}
+
+fn rewrite_section(section: &mut ManualSection, table: &XRefTable) {
+    rewrite_refs(&mut section.content, table);
+    for subsection in &mut section.subsections {
+        rewrite_section(subsection, table);
+    }
+}
+
+/// Every `@ref(name)` across `documentation` that doesn't resolve against
+/// `table`.
+pub fn find_broken_refs(documentation: &Documentation, table: &XRefTable) -> Vec<BrokenRef> {
+    let mut broken = Vec::new();
+
+    for module in &documentation.api.modules {
+        let section = format!("module `{}`", module.path);
+        collect_broken(&module.docs, table, &section, &mut broken);
+        if let Some(description) = &module.description {
+            collect_broken(description, table, &section, &mut broken);
+        }
+    }
+    for tutorial in &documentation.tutorials {
+        let section = format!("tutorial `{}`", tutorial.title);
+        collect_broken(&tutorial.content, table, &section, &mut broken);
+        for tutorial_section in &tutorial.sections {
+            let section = format!("tutorial `{}` section `{}`", tutorial.title, tutorial_section.title);
+            collect_broken(&tutorial_section.content, table, &section, &mut broken);
+        }
+    }
+    for example in &documentation.examples {
+        let section = format!("example `{}`", example.name);
+        collect_broken(&example.description, table, &section, &mut broken);
+    }
+    for section in &documentation.reference.sections {
+        collect_section_broken(section, table, &mut broken);
+    }
+
+    broken
+}
+
+/// Build the xref table for `documentation`, rewrite every resolvable
+/// `@ref(name)` into a Markdown link in place, and store the table on
+/// `documentation.xref_table`. With `strict` set, a non-empty set of
+/// dangling references fails with the full list; otherwise it's logged as
+/// a warning.
+pub fn resolve_and_rewrite(documentation: &mut Documentation, strict: bool) -> Result<(), SemanticError> {
+    let table = build_table(documentation)?;
+    let broken = find_broken_refs(documentation, &table);
+
+    for module in &mut documentation.api.modules {
+        rewrite_refs(&mut module.docs, &table);
+        if let Some(description) = &mut module.description {
+            rewrite_refs(description, &table);
+        }
+    }
+    for tutorial in &mut documentation.tutorials {
+        rewrite_refs(&mut tutorial.content, &table);
+        for section in &mut tutorial.sections {
+            rewrite_refs(&mut section.content, &table);
+        }
+    }
+    for example in &mut documentation.examples {
+        rewrite_refs(&mut example.description, &table);
+    }
+    for section in &mut documentation.reference.sections {
+        rewrite_section(section, &table);
+    }
+
+    documentation.xref_table = table;
+
+    if !broken.is_empty() {
+        let message = format!(
+            "{} dangling cross-reference(s): {}",
+            broken.len(),
+            broken.iter().map(BrokenRef::to_string).collect::<Vec<_>>().join("; ")
+        );
+        if strict {
+            return Err(SemanticError::Internal { message });
+        }
+        eprintln!("Warning: {}", message);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::docs::{
+        ApiDocumentation, ModuleDoc, ProjectMetadata, ReferenceManual, SourceLocation, Tutorial, TutorialSection,
+        Visibility,
+    };
+    use std::path::PathBuf;
+
+    fn loc() -> SourceLocation {
+        SourceLocation { file: PathBuf::from("test.aether"), line: 1, column: 1, span: 0 }
+    }
+
+    fn empty_docs() -> Documentation {
+        Documentation {
+            metadata: ProjectMetadata {
+                name: "test_crate".to_string(),
+                version: "1.0.0".to_string(),
+                description: None,
+                authors: vec![],
+                license: None,
+                homepage: None,
+                repository: None,
+                documentation_url: None,
+                generated_at: std::time::SystemTime::UNIX_EPOCH,
+            },
+            api: ApiDocumentation { modules: vec![], functions: vec![], types: vec![], constants: vec![], macros: vec![] },
+            tutorials: vec![],
+            examples: vec![],
+            reference: ReferenceManual { sections: vec![], appendices: vec![], glossary: HashMap::new(), index: vec![] },
+            search_index: None,
+            book: None,
+            link_warnings: vec![],
+            xref_table: XRefTable::default(),
+        }
+    }
+
+    fn module(path: &str, refname: &str, docs: &str) -> ModuleDoc {
+        ModuleDoc {
+            name: path.to_string(),
+            path: path.to_string(),
+            description: None,
+            docs: docs.to_string(),
+            visibility: Visibility::Public,
+            source_location: loc(),
+            submodules: vec![],
+            items: vec![],
+            examples: vec![],
+            refname: refname.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_derive_refname_strips_spaces_and_punctuation() {
+        assert_eq!(derive_refname("Getting Started!"), "gettingstarted");
+    }
+
+    #[test]
+    fn test_validate_refname_rejects_empty_and_whitespace() {
+        assert!(validate_refname("   ").is_err());
+        assert!(validate_refname("has space").is_err());
+        assert!(validate_refname("has-dash").is_err());
+        assert!(validate_refname("valid123").is_ok());
+    }
+
+    #[test]
+    fn test_build_table_rejects_duplicate_refnames() {
+        let mut docs = empty_docs();
+        docs.api.modules.push(module("a", "dup", ""));
+        docs.api.modules.push(module("b", "dup", ""));
+
+        assert!(build_table(&docs).is_err());
+    }
+
+    #[test]
+    fn test_resolve_and_rewrite_rewrites_known_ref_and_reports_broken() {
+        let mut docs = empty_docs();
+        docs.api.modules.push(module("target", "target", ""));
+        docs.api.modules.push(module("source", "source", "see @ref(target) and @ref(missing)"));
+
+        let ok = resolve_and_rewrite(&mut docs, false).is_ok();
+        assert!(ok);
+
+        let source = docs.api.modules.iter().find(|m| m.path == "source").unwrap();
+        assert_eq!(source.docs, "see [target](api/target.html) and @ref(missing)");
+        assert_eq!(docs.xref_table.get("target"), Some("api/target.html"));
+
+        let broken = find_broken_refs(&docs, &docs.xref_table);
+        assert_eq!(broken.len(), 1);
+        assert_eq!(broken[0].refname, "missing");
+    }
+
+    #[test]
+    fn test_strict_mode_fails_on_dangling_ref() {
+        let mut docs = empty_docs();
+        docs.api.modules.push(module("source", "source", "see @ref(missing)"));
+
+        assert!(resolve_and_rewrite(&mut docs, true).is_err());
+    }
+
+    #[test]
+    fn test_find_broken_refs_labels_tutorial_sections() {
+        let mut docs = empty_docs();
+        docs.tutorials.push(Tutorial {
+            title: "Intro".to_string(),
+            description: String::new(),
+            content: String::new(),
+            sections: vec![TutorialSection {
+                title: "Basics".to_string(),
+                content: "@ref(missing)".to_string(),
+                examples: vec![],
+                exercises: vec![],
+            }],
+            prerequisites: vec![],
+            difficulty: crate::docs::DifficultyLevel::Beginner,
+            estimated_time: None,
+            tags: vec![],
+            language: None,
+            refname: "intro".to_string(),
+        });
+
+        let table = build_table(&docs).unwrap();
+        let broken = find_broken_refs(&docs, &table);
+
+        assert_eq!(broken.len(), 1);
+        assert_eq!(broken[0].section, "tutorial `Intro` section `Basics`");
+    }
+}
@@ -0,0 +1,196 @@
+// Copyright 2025 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Machine-readable JSON diagnostic output (`--error-format=json`)
+//!
+//! Mirrors the rustc/compiletest JSON diagnostic schema so editors and CI
+//! can consume compiler output without regex-scraping the human-readable
+//! strings the CLI prints by default. One [`JsonDiagnostic`] is emitted
+//! per error/warning, followed by a single [`JsonSummary`]; the exit code
+//! contract is unchanged, only the rendering is.
+
+use crate::error::{CompilerError, Diagnostic, ErrorReporter, Severity, SourceSpan};
+use serde::Serialize;
+
+/// An error code together with a short explanation of what it means.
+#[derive(Debug, Clone, Serialize)]
+pub struct JsonDiagnosticCode {
+    pub code: String,
+    pub explanation: Option<String>,
+}
+
+/// Diagnostic severity, serialized as the lowercase strings rustc uses.
+#[derive(Debug, Clone, Copy, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum JsonDiagnosticLevel {
+    Error,
+    Warning,
+    Note,
+}
+
+/// A source range referenced by a diagnostic. `is_primary` marks the span
+/// that points at the actual problem, as opposed to spans attached to
+/// `children` for supplementary context.
+#[derive(Debug, Clone, Serialize)]
+pub struct JsonSpan {
+    pub file_name: String,
+    pub line_start: usize,
+    pub line_end: usize,
+    pub column_start: usize,
+    pub column_end: usize,
+    pub is_primary: bool,
+    pub label: Option<String>,
+}
+
+impl JsonSpan {
+    fn primary(span: &SourceSpan, label: Option<String>) -> Self {
+        Self {
+            file_name: span.start.file.clone(),
+            line_start: span.start.line,
+            line_end: span.end.line,
+            column_start: span.start.column,
+            column_end: span.end.column,
+            is_primary: true,
+            label,
+        }
+    }
+}
+
+/// One diagnostic object, modeled after rustc's `--error-format=json`
+/// output. `children` carries the `help`/`note` lines a human-readable
+/// [`Diagnostic`] attaches below the main message.
+#[derive(Debug, Clone, Serialize)]
+pub struct JsonDiagnostic {
+    pub message: String,
+    pub code: Option<JsonDiagnosticCode>,
+    pub level: JsonDiagnosticLevel,
+    pub spans: Vec<JsonSpan>,
+    pub children: Vec<JsonDiagnostic>,
+}
+
+impl JsonDiagnostic {
+    /// Build a diagnostic from a [`CompilerError`], routing through
+    /// [`ErrorReporter::error_to_diagnostic`] so the JSON and
+    /// human-readable renderers never disagree about what a given error
+    /// means.
+    pub fn from_compiler_error(error: &CompilerError) -> Self {
+        let diagnostic = ErrorReporter::new(false).error_to_diagnostic(error);
+        Self::from_diagnostic(&diagnostic, Some(error_code(error)))
+    }
+
+    fn from_diagnostic(diagnostic: &Diagnostic, code: Option<&str>) -> Self {
+        let level = match diagnostic.severity {
+            Severity::Error => JsonDiagnosticLevel::Error,
+            Severity::Warning => JsonDiagnosticLevel::Warning,
+            Severity::Info | Severity::Hint => JsonDiagnosticLevel::Note,
+        };
+
+        let spans = match &diagnostic.location {
+            Some(span) => vec![JsonSpan::primary(span, None)],
+            None => vec![],
+        };
+
+        let mut children = Vec::new();
+        if let Some(help) = &diagnostic.help {
+            children.push(Self::child_note(format!("help: {}", help)));
+        }
+        if let Some(note) = &diagnostic.note {
+            children.push(Self::child_note(format!("note: {}", note)));
+        }
+
+        Self {
+            message: diagnostic.message.clone(),
+            code: code.map(|code| JsonDiagnosticCode {
+                code: code.to_string(),
+                explanation: error_explanation(code),
+            }),
+            level,
+            spans,
+            children,
+        }
+    }
+
+    fn child_note(message: String) -> Self {
+        Self {
+            message,
+            code: None,
+            level: JsonDiagnosticLevel::Note,
+            spans: vec![],
+            children: vec![],
+        }
+    }
+
+    /// Serialize as a single line of JSON, the way rustc streams one
+    /// object per diagnostic.
+    pub fn to_json_line(&self) -> String {
+        serde_json::to_string(self).unwrap_or_else(|e| {
+            format!("{{\"message\":\"failed to serialize diagnostic: {}\",\"level\":\"error\"}}", e)
+        })
+    }
+
+    /// Print the diagnostic as one JSON line to stdout.
+    pub fn print(&self) {
+        println!("{}", self.to_json_line());
+    }
+}
+
+/// Final summary object emitted after all per-file diagnostics.
+#[derive(Debug, Clone, Serialize)]
+pub struct JsonSummary {
+    pub files_passed: usize,
+    pub files_with_errors: usize,
+    pub total_errors: usize,
+}
+
+impl JsonSummary {
+    pub fn print(&self) {
+        let line = serde_json::to_string(self).unwrap_or_else(|e| {
+            format!("{{\"files_passed\":0,\"files_with_errors\":0,\"total_errors\":0,\"serialize_error\":\"{}\"}}", e)
+        });
+        println!("{}", line);
+    }
+}
+
+/// A short, stable error code for each [`CompilerError`] variant.
+fn error_code(error: &CompilerError) -> &'static str {
+    match error {
+        CompilerError::Lexer { .. } => "E0001",
+        CompilerError::Parser { .. } => "E0002",
+        CompilerError::Semantic { .. } => "E0003",
+        CompilerError::Codegen { .. } => "E0004",
+        CompilerError::IoError { .. } => "E0005",
+        CompilerError::Internal { .. } => "E0006",
+        CompilerError::TimeBudgetExceeded { .. } => "E0007",
+        CompilerError::ImportCycle { .. } => "E0008",
+        CompilerError::ParseError(_) => "E0002",
+        CompilerError::TypeError(_) => "E0003",
+        CompilerError::SemanticError(_) => "E0003",
+    }
+}
+
+/// A one-line explanation for each code in [`error_code`].
+fn error_explanation(code: &str) -> Option<String> {
+    let explanation = match code {
+        "E0001" => "An error occurred while tokenizing the source file.",
+        "E0002" => "The parser could not build a valid syntax tree from the tokens.",
+        "E0003" => "A semantic check (type checking, name resolution, etc.) failed.",
+        "E0004" => "Code generation or linking failed.",
+        "E0005" => "An I/O operation (reading a source file, writing output) failed.",
+        "E0006" => "The compiler hit an internal error; this is a compiler bug.",
+        "E0007" => "A compilation phase exceeded the time budget set by --time-budget-ms.",
+        "E0008" => "A module imports itself, directly or through other modules.",
+        _ => return None,
+    };
+    Some(explanation.to_string())
+}
@@ -23,6 +23,8 @@ pub use reporter::DetailedErrorReporter;
 pub mod structured;
 pub mod enhancement;
 pub mod intent_analysis;
+pub mod json_diagnostic;
+pub mod snippet;
 
 use std::fmt;
 use thiserror::Error;
@@ -111,6 +113,66 @@ pub enum LexerError {
 
     #[error("Maximum nesting depth exceeded at {location}")]
     MaxNestingDepthExceeded { location: SourceLocation },
+
+    #[error("Hex escape '\\x' at {location} is missing digits (need exactly 2)")]
+    TooShortHexEscape { location: SourceLocation },
+
+    #[error("Invalid hex digit '{character}' in '\\x' escape at {location}")]
+    InvalidCharInHexEscape {
+        character: char,
+        location: SourceLocation,
+    },
+
+    #[error("Hex escape value {value:#x} at {location} is out of range (must be <= 0x7F)")]
+    OutOfRangeHexEscape { value: u32, location: SourceLocation },
+
+    #[error("Unicode escape '\\u{{}}' at {location} has no digits")]
+    EmptyUnicodeEscape { location: SourceLocation },
+
+    #[error("Unicode escape '\\u{{...' at {location} is missing closing '}}'")]
+    UnclosedUnicodeEscape { location: SourceLocation },
+
+    #[error("Invalid hex digit '{character}' in '\\u{{...}}' escape at {location}")]
+    InvalidCharInUnicodeEscape {
+        character: char,
+        location: SourceLocation,
+    },
+
+    #[error("Unicode escape value {value:#x} at {location} is out of range")]
+    OutOfRangeUnicodeEscape { value: u32, location: SourceLocation },
+
+    #[error("Unicode text-flow control character (code point {code_point:#06x}) at {location} can make source read differently than it executes (\"Trojan Source\")")]
+    TextFlowControlChar {
+        code_point: u32,
+        location: SourceLocation,
+    },
+
+    #[error("Unterminated raw string at {location} (expected closing '\"' followed by {hash_count} '#' before end of file)")]
+    UnterminatedRawString {
+        hash_count: usize,
+        location: SourceLocation,
+    },
+}
+
+/// A [`LexerError`] paired with the full source span it covers, for tools
+/// (editors, LSP) that want to underline the offending slice rather than
+/// just a single point. Produced by [`crate::lexer::Lexer::tokenize_recovering`].
+#[derive(Debug, Clone)]
+pub struct LexerDiagnostic {
+    pub error: LexerError,
+    pub span: SourceSpan,
+}
+
+impl LexerDiagnostic {
+    pub fn new(error: LexerError, span: SourceSpan) -> Self {
+        Self { error, span }
+    }
+}
+
+impl fmt::Display for LexerDiagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.error)
+    }
 }
 
 /// Parsing errors
@@ -367,6 +429,23 @@ pub enum SemanticError {
         enum_name: String,
         location: SourceLocation,
     },
+
+    #[error("Literal {value} does not fit in {type_name} (valid range is {min}..={max}) at {location}")]
+    LiteralOutOfRange {
+        value: i128,
+        type_name: String,
+        min: i128,
+        max: i128,
+        location: SourceLocation,
+    },
+
+    #[error("Call to '{function}' with path '{path}' is not covered by a granted {required} capability at {location}")]
+    CapabilityViolation {
+        function: String,
+        path: String,
+        required: String,
+        location: SourceLocation,
+    },
 }
 
 impl From<std::io::Error> for SemanticError {
@@ -431,7 +510,17 @@ pub enum CompilerError {
 
     #[error("Internal compiler error: {message}")]
     Internal { message: String },
-    
+
+    #[error("phase '{phase}' took {actual_ms}ms, exceeding the {budget_ms}ms time budget")]
+    TimeBudgetExceeded {
+        phase: String,
+        budget_ms: u128,
+        actual_ms: u128,
+    },
+
+    #[error("import cycle detected: {chain}")]
+    ImportCycle { chain: String },
+
     // Wrapper types for new error system compatibility
     #[error("Parse error: {0}")]
     ParseError(ParseError),
@@ -585,7 +674,15 @@ impl ErrorReporter {
     }
 
     pub fn report_error(&self, error: &CompilerError) {
-        let diagnostic = match error {
+        let diagnostic = self.error_to_diagnostic(error);
+        self.report_diagnostic(&diagnostic);
+    }
+
+    /// Convert a [`CompilerError`] to a [`Diagnostic`] without printing it,
+    /// so callers that need a different rendering (e.g. the JSON
+    /// diagnostic emitter) don't have to duplicate this mapping.
+    pub fn error_to_diagnostic(&self, error: &CompilerError) -> Diagnostic {
+        match error {
             CompilerError::Lexer { source } => self.lexer_error_to_diagnostic(source),
             CompilerError::Parser { source } => self.parser_error_to_diagnostic(source),
             CompilerError::Semantic { source } => self.semantic_error_to_diagnostic(source),
@@ -598,6 +695,14 @@ impl ErrorReporter {
                 format!("Internal compiler error: {}", message),
                 None,
             ).with_note("This is a bug in the compiler. Please report it.".to_string()),
+            CompilerError::TimeBudgetExceeded { .. } => Diagnostic::error(
+                error.to_string(),
+                None,
+            ),
+            CompilerError::ImportCycle { .. } => Diagnostic::error(
+                error.to_string(),
+                None,
+            ),
             CompilerError::ParseError(e) => Diagnostic::error(
                 format!("Parse error: {}", e),
                 None,
@@ -607,9 +712,7 @@ impl ErrorReporter {
                 None,
             ),
             CompilerError::SemanticError(e) => self.semantic_error_to_diagnostic(&e),
-        };
-
-        self.report_diagnostic(&diagnostic);
+        }
     }
 
     fn lexer_error_to_diagnostic(&self, error: &LexerError) -> Diagnostic {
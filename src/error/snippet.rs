@@ -0,0 +1,231 @@
+// Copyright 2025 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Source-snippet diagnostic rendering, in the style of `annotate_snippets`.
+//!
+//! Today the lexer/parser/semantic stages only surface failures as an
+//! opaque `Result::Err`, so property tests can do little more than assert
+//! `is_err()`. [`Diagnostic`] carries a severity, message, primary
+//! [`SourceLocation`], and optional labeled secondary spans; [`SnippetRenderer`]
+//! reads the original source buffer and prints a caret-underlined excerpt
+//! with line numbers, so users get column-accurate messages instead of a
+//! bare error string.
+
+use crate::error::{CompilerError, ErrorReporter, Severity, SourceLocation, SourceSpan};
+
+/// A secondary span rendered alongside a diagnostic's primary span, with a
+/// short label explaining why it's relevant (e.g. "expected due to this").
+#[derive(Debug, Clone)]
+pub struct SecondaryLabel {
+    pub span: SourceSpan,
+    pub label: String,
+}
+
+/// A diagnostic anchored to a location in a specific source file, ready to
+/// be rendered with [`SnippetRenderer::render`].
+#[derive(Debug, Clone)]
+pub struct Diagnostic {
+    pub severity: Severity,
+    pub message: String,
+    pub primary: SourceLocation,
+    pub secondary: Vec<SecondaryLabel>,
+}
+
+impl Diagnostic {
+    pub fn new(severity: Severity, message: String, primary: SourceLocation) -> Self {
+        Self {
+            severity,
+            message,
+            primary,
+            secondary: Vec::new(),
+        }
+    }
+
+    pub fn error(message: String, primary: SourceLocation) -> Self {
+        Self::new(Severity::Error, message, primary)
+    }
+
+    pub fn with_secondary(mut self, span: SourceSpan, label: String) -> Self {
+        self.secondary.push(SecondaryLabel { span, label });
+        self
+    }
+}
+
+/// Renders [`Diagnostic`]s against an in-memory copy of the source buffer
+/// they reference, producing a rustc-style caret-underlined excerpt.
+pub struct SnippetRenderer<'a> {
+    filename: &'a str,
+    lines: Vec<&'a str>,
+}
+
+impl<'a> SnippetRenderer<'a> {
+    /// `filename` must match the `file` field of the [`SourceLocation`]s
+    /// being rendered (the `"test.aether"`-style name already stored on
+    /// `Lexer`/`SourceLocation`); spans from a different file are skipped.
+    pub fn new(filename: &'a str, source: &'a str) -> Self {
+        Self {
+            filename,
+            lines: source.lines().collect(),
+        }
+    }
+
+    fn line_text(&self, line_number: usize) -> Option<&'a str> {
+        line_number.checked_sub(1).and_then(|idx| self.lines.get(idx).copied())
+    }
+
+    /// Render one diagnostic as a multi-line string: a header, the primary
+    /// span's line with a caret underneath it, and one caret-underlined
+    /// line per labeled secondary span.
+    pub fn render(&self, diagnostic: &Diagnostic) -> String {
+        let mut out = format!("{}: {}\n", diagnostic.severity, diagnostic.message);
+        out.push_str(&format!("  --> {}\n", diagnostic.primary));
+        self.render_span(&mut out, &diagnostic.primary, None);
+
+        for secondary in &diagnostic.secondary {
+            self.render_span(&mut out, &secondary.span.start, Some(&secondary.label));
+        }
+
+        out
+    }
+
+    fn render_span(&self, out: &mut String, location: &SourceLocation, label: Option<&str>) {
+        if location.file != self.filename {
+            return;
+        }
+        let Some(line) = self.line_text(location.line) else {
+            return;
+        };
+
+        let gutter_width = location.line.to_string().len().max(3);
+        out.push_str(&format!("{:>width$} |\n", "", width = gutter_width));
+        out.push_str(&format!("{:>width$} | {}\n", location.line, line, width = gutter_width));
+
+        let caret_padding = " ".repeat(location.column.saturating_sub(1));
+        let marker = match label {
+            Some(label) => format!("^ {}", label),
+            None => "^".to_string(),
+        };
+        out.push_str(&format!("{:>width$} | {}{}\n", "", caret_padding, marker, width = gutter_width));
+    }
+}
+
+/// Render a [`CompilerError`] as a caret-annotated source snippet for
+/// `--error-format=human`, routing through [`ErrorReporter::error_to_diagnostic`]
+/// so this never disagrees with the JSON renderer about what an error means.
+/// Falls back to a bare `severity: message` line when the error carries no
+/// location (e.g. an I/O error) or its source file can't be read back off
+/// disk (e.g. it was since deleted).
+pub fn render_compiler_error(error: &CompilerError) -> String {
+    let diagnostic = ErrorReporter::new(false).error_to_diagnostic(error);
+
+    let rendered = diagnostic.location.as_ref().and_then(|span| {
+        let source = std::fs::read_to_string(&span.start.file).ok()?;
+        let snippet = Diagnostic::new(diagnostic.severity, diagnostic.message.clone(), span.start.clone());
+        Some(SnippetRenderer::new(&span.start.file, &source).render(&snippet))
+    });
+
+    let mut out = rendered.unwrap_or_else(|| format!("{}: {}\n", diagnostic.severity, diagnostic.message));
+    if let Some(help) = &diagnostic.help {
+        out.push_str(&format!("  = help: {}\n", help));
+    }
+    if let Some(note) = &diagnostic.note {
+        out.push_str(&format!("  = note: {}\n", note));
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renders_caret_under_primary_span() {
+        let source = "(DECLARE_CONSTANT\n    (VALUE 42)\n)";
+        let renderer = SnippetRenderer::new("test.aether", source);
+        let diagnostic = Diagnostic::error(
+            "Type mismatch: expected STRING, found INTEGER".to_string(),
+            SourceLocation::new("test.aether".to_string(), 2, 12, 22),
+        );
+
+        let rendered = renderer.render(&diagnostic);
+
+        assert!(rendered.contains("error: Type mismatch: expected STRING, found INTEGER"));
+        assert!(rendered.contains("test.aether:2:12"));
+        assert!(rendered.contains("(VALUE 42)"));
+        assert!(rendered.contains("^"));
+    }
+
+    #[test]
+    fn skips_spans_from_a_different_file() {
+        let renderer = SnippetRenderer::new("a.aether", "(X)");
+        let diagnostic = Diagnostic::error(
+            "unrelated".to_string(),
+            SourceLocation::new("b.aether".to_string(), 1, 1, 0),
+        );
+
+        let rendered = renderer.render(&diagnostic);
+
+        assert!(!rendered.contains("(X)"));
+    }
+
+    #[test]
+    fn renders_labeled_secondary_spans() {
+        let source = "(DECLARE_CONSTANT\n    (TYPE STRING)\n    (VALUE 42)\n)";
+        let renderer = SnippetRenderer::new("test.aether", source);
+        let diagnostic = Diagnostic::error(
+            "Type mismatch".to_string(),
+            SourceLocation::new("test.aether".to_string(), 3, 12, 40),
+        )
+        .with_secondary(
+            SourceSpan::single(SourceLocation::new("test.aether".to_string(), 2, 11, 20)),
+            "expected due to this".to_string(),
+        );
+
+        let rendered = renderer.render(&diagnostic);
+
+        assert!(rendered.contains("(TYPE STRING)"));
+        assert!(rendered.contains("expected due to this"));
+    }
+
+    #[test]
+    fn render_compiler_error_reads_source_and_underlines_it() {
+        let dir = std::env::temp_dir().join(format!("aether_snippet_test_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("bad.aether");
+        std::fs::write(&path, "(DECLARE_CONSTANT\n    (VALUE @)\n)").unwrap();
+
+        let error = CompilerError::Lexer {
+            source: crate::error::LexerError::UnexpectedCharacter {
+                character: '@',
+                location: SourceLocation::new(path.to_string_lossy().to_string(), 2, 12, 27),
+            },
+        };
+
+        let rendered = render_compiler_error(&error);
+
+        assert!(rendered.contains("Unexpected character '@'"));
+        assert!(rendered.contains("(VALUE @)"));
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn render_compiler_error_falls_back_without_a_location() {
+        let error = CompilerError::IoError { message: "File 'x.aether' not found".to_string() };
+
+        let rendered = render_compiler_error(&error);
+
+        assert!(rendered.contains("File 'x.aether' not found"));
+    }
+}
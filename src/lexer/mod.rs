@@ -31,7 +31,25 @@ pub enum TokenType {
     // Literals
     Integer(i64),
     Float(f64),
+    /// An integer literal carrying an explicit width/signedness suffix,
+    /// e.g. `42i64`, `100u8`, `7i32`. Unsuffixed integers still lex as
+    /// [`TokenType::Integer`] with the existing i64 default.
+    SizedInteger { value: i64, bits: u32, signed: bool },
+    /// A float literal carrying an explicit width suffix, e.g. `3.5f32`.
+    /// Unsuffixed floats still lex as [`TokenType::Float`] with the
+    /// existing f64 default.
+    SizedFloat { value: f64, bits: u32 },
     String(String),
+    /// A piece of a string literal's text that contains at least one `${`
+    /// interpolation, i.e. the text before the first `${`, between a `}`
+    /// and the next `${`, or after the last `}` before the closing `"`.
+    /// A string with no interpolation still lexes as a single
+    /// [`TokenType::String`], exactly as before.
+    StringFragment(String),
+    /// Opens an interpolated expression inside a string literal (`${`).
+    InterpolationStart,
+    /// Closes an interpolated expression inside a string literal (`}`).
+    InterpolationEnd,
     Character(char),
     Boolean(bool),
     Identifier(String),
@@ -53,6 +71,11 @@ pub enum TokenType {
 
     // End of file
     Eof,
+
+    /// A synthetic token covering a region that failed to tokenize,
+    /// produced only by [`Lexer::tokenize_recovering`]. Carries the
+    /// corresponding diagnostic message for display.
+    Error(String),
 }
 
 /// A token with its type and location information
@@ -61,6 +84,12 @@ pub struct Token {
     pub token_type: TokenType,
     pub location: SourceLocation,
     pub lexeme: String,
+    /// For float literals, the mantissa/fraction/exponent pieces as
+    /// written, preserved alongside the rounded `f64` in `token_type` so a
+    /// later pass that needs the exact decimal digits doesn't have to
+    /// re-derive them by formatting the `f64` back to a string. `None` for
+    /// every other token kind, including integer literals.
+    pub rational: Option<RationalParts>,
 }
 
 impl Token {
@@ -69,8 +98,78 @@ impl Token {
             token_type,
             location,
             lexeme,
+            rational: None,
         }
     }
+
+    /// Attach the lossless decimal pieces of a float literal to this token.
+    pub fn with_rational(mut self, rational: RationalParts) -> Self {
+        self.rational = Some(rational);
+        self
+    }
+}
+
+/// The constituent pieces of a float literal's decimal representation, as
+/// written in the source: the digits before the point, the digits after
+/// it, and the exponent following `e`/`E` (0 if none was written). See
+/// [`Token::rational`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RationalParts {
+    pub mantissa: String,
+    pub fraction: String,
+    pub exponent: i32,
+}
+
+/// Unicode bidirectional/text-flow override and isolate formatting
+/// characters. Letting these appear unflagged in strings, comments, or
+/// quoted identifiers allows "Trojan Source" attacks, where source reads
+/// differently to a human than it does to the compiler.
+fn is_text_flow_control_char(ch: char) -> bool {
+    matches!(
+        ch,
+        '\u{202A}' | '\u{202B}' | '\u{202C}' | '\u{202D}' | '\u{202E}'
+            | '\u{2066}' | '\u{2067}' | '\u{2068}' | '\u{2069}'
+    )
+}
+
+/// Coarse-grained lexer mode, tracked for introspection by resumable/streaming
+/// consumers (editors, network readers) that feed input in chunks via
+/// [`Lexer::feed`] rather than handing over the whole source up front.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LexerState {
+    /// Not in the middle of any multi-character token.
+    StartLine,
+    InIdentifier,
+    InString,
+    InComment,
+    InQuotedIdentifier,
+}
+
+/// A recognized `i8`/`i16`/.../`u64`/`f32`/`f64` suffix on a numeric literal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum NumberSuffix {
+    Integer { bits: u32, signed: bool },
+    Float { bits: u32 },
+}
+
+/// An explicit lexer mode, pushed/popped on a stack so tokenization can
+/// switch rule sets mid-scan and resume the previous rule set afterwards -
+/// modeled on the Enso flexer's `push_state`/`pop_state`. A child mode (e.g.
+/// `InInterpolation`) is tried strictly before falling back to whatever mode
+/// it was pushed from, the way `Lexer::next_token` dispatches on
+/// `current_mode()` first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum LexerMode {
+    /// Ordinary top-level tokenization: the rules `next_token` already had.
+    Normal,
+    /// Between a string's opening and closing `"`, scanning fragment text
+    /// and `${` interpolation openers via [`Lexer::scan_string_fragment`].
+    InString,
+    /// Between `${` and its matching `}`. Normal expression tokenization
+    /// rules apply (this mode inherits them), except a bare `}` is not
+    /// itself a token: it closes the interpolation, popping back to
+    /// `InString` and emitting [`TokenType::InterpolationEnd`].
+    InInterpolation,
 }
 
 /// Lexer for AetherScript source code
@@ -82,6 +181,21 @@ pub struct Lexer {
     column: usize,
     file_name: String,
     keywords: HashMap<String, String>,
+    state: LexerState,
+    /// Explicit mode stack (see [`LexerMode`]); always has at least one
+    /// entry (`Normal`) - [`Lexer::pop_state`] refuses to pop the last one.
+    mode_stack: Vec<LexerMode>,
+    /// One flag per currently-open string (parallel to the `InString`
+    /// entries on `mode_stack`): whether `${` has been seen yet in that
+    /// string, so its closing `"` knows whether to emit a final
+    /// [`TokenType::StringFragment`] or, for a plain non-interpolated
+    /// string, the original single [`TokenType::String`].
+    string_frames: Vec<bool>,
+    /// A second token already produced by the current scan step, returned
+    /// on the next call to [`Lexer::next_token`] before resuming the scan
+    /// (e.g. the [`TokenType::InterpolationStart`] that follows the
+    /// [`TokenType::StringFragment`] emitted for the text before a `${`).
+    pending_token: Option<Token>,
 }
 
 impl Lexer {
@@ -98,12 +212,60 @@ impl Lexer {
             column: 1,
             file_name,
             keywords: HashMap::new(),
+            state: LexerState::StartLine,
+            mode_stack: vec![LexerMode::Normal],
+            string_frames: Vec::new(),
+            pending_token: None,
         };
 
         lexer.initialize_keywords();
         lexer
     }
 
+    /// Current coarse-grained lexer mode. Useful for streaming consumers that
+    /// want to know whether it's safe to pause between [`Lexer::feed`] calls.
+    pub fn state(&self) -> LexerState {
+        self.state
+    }
+
+    /// Depth of the explicit [`LexerMode`] stack (always >= 1). Used by
+    /// fuzzing to confirm that even unbalanced `${`/`"` input leaves the
+    /// stack back at its resting depth of 1 once tokenization finishes,
+    /// rather than leaking nested modes.
+    pub fn mode_stack_depth(&self) -> usize {
+        self.mode_stack.len()
+    }
+
+    fn current_mode(&self) -> LexerMode {
+        *self.mode_stack.last().expect("mode_stack always has Normal at the bottom")
+    }
+
+    fn push_state(&mut self, mode: LexerMode) {
+        self.mode_stack.push(mode);
+    }
+
+    /// Pop back to the previous mode. Refuses to pop the bottommost
+    /// `Normal` entry, so a malformed/unbalanced scan can never leave the
+    /// stack empty.
+    fn pop_state(&mut self) -> LexerMode {
+        if self.mode_stack.len() > 1 {
+            self.mode_stack.pop().expect("just checked len > 1")
+        } else {
+            LexerMode::Normal
+        }
+    }
+
+    /// Append more source text to the lexer's buffer, so tokenization can
+    /// resume where it left off (e.g. as bytes arrive over a socket, or as an
+    /// editor buffer grows) instead of requiring the whole source up front.
+    pub fn feed(&mut self, more: &str) {
+        let had_more = self.position < self.input.len();
+        self.input.extend(more.graphemes(true).flat_map(|g| g.chars()));
+        if !had_more {
+            self.current_char = self.input.get(self.position).copied();
+        }
+    }
+
     /// Initialize the keywords map with all AetherScript keywords
     fn initialize_keywords(&mut self) {
         let keywords = [
@@ -206,157 +368,560 @@ impl Lexer {
         }
     }
 
-    /// Read a number (integer or float)
+    /// Read a number: a `0x`/`0b`/`0o`-prefixed integer, or a decimal
+    /// integer/float. Decimal digit groups (mantissa, fraction, exponent)
+    /// may contain `_` digit separators (e.g. `1_000_000`), which are
+    /// stripped before parsing.
     fn read_number(&mut self) -> Result<Token, LexerError> {
         let start_location = self.current_location();
-        let mut number_str = String::new();
-        let mut is_float = false;
-
-        // Handle negative numbers
-        if self.current_char == Some('-') {
-            number_str.push('-');
+        let negative = self.current_char == Some('-');
+        if negative {
             self.advance();
         }
 
-        // Read digits before decimal point
-        while let Some(ch) = self.current_char {
-            if ch.is_ascii_digit() {
-                number_str.push(ch);
-                self.advance();
-            } else if ch == '.' && !is_float && self.peek().is_some_and(|c| c.is_ascii_digit()) {
-                is_float = true;
-                number_str.push(ch);
-                self.advance();
-            } else {
-                break;
-            }
+        if let Some(token) = self.try_read_radix_integer(negative, &start_location)? {
+            return Ok(token);
+        }
+
+        let sign = if negative { "-" } else { "" };
+        let mantissa = self.read_digit_group(&start_location)?;
+        let mut number_str = format!("{}{}", sign, mantissa);
+        let mut is_float = false;
+        let mut fraction = String::new();
+
+        if self.current_char == Some('.')
+            && self.peek().is_some_and(|c| c.is_ascii_digit() || c == '_')
+        {
+            is_float = true;
+            number_str.push('.');
+            self.advance();
+            fraction = self.read_digit_group(&start_location)?;
+            number_str.push_str(&fraction);
         }
 
-        // Handle scientific notation
+        let mut exponent = 0i32;
         if let Some(ch) = self.current_char {
             if ch == 'e' || ch == 'E' {
                 is_float = true;
                 number_str.push(ch);
                 self.advance();
 
-                // Optional + or - after e/E
+                let mut exponent_sign = "";
                 if let Some(sign) = self.current_char {
                     if sign == '+' || sign == '-' {
+                        exponent_sign = if sign == '-' { "-" } else { "" };
                         number_str.push(sign);
                         self.advance();
                     }
                 }
 
-                // Read exponent digits
-                while let Some(ch) = self.current_char {
-                    if ch.is_ascii_digit() {
-                        number_str.push(ch);
-                        self.advance();
-                    } else {
-                        break;
-                    }
-                }
+                let exponent_digits = self.read_digit_group(&start_location)?;
+                number_str.push_str(&exponent_digits);
+                exponent = format!("{}{}", exponent_sign, exponent_digits)
+                    .parse::<i32>()
+                    .map_err(|_| LexerError::InvalidNumber {
+                        value: number_str.clone(),
+                        location: start_location.clone(),
+                    })?;
             }
         }
 
+        let suffix = self.read_number_suffix();
+
         if is_float {
-            match number_str.parse::<f64>() {
-                Ok(value) => Ok(Token::new(
-                    TokenType::Float(value),
-                    start_location,
-                    number_str,
-                )),
-                Err(_) => Err(LexerError::InvalidNumber {
-                    value: number_str,
+            let value = number_str.parse::<f64>().map_err(|_| LexerError::InvalidNumber {
+                value: number_str.clone(),
+                location: start_location.clone(),
+            })?;
+            let rational = RationalParts {
+                mantissa: format!("{}{}", sign, mantissa),
+                fraction,
+                exponent,
+            };
+
+            match suffix {
+                None => Ok(Token::new(TokenType::Float(value), start_location, number_str)
+                    .with_rational(rational)),
+                Some(NumberSuffix::Float { bits }) => {
+                    let lexeme = format!("{}f{}", number_str, bits);
+                    Ok(Token::new(TokenType::SizedFloat { value, bits }, start_location, lexeme)
+                        .with_rational(rational))
+                }
+                Some(NumberSuffix::Integer { bits, signed }) => Err(LexerError::InvalidNumber {
+                    value: format!("{}{}{}", number_str, if signed { "i" } else { "u" }, bits),
                     location: start_location,
                 }),
             }
         } else {
-            match number_str.parse::<i64>() {
-                Ok(value) => Ok(Token::new(
-                    TokenType::Integer(value),
-                    start_location,
-                    number_str,
-                )),
-                Err(_) => Err(LexerError::InvalidNumber {
-                    value: number_str,
+            let value = number_str.parse::<i64>().map_err(|_| LexerError::InvalidNumber {
+                value: number_str.clone(),
+                location: start_location.clone(),
+            })?;
+
+            match suffix {
+                None => Ok(Token::new(TokenType::Integer(value), start_location, number_str)),
+                Some(NumberSuffix::Integer { bits, signed }) => {
+                    let lexeme = format!("{}{}{}", number_str, if signed { "i" } else { "u" }, bits);
+                    Ok(Token::new(
+                        TokenType::SizedInteger { value, bits, signed },
+                        start_location,
+                        lexeme,
+                    ))
+                }
+                Some(NumberSuffix::Float { bits }) => Err(LexerError::InvalidNumber {
+                    value: format!("{}f{}", number_str, bits),
                     location: start_location,
                 }),
             }
         }
     }
 
-    /// Read a string literal
-    fn read_string(&mut self) -> Result<Token, LexerError> {
-        let start_location = self.current_location();
-        let mut string_value = String::new();
-        let mut lexeme = String::new();
+    /// Read a `0x`/`0b`/`0o`-prefixed integer literal, if the input is
+    /// positioned at one. Returns `Ok(None)` without consuming anything if
+    /// the current character isn't `0` followed by a radix letter, so the
+    /// caller falls back to decimal parsing (this also keeps a lone `0`, or
+    /// a decimal literal like `0123`, reading as plain decimal - there is no
+    /// C-style implicit octal here).
+    fn try_read_radix_integer(
+        &mut self,
+        negative: bool,
+        start_location: &SourceLocation,
+    ) -> Result<Option<Token>, LexerError> {
+        if self.current_char != Some('0') {
+            return Ok(None);
+        }
+        let radix = match self.peek() {
+            Some('x') | Some('X') => 16,
+            Some('b') | Some('B') => 2,
+            Some('o') | Some('O') => 8,
+            _ => return Ok(None),
+        };
 
-        // Skip opening quote
-        lexeme.push('"');
-        self.advance();
+        self.advance(); // consume '0'
+        let prefix_char = self.current_char.expect("peek confirmed a radix letter follows");
+        self.advance(); // consume 'x'/'b'/'o'
+
+        let digits = self.read_digit_group_radix(radix, start_location)?;
+        let magnitude =
+            i64::from_str_radix(&digits, radix).map_err(|_| LexerError::InvalidNumber {
+                value: digits.clone(),
+                location: start_location.clone(),
+            })?;
+        let value = if negative { -magnitude } else { magnitude };
+        let lexeme = format!(
+            "{}0{}{}",
+            if negative { "-" } else { "" },
+            prefix_char,
+            digits
+        );
+
+        let suffix = self.read_number_suffix();
+        match suffix {
+            None => Ok(Some(Token::new(TokenType::Integer(value), start_location.clone(), lexeme))),
+            Some(NumberSuffix::Integer { bits, signed }) => {
+                let lexeme = format!("{}{}{}", lexeme, if signed { "i" } else { "u" }, bits);
+                Ok(Some(Token::new(
+                    TokenType::SizedInteger { value, bits, signed },
+                    start_location.clone(),
+                    lexeme,
+                )))
+            }
+            Some(NumberSuffix::Float { bits }) => Err(LexerError::InvalidNumber {
+                value: format!("{}f{}", lexeme, bits),
+                location: start_location.clone(),
+            }),
+        }
+    }
 
-        while let Some(ch) = self.current_char {
-            lexeme.push(ch);
+    /// Read a run of base-10 digits, allowing `_` digit separators anywhere
+    /// in the run (stripped from the returned string). Errors if the run is
+    /// empty, e.g. a `.` with nothing but `_` after it.
+    fn read_digit_group(&mut self, start_location: &SourceLocation) -> Result<String, LexerError> {
+        self.read_digit_group_radix(10, start_location)
+    }
 
-            if ch == '"' {
-                // End of string
+    /// Read a run of base-`radix` digits, allowing `_` digit separators
+    /// anywhere in the run (stripped from the returned string). Errors if
+    /// the run is empty, e.g. `0x` with no hex digits following.
+    fn read_digit_group_radix(
+        &mut self,
+        radix: u32,
+        start_location: &SourceLocation,
+    ) -> Result<String, LexerError> {
+        let mut digits = String::new();
+        while let Some(ch) = self.current_char {
+            if ch == '_' {
                 self.advance();
-                return Ok(Token::new(
-                    TokenType::String(string_value),
-                    start_location,
-                    lexeme,
-                ));
-            } else if ch == '\\' {
-                // Handle escape sequences
+            } else if ch.is_digit(radix) {
+                digits.push(ch);
                 self.advance();
-                match self.current_char {
-                    Some('n') => {
-                        string_value.push('\n');
-                        lexeme.push('n');
-                    }
-                    Some('t') => {
-                        string_value.push('\t');
-                        lexeme.push('t');
-                    }
-                    Some('r') => {
-                        string_value.push('\r');
-                        lexeme.push('r');
+            } else {
+                break;
+            }
+        }
+
+        if digits.is_empty() {
+            return Err(LexerError::InvalidNumber {
+                value: String::new(),
+                location: start_location.clone(),
+            });
+        }
+
+        Ok(digits)
+    }
+
+    /// Recognize a numeric literal suffix (`i8`/`i16`/`i32`/`i64`/`u8`/`u16`/
+    /// `u32`/`u64`/`f32`/`f64`) immediately following a number, consuming it
+    /// from the input only if it's an exact, complete match (a trailing
+    /// identifier character means this is something else entirely, e.g. a
+    /// variable named `i64x`, so the input is left untouched).
+    fn read_number_suffix(&mut self) -> Option<NumberSuffix> {
+        let prefix = match self.current_char {
+            Some('i') => 'i',
+            Some('u') => 'u',
+            Some('f') => 'f',
+            _ => return None,
+        };
+
+        let mut lookahead = 1;
+        let mut digits = String::new();
+        while let Some(ch) = self.input.get(self.position + lookahead).copied() {
+            if ch.is_ascii_digit() {
+                digits.push(ch);
+                lookahead += 1;
+            } else {
+                break;
+            }
+        }
+
+        if let Some(ch) = self.input.get(self.position + lookahead).copied() {
+            if ch.is_alphanumeric() || ch == '_' {
+                return None;
+            }
+        }
+
+        let bits: u32 = match digits.as_str() {
+            "8" if prefix != 'f' => 8,
+            "16" if prefix != 'f' => 16,
+            "32" => 32,
+            "64" => 64,
+            _ => return None,
+        };
+
+        for _ in 0..lookahead {
+            self.advance();
+        }
+
+        Some(match prefix {
+            'i' => NumberSuffix::Integer { bits, signed: true },
+            'u' => NumberSuffix::Integer { bits, signed: false },
+            _ => NumberSuffix::Float { bits },
+        })
+    }
+
+    /// Begin a string literal: consume the opening `"`, push `InString`
+    /// mode, and scan its first fragment. Called from `next_token` when the
+    /// current character is `"`; every later fragment of the same string
+    /// (after a `${...}` interpolation) is produced by further
+    /// `next_token` calls that see `InString` on top of the mode stack.
+    fn begin_string(&mut self) -> Result<Token, LexerError> {
+        self.advance(); // consume opening quote
+        self.push_state(LexerMode::InString);
+        self.string_frames.push(false);
+        self.state = LexerState::InString;
+        self.scan_string_fragment()
+    }
+
+    /// Scan one fragment of string content up to (not including) the
+    /// closing `"` or the next `${`. Handles the same escape sequences as a
+    /// plain string always has, plus `\$` so a literal `$` before `{` does
+    /// not open an interpolation.
+    ///
+    /// - On `${`: records that this string has interpolation, pushes
+    ///   `InInterpolation`, queues an `InterpolationStart` token as
+    ///   `pending_token` for the *next* `next_token` call, and returns the
+    ///   fragment scanned so far as a `StringFragment` (possibly empty,
+    ///   e.g. for `"${x}"`).
+    /// - On the closing `"`: pops back out of `InString` and returns a
+    ///   `StringFragment` if `${` was seen anywhere in this string, or
+    ///   otherwise a plain `String` token - the same single token a
+    ///   non-interpolated string has always produced.
+    fn scan_string_fragment(&mut self) -> Result<Token, LexerError> {
+        let start_location = self.current_location();
+        let mut value = String::new();
+        let mut lexeme = String::new();
+
+        loop {
+            match self.current_char {
+                None => {
+                    return Err(LexerError::UnterminatedString {
+                        location: start_location,
+                    });
+                }
+                Some('"') => {
+                    lexeme.push('"');
+                    self.advance();
+                    self.pop_state();
+                    self.state = LexerState::StartLine;
+                    let had_interpolation = self.string_frames.pop().unwrap_or(false);
+                    return Ok(if had_interpolation {
+                        Token::new(TokenType::StringFragment(value), start_location, lexeme)
+                    } else {
+                        Token::new(TokenType::String(value), start_location, lexeme)
+                    });
+                }
+                Some('$') if self.peek() == Some('{') => {
+                    let interpolation_location = self.current_location();
+                    self.advance(); // consume '$'
+                    self.advance(); // consume '{'
+                    if let Some(had_interpolation) = self.string_frames.last_mut() {
+                        *had_interpolation = true;
                     }
-                    Some('\\') => {
-                        string_value.push('\\');
-                        lexeme.push('\\');
+                    self.push_state(LexerMode::InInterpolation);
+                    self.pending_token = Some(Token::new(
+                        TokenType::InterpolationStart,
+                        interpolation_location,
+                        "${".to_string(),
+                    ));
+                    return Ok(Token::new(
+                        TokenType::StringFragment(value),
+                        start_location,
+                        lexeme,
+                    ));
+                }
+                Some('\\') => {
+                    lexeme.push('\\');
+                    self.advance();
+                    match self.current_char {
+                        Some('n') => {
+                            value.push('\n');
+                            lexeme.push('n');
+                            self.advance();
+                        }
+                        Some('t') => {
+                            value.push('\t');
+                            lexeme.push('t');
+                            self.advance();
+                        }
+                        Some('r') => {
+                            value.push('\r');
+                            lexeme.push('r');
+                            self.advance();
+                        }
+                        Some('\\') => {
+                            value.push('\\');
+                            lexeme.push('\\');
+                            self.advance();
+                        }
+                        Some('"') => {
+                            value.push('"');
+                            lexeme.push('"');
+                            self.advance();
+                        }
+                        Some('$') => {
+                            value.push('$');
+                            lexeme.push('$');
+                            self.advance();
+                        }
+                        Some('x') => {
+                            lexeme.push('x');
+                            self.advance();
+                            let hex_value = self.read_hex_escape(&mut lexeme)?;
+                            value.push(hex_value as u8 as char);
+                        }
+                        Some('u') => {
+                            lexeme.push('u');
+                            self.advance();
+                            let ch = self.read_unicode_escape(&mut lexeme)?;
+                            value.push(ch);
+                        }
+                        Some(other) => {
+                            return Err(LexerError::InvalidEscapeSequence {
+                                sequence: other.to_string(),
+                                location: self.current_location(),
+                            });
+                        }
+                        None => {
+                            return Err(LexerError::UnterminatedString {
+                                location: start_location,
+                            });
+                        }
                     }
-                    Some('"') => {
-                        string_value.push('"');
-                        lexeme.push('"');
+                }
+                Some(ch) if ch == '\n' || ch == '\r' => {
+                    return Err(LexerError::UnterminatedString {
+                        location: start_location,
+                    });
+                }
+                Some(ch) if is_text_flow_control_char(ch) => {
+                    return Err(LexerError::TextFlowControlChar {
+                        code_point: ch as u32,
+                        location: self.current_location(),
+                    });
+                }
+                Some(ch) => {
+                    value.push(ch);
+                    lexeme.push(ch);
+                    self.advance();
+                }
+            }
+        }
+    }
+
+    /// Read a raw string: `r"..."` or `r#"..."#` (with any number of `#`s),
+    /// closed by a `"` followed by the same number of `#`s. No escape
+    /// processing happens inside, and the content may span multiple lines -
+    /// line/column tracking advances normally via [`Lexer::advance`].
+    fn read_raw_string(&mut self) -> Result<Token, LexerError> {
+        let start_location = self.current_location();
+        let mut lexeme = String::from("r");
+        self.advance(); // consume 'r'
+
+        let mut hash_count = 0usize;
+        while self.current_char == Some('#') {
+            hash_count += 1;
+            lexeme.push('#');
+            self.advance();
+        }
+
+        if self.current_char != Some('"') {
+            return Err(LexerError::UnexpectedCharacter {
+                character: self.current_char.unwrap_or('r'),
+                location: self.current_location(),
+            });
+        }
+        lexeme.push('"');
+        self.advance();
+
+        let mut string_value = String::new();
+        loop {
+            match self.current_char {
+                None => {
+                    return Err(LexerError::UnterminatedRawString {
+                        hash_count,
+                        location: start_location,
+                    });
+                }
+                Some('"') if self.raw_string_closes_here(hash_count) => {
+                    lexeme.push('"');
+                    self.advance();
+                    for _ in 0..hash_count {
+                        lexeme.push('#');
+                        self.advance();
                     }
-                    Some(other) => {
-                        return Err(LexerError::InvalidEscapeSequence {
-                            sequence: other.to_string(),
+                    return Ok(Token::new(
+                        TokenType::String(string_value),
+                        start_location,
+                        lexeme,
+                    ));
+                }
+                Some(ch) => {
+                    string_value.push(ch);
+                    lexeme.push(ch);
+                    self.advance();
+                }
+            }
+        }
+    }
+
+    /// Whether the `"` at the current position is immediately followed by
+    /// `hash_count` `#` characters, i.e. is the closing delimiter of a raw
+    /// string opened with that many hashes.
+    fn raw_string_closes_here(&self, hash_count: usize) -> bool {
+        (0..hash_count).all(|i| self.input.get(self.position + 1 + i).copied() == Some('#'))
+    }
+
+    /// Read a `\xNN` escape, assuming `\x` has already been consumed.
+    /// Returns the parsed byte value (0x00..=0x7F).
+    fn read_hex_escape(&mut self, lexeme: &mut String) -> Result<u32, LexerError> {
+        let mut digits = String::new();
+
+        for _ in 0..2 {
+            match self.current_char {
+                Some(ch) if ch.is_ascii_hexdigit() => {
+                    digits.push(ch);
+                    lexeme.push(ch);
+                    self.advance();
+                }
+                Some(ch) => {
+                    return Err(LexerError::InvalidCharInHexEscape {
+                        character: ch,
+                        location: self.current_location(),
+                    });
+                }
+                None => {
+                    return Err(LexerError::TooShortHexEscape {
+                        location: self.current_location(),
+                    });
+                }
+            }
+        }
+
+        let value = u32::from_str_radix(&digits, 16).expect("validated hex digits");
+        if value > 0x7F {
+            return Err(LexerError::OutOfRangeHexEscape {
+                value,
+                location: self.current_location(),
+            });
+        }
+
+        Ok(value)
+    }
+
+    /// Read a `\u{...}` escape, assuming `\u` has already been consumed.
+    fn read_unicode_escape(&mut self, lexeme: &mut String) -> Result<char, LexerError> {
+        let location = self.current_location();
+
+        match self.current_char {
+            Some('{') => {
+                lexeme.push('{');
+                self.advance();
+            }
+            _ => {
+                return Err(LexerError::UnclosedUnicodeEscape { location });
+            }
+        }
+
+        let mut digits = String::new();
+        loop {
+            match self.current_char {
+                Some('}') => break,
+                Some(ch) if ch.is_ascii_hexdigit() => {
+                    if digits.len() == 6 {
+                        return Err(LexerError::InvalidCharInUnicodeEscape {
+                            character: ch,
                             location: self.current_location(),
                         });
                     }
-                    None => {
-                        return Err(LexerError::UnterminatedString {
-                            location: start_location,
-                        });
-                    }
+                    digits.push(ch);
+                    lexeme.push(ch);
+                    self.advance();
+                }
+                Some(ch) => {
+                    return Err(LexerError::InvalidCharInUnicodeEscape {
+                        character: ch,
+                        location: self.current_location(),
+                    });
+                }
+                None => {
+                    return Err(LexerError::UnclosedUnicodeEscape { location });
                 }
-                self.advance();
-            } else if ch == '\n' || ch == '\r' {
-                return Err(LexerError::UnterminatedString {
-                    location: start_location,
-                });
-            } else {
-                string_value.push(ch);
-                self.advance();
             }
         }
 
-        Err(LexerError::UnterminatedString {
-            location: start_location,
-        })
+        if digits.is_empty() {
+            return Err(LexerError::EmptyUnicodeEscape { location });
+        }
+
+        // Consume closing '}'
+        lexeme.push('}');
+        self.advance();
+
+        let value = u32::from_str_radix(&digits, 16).expect("validated hex digits");
+        match char::from_u32(value) {
+            Some(ch) => Ok(ch),
+            None => Err(LexerError::OutOfRangeUnicodeEscape { value, location }),
+        }
     }
 
     /// Read an identifier or keyword
@@ -420,6 +985,11 @@ impl Lexer {
                 return Err(LexerError::UnterminatedString {
                     location: start_location,
                 });
+            } else if is_text_flow_control_char(ch) {
+                return Err(LexerError::TextFlowControlChar {
+                    code_point: ch as u32,
+                    location: self.current_location(),
+                });
             } else {
                 identifier.push(ch);
                 self.advance();
@@ -509,6 +1079,11 @@ impl Lexer {
                 return Err(LexerError::UnterminatedString {
                     location: start_location,
                 });
+            } else if is_text_flow_control_char(ch) {
+                return Err(LexerError::TextFlowControlChar {
+                    code_point: ch as u32,
+                    location: self.current_location(),
+                });
             } else {
                 content.push(ch);
                 lexeme.push(ch);
@@ -522,7 +1097,7 @@ impl Lexer {
     }
 
     /// Read a comment
-    fn read_comment(&mut self) -> Token {
+    fn read_comment(&mut self) -> Result<Token, LexerError> {
         let start_location = self.current_location();
         let mut comment = String::new();
 
@@ -533,22 +1108,59 @@ impl Lexer {
             if ch == '\n' || ch == '\r' {
                 break;
             }
+            if is_text_flow_control_char(ch) {
+                return Err(LexerError::TextFlowControlChar {
+                    code_point: ch as u32,
+                    location: self.current_location(),
+                });
+            }
             comment.push(ch);
             self.advance();
         }
 
-        Token::new(
+        Ok(Token::new(
             TokenType::Comment(comment.trim().to_string()),
             start_location,
             format!(";{}", comment),
-        )
+        ))
     }
 
     /// Get the next token from the input
     pub fn next_token(&mut self) -> Result<Token, LexerError> {
+        // A `${` fragment boundary queues an `InterpolationStart` token to
+        // be returned on the very next call, after the `StringFragment`
+        // leading up to it.
+        if let Some(token) = self.pending_token.take() {
+            return Ok(token);
+        }
+
+        // `InString` bypasses the normal dispatch below entirely: once a
+        // string has been opened, every subsequent call resumes scanning
+        // its next fragment until the closing `"` pops back out.
+        if self.current_mode() == LexerMode::InString {
+            return self.scan_string_fragment();
+        }
+
         loop {
+            if self.current_mode() == LexerMode::InInterpolation && self.current_char == Some('}') {
+                let location = self.current_location();
+                self.advance();
+                self.pop_state();
+                return Ok(Token::new(TokenType::InterpolationEnd, location, "}".to_string()));
+            }
+
             match self.current_char {
                 None => {
+                    if self.mode_stack.len() > 1 {
+                        // Hit EOF with an interpolation still open (e.g.
+                        // `"a${b`); report it the same way an unterminated
+                        // string is reported rather than silently emitting
+                        // Eof and leaving the mode stack unwound by
+                        // `tokenize`.
+                        return Err(LexerError::UnterminatedString {
+                            location: self.current_location(),
+                        });
+                    }
                     return Ok(Token::new(
                         TokenType::Eof,
                         self.current_location(),
@@ -566,13 +1178,25 @@ impl Lexer {
                     return Ok(Token::new(TokenType::RightParen, location, ")".to_string()));
                 }
                 Some(';') => {
-                    return Ok(self.read_comment());
+                    self.state = LexerState::InComment;
+                    let token = self.read_comment();
+                    self.state = LexerState::StartLine;
+                    return token;
                 }
                 Some('"') => {
-                    return self.read_string();
+                    return self.begin_string();
                 }
                 Some('\'') => {
-                    return self.read_quoted_content();
+                    self.state = LexerState::InQuotedIdentifier;
+                    let token = self.read_quoted_content();
+                    self.state = LexerState::StartLine;
+                    return token;
+                }
+                Some('r') if matches!(self.peek(), Some('"') | Some('#')) => {
+                    self.state = LexerState::InString;
+                    let token = self.read_raw_string();
+                    self.state = LexerState::StartLine;
+                    return token;
                 }
                 Some(ch) if ch.is_whitespace() => {
                     self.skip_whitespace();
@@ -582,7 +1206,10 @@ impl Lexer {
                     return self.read_number();
                 }
                 Some(ch) if ch.is_ascii_alphabetic() || ch == '_' => {
-                    return Ok(self.read_identifier());
+                    self.state = LexerState::InIdentifier;
+                    let token = self.read_identifier();
+                    self.state = LexerState::StartLine;
+                    return Ok(token);
                 }
                 Some('^') => {
                     let location = self.current_location();
@@ -610,21 +1237,124 @@ impl Lexer {
         }
     }
 
-    /// Tokenize the entire input and return a vector of tokens
+    /// Tokenize the entire input and return a vector of tokens.
+    ///
+    /// Whether this succeeds or fails partway through (e.g. on an
+    /// unterminated string or interpolation), the mode stack is always left
+    /// unwound back to `[Normal]` afterward - see
+    /// [`Lexer::mode_stack_depth`].
     pub fn tokenize(&mut self) -> Result<Vec<Token>, LexerError> {
         let mut tokens = Vec::new();
 
+        let result = (|| -> Result<(), LexerError> {
+            loop {
+                let token = self.next_token()?;
+                let is_eof = matches!(token.token_type, TokenType::Eof);
+                tokens.push(token);
+
+                if is_eof {
+                    break;
+                }
+            }
+            Ok(())
+        })();
+
+        self.mode_stack.truncate(1);
+        self.string_frames.clear();
+        self.pending_token = None;
+
+        result.map(|()| tokens)
+    }
+
+    /// Tokenize the entire input, recovering from errors instead of bailing
+    /// on the first one. Useful for editor/LSP tooling that wants all the
+    /// diagnostics in a file rather than just the first.
+    ///
+    /// On error, a synthetic [`TokenType::Error`] token covering the bad
+    /// region is emitted, the error (with its full span) is recorded, and
+    /// the lexer resynchronizes at the next whitespace/paren/newline
+    /// boundary before continuing.
+    pub fn tokenize_recovering(&mut self) -> (Vec<Token>, Vec<crate::error::LexerDiagnostic>) {
+        let mut tokens = Vec::new();
+        let mut errors = Vec::new();
+
         loop {
-            let token = self.next_token()?;
-            let is_eof = matches!(token.token_type, TokenType::Eof);
-            tokens.push(token);
+            let start_location = self.current_location();
 
-            if is_eof {
-                break;
+            match self.next_token() {
+                Ok(token) => {
+                    let is_eof = matches!(token.token_type, TokenType::Eof);
+                    tokens.push(token);
+
+                    if is_eof {
+                        break;
+                    }
+                }
+                Err(error) => {
+                    // Drop back to `Normal` mode so a string/interpolation
+                    // left open by the error doesn't wedge every subsequent
+                    // call into `scan_string_fragment`.
+                    self.mode_stack.truncate(1);
+                    self.string_frames.clear();
+                    self.pending_token = None;
+
+                    // Resynchronize: skip past the bad region until we hit a
+                    // whitespace/paren/newline boundary (or the end of input).
+                    if self.position == 0 || self.current_char.is_some() {
+                        self.advance();
+                    }
+                    while let Some(ch) = self.current_char {
+                        if ch.is_whitespace() || ch == '(' || ch == ')' {
+                            break;
+                        }
+                        self.advance();
+                    }
+
+                    let end_location = self.current_location();
+                    let lexeme: String = self.input[start_location.offset..end_location.offset]
+                        .iter()
+                        .collect();
+                    let span = crate::error::SourceSpan::new(start_location, end_location);
+
+                    tokens.push(Token::new(
+                        TokenType::Error(error.to_string()),
+                        span.start.clone(),
+                        lexeme,
+                    ));
+                    errors.push(crate::error::LexerDiagnostic::new(error, span));
+                }
             }
         }
 
-        Ok(tokens)
+        (tokens, errors)
+    }
+
+    /// Try to produce the next token, but don't treat running out of buffer
+    /// mid-token as an error: returns `Ok(None)` so a streaming caller can
+    /// [`feed`](Lexer::feed) more input and retry, rather than failing with
+    /// an `UnterminatedString`. Other errors (e.g. a real newline inside a
+    /// string, or an unexpected character) are reported immediately, since
+    /// more input would not resolve them.
+    pub fn next_token_resumable(&mut self) -> Result<Option<Token>, LexerError> {
+        let saved_position = self.position;
+        let saved_current_char = self.current_char;
+        let saved_line = self.line;
+        let saved_column = self.column;
+        let saved_state = self.state;
+
+        match self.next_token() {
+            Ok(token) => Ok(Some(token)),
+            Err(LexerError::UnterminatedString { .. }) if self.position >= self.input.len() => {
+                // Ran out of buffer, not out of valid input: rewind and wait.
+                self.position = saved_position;
+                self.current_char = saved_current_char;
+                self.line = saved_line;
+                self.column = saved_column;
+                self.state = saved_state;
+                Ok(None)
+            }
+            Err(other) => Err(other),
+        }
     }
 
     /// Peek at the next token without consuming it
@@ -712,14 +1442,114 @@ mod tests {
         assert!(matches!(lexer.tokenize(), Err(LexerError::UnterminatedString { .. })));
 
         // Invalid escape sequence
-        let mut lexer = Lexer::new(r#""\x""#, "test.aether".to_string());
+        let mut lexer = Lexer::new(r#""\q""#, "test.aether".to_string());
         assert!(matches!(lexer.tokenize(), Err(LexerError::InvalidEscapeSequence { .. })));
 
+        // \x escape that is too short
+        let mut lexer = Lexer::new(r#""\x""#, "test.aether".to_string());
+        assert!(matches!(lexer.tokenize(), Err(LexerError::TooShortHexEscape { .. })));
+
         // Unexpected character
         let mut lexer = Lexer::new("@", "test.aether".to_string());
         assert!(matches!(lexer.tokenize(), Err(LexerError::UnexpectedCharacter { .. })));
     }
 
+    #[test]
+    fn test_hex_and_unicode_escapes() {
+        let mut lexer = Lexer::new(r#""\x41\x42" "\u{48}\u{65}\u{6C}\u{6C}\u{6F}""#, "test.aether".to_string());
+        let tokens = lexer.tokenize().unwrap();
+
+        assert!(matches!(tokens[0].token_type, TokenType::String(ref s) if s == "AB"));
+        assert!(matches!(tokens[1].token_type, TokenType::String(ref s) if s == "Hello"));
+
+        let mut lexer = Lexer::new(r#""\xFF""#, "test.aether".to_string());
+        assert!(matches!(lexer.tokenize(), Err(LexerError::OutOfRangeHexEscape { .. })));
+
+        let mut lexer = Lexer::new(r#""\xG1""#, "test.aether".to_string());
+        assert!(matches!(lexer.tokenize(), Err(LexerError::InvalidCharInHexEscape { .. })));
+
+        let mut lexer = Lexer::new(r#""\u{}""#, "test.aether".to_string());
+        assert!(matches!(lexer.tokenize(), Err(LexerError::EmptyUnicodeEscape { .. })));
+
+        let mut lexer = Lexer::new(r#""\u{41""#, "test.aether".to_string());
+        assert!(matches!(lexer.tokenize(), Err(LexerError::UnclosedUnicodeEscape { .. })));
+
+        let mut lexer = Lexer::new(r#""\u{D800}""#, "test.aether".to_string());
+        assert!(matches!(lexer.tokenize(), Err(LexerError::OutOfRangeUnicodeEscape { .. })));
+
+        let mut lexer = Lexer::new(r#""\u{110000}""#, "test.aether".to_string());
+        assert!(matches!(lexer.tokenize(), Err(LexerError::OutOfRangeUnicodeEscape { .. })));
+    }
+
+    #[test]
+    fn test_raw_strings() {
+        let mut lexer = Lexer::new(r#"r"C:\path\no\escapes""#, "test.aether".to_string());
+        let tokens = lexer.tokenize().unwrap();
+        assert!(matches!(tokens[0].token_type, TokenType::String(ref s) if s == r"C:\path\no\escapes"));
+
+        let mut lexer = Lexer::new(r##"r#"embedded "quotes" work"#"##, "test.aether".to_string());
+        let tokens = lexer.tokenize().unwrap();
+        assert!(matches!(tokens[0].token_type, TokenType::String(ref s) if s == r#"embedded "quotes" work"#));
+
+        let mut lexer = Lexer::new("r\"line one\nline two\" x", "test.aether".to_string());
+        let tokens = lexer.tokenize().unwrap();
+        assert!(matches!(tokens[0].token_type, TokenType::String(ref s) if s == "line one\nline two"));
+        assert_eq!(tokens[1].location.line, 2);
+
+        let mut lexer = Lexer::new(r##"r#"unterminated"##, "test.aether".to_string());
+        assert!(matches!(
+            lexer.tokenize(),
+            Err(LexerError::UnterminatedRawString { hash_count: 1, .. })
+        ));
+    }
+
+    #[test]
+    fn test_text_flow_control_chars_rejected() {
+        let mut lexer = Lexer::new("\"hi\u{202E}there\"", "test.aether".to_string());
+        assert!(matches!(
+            lexer.tokenize(),
+            Err(LexerError::TextFlowControlChar { code_point: 0x202E, .. })
+        ));
+
+        let mut lexer = Lexer::new("; comment \u{2066} trick", "test.aether".to_string());
+        assert!(matches!(
+            lexer.tokenize(),
+            Err(LexerError::TextFlowControlChar { code_point: 0x2066, .. })
+        ));
+    }
+
+    #[test]
+    fn test_feed_resumes_mid_token() {
+        let mut lexer = Lexer::new(r#""hel"#, "test.aether".to_string());
+
+        // Not enough input yet to close the string.
+        assert!(lexer.next_token_resumable().unwrap().is_none());
+
+        lexer.feed("lo\" (");
+
+        let token = lexer.next_token_resumable().unwrap().unwrap();
+        assert!(matches!(token.token_type, TokenType::String(ref s) if s == "hello"));
+
+        let token = lexer.next_token_resumable().unwrap().unwrap();
+        assert!(matches!(token.token_type, TokenType::LeftParen));
+    }
+
+    #[test]
+    fn test_tokenize_recovering_collects_multiple_errors() {
+        let mut lexer = Lexer::new("@ ( @ )", "test.aether".to_string());
+        let (tokens, errors) = lexer.tokenize_recovering();
+
+        assert_eq!(errors.len(), 2);
+        assert!(matches!(errors[0].error, LexerError::UnexpectedCharacter { .. }));
+        assert!(matches!(errors[1].error, LexerError::UnexpectedCharacter { .. }));
+
+        assert!(matches!(tokens[0].token_type, TokenType::Error(_)));
+        assert!(matches!(tokens[1].token_type, TokenType::LeftParen));
+        assert!(matches!(tokens[2].token_type, TokenType::Error(_)));
+        assert!(matches!(tokens[3].token_type, TokenType::RightParen));
+        assert!(matches!(tokens.last().unwrap().token_type, TokenType::Eof));
+    }
+
     #[test]
     fn test_peek_token() {
         let mut lexer = Lexer::new("(", "test.aether".to_string());
@@ -731,6 +1561,103 @@ mod tests {
         assert!(matches!(actual.token_type, TokenType::LeftParen));
     }
 
+    #[test]
+    fn test_radix_integer_literals() {
+        let mut lexer = Lexer::new("0xFF 0b1010 0o17 -0x10", "test.aether".to_string());
+        let tokens = lexer.tokenize().unwrap();
+
+        assert!(matches!(tokens[0].token_type, TokenType::Integer(255)));
+        assert!(matches!(tokens[1].token_type, TokenType::Integer(10)));
+        assert!(matches!(tokens[2].token_type, TokenType::Integer(15)));
+        assert!(matches!(tokens[3].token_type, TokenType::Integer(-16)));
+
+        // An empty digit group after the radix prefix is an error.
+        let mut lexer = Lexer::new("0x", "test.aether".to_string());
+        assert!(matches!(lexer.tokenize(), Err(LexerError::InvalidNumber { .. })));
+    }
+
+    #[test]
+    fn test_digit_separators() {
+        let mut lexer = Lexer::new("1_000_000 0x1_FF 3.14_159", "test.aether".to_string());
+        let tokens = lexer.tokenize().unwrap();
+
+        assert!(matches!(tokens[0].token_type, TokenType::Integer(1_000_000)));
+        assert!(matches!(tokens[1].token_type, TokenType::Integer(0x1FF)));
+        assert!(matches!(tokens[2].token_type, TokenType::Float(f) if (f - 3.14159).abs() < f64::EPSILON));
+    }
+
+    #[test]
+    fn test_float_rational_parts() {
+        let mut lexer = Lexer::new("1.5e10", "test.aether".to_string());
+        let tokens = lexer.tokenize().unwrap();
+
+        let rational = tokens[0].rational.as_ref().expect("float carries rational parts");
+        assert_eq!(rational.mantissa, "1");
+        assert_eq!(rational.fraction, "5");
+        assert_eq!(rational.exponent, 10);
+    }
+
+    #[test]
+    fn test_plain_string_unaffected_by_interpolation_support() {
+        // No `${` anywhere, so this must still lex as a single `String`
+        // token exactly as before this feature existed.
+        let mut lexer = Lexer::new(r#""hello world""#, "test.aether".to_string());
+        let tokens = lexer.tokenize().unwrap();
+
+        assert!(matches!(tokens[0].token_type, TokenType::String(ref s) if s == "hello world"));
+        assert_eq!(lexer.mode_stack_depth(), 1);
+    }
+
+    #[test]
+    fn test_string_interpolation_tokens() {
+        let mut lexer = Lexer::new(r#""a${ x }b""#, "test.aether".to_string());
+        let tokens = lexer.tokenize().unwrap();
+
+        assert!(matches!(tokens[0].token_type, TokenType::StringFragment(ref s) if s == "a"));
+        assert!(matches!(tokens[1].token_type, TokenType::InterpolationStart));
+        assert!(matches!(tokens[2].token_type, TokenType::Identifier(ref i) if i == "x"));
+        assert!(matches!(tokens[3].token_type, TokenType::InterpolationEnd));
+        assert!(matches!(tokens[4].token_type, TokenType::StringFragment(ref s) if s == "b"));
+        assert!(matches!(tokens[5].token_type, TokenType::Eof));
+        assert_eq!(lexer.mode_stack_depth(), 1);
+    }
+
+    #[test]
+    fn test_string_interpolation_with_empty_fragments() {
+        // `${x}` with nothing before or after still brackets the
+        // interpolation with (possibly empty) `StringFragment`s.
+        let mut lexer = Lexer::new(r#""${x}""#, "test.aether".to_string());
+        let tokens = lexer.tokenize().unwrap();
+
+        assert!(matches!(tokens[0].token_type, TokenType::StringFragment(ref s) if s.is_empty()));
+        assert!(matches!(tokens[1].token_type, TokenType::InterpolationStart));
+        assert!(matches!(tokens[2].token_type, TokenType::Identifier(ref i) if i == "x"));
+        assert!(matches!(tokens[3].token_type, TokenType::InterpolationEnd));
+        assert!(matches!(tokens[4].token_type, TokenType::StringFragment(ref s) if s.is_empty()));
+    }
+
+    #[test]
+    fn test_escaped_dollar_does_not_open_interpolation() {
+        let mut lexer = Lexer::new(r#""price: \$5""#, "test.aether".to_string());
+        let tokens = lexer.tokenize().unwrap();
+
+        assert!(matches!(tokens[0].token_type, TokenType::String(ref s) if s == "price: $5"));
+    }
+
+    #[test]
+    fn test_unterminated_interpolation_unwinds_mode_stack() {
+        let mut lexer = Lexer::new(r#""a${ b"#, "test.aether".to_string());
+        assert!(matches!(lexer.tokenize(), Err(LexerError::UnterminatedString { .. })));
+        assert_eq!(lexer.mode_stack_depth(), 1);
+    }
+
+    #[test]
+    fn test_unterminated_string_after_interpolation_unwinds_mode_stack() {
+        let mut lexer = Lexer::new(r#""a${ b }c"#, "test.aether".to_string());
+        assert!(matches!(lexer.tokenize(), Err(LexerError::UnterminatedString { .. })));
+        assert_eq!(lexer.mode_stack_depth(), 1);
+    }
+
     #[test]
     fn test_quoted_identifiers() {
         let mut lexer = Lexer::new("'hello_world' 'DEFINE_FUNCTION'", "test.aether".to_string());
@@ -25,8 +25,10 @@ pub mod debug;
 pub mod docs;
 pub mod error;
 pub mod ffi;
+pub mod fmt;
 pub mod lexer;
 pub mod llvm_backend;
+pub mod loader;
 pub mod memory;
 pub mod mir;
 pub mod module_loader;
@@ -42,6 +44,7 @@ pub mod runtime;
 pub mod semantic;
 pub mod stdlib;
 pub mod symbols;
+pub mod test_harness;
 pub mod types;
 pub mod utils;
 pub mod verification;
@@ -130,7 +133,26 @@ impl Compiler {
         self.options.parallel = enable;
         self
     }
-    
+
+    /// Enable or disable the function inlining pass
+    pub fn inlining(mut self, enable: bool) -> Self {
+        self.options.enable_inlining = enable;
+        self
+    }
+
+    /// Set the maximum cost a function may have to be considered for inlining
+    pub fn inline_threshold(mut self, threshold: usize) -> Self {
+        self.options.inline_threshold = threshold;
+        self
+    }
+
+    /// Set the maximum inlining depth option. The inlining pass ignores it;
+    /// see [`InliningPass::set_max_inline_depth`](crate::optimizations::inlining::InliningPass::set_max_inline_depth).
+    pub fn inline_depth(mut self, depth: usize) -> Self {
+        self.options.inline_depth = depth;
+        self
+    }
+
     /// Compile a single source file
     pub fn compile_file(&self, input: PathBuf) -> Result<CompilationResult, CompilerError> {
         self.compile_files(&[input])
@@ -141,6 +163,13 @@ impl Compiler {
         let mut pipeline = CompilationPipeline::new(self.options.clone());
         pipeline.compile_files(inputs)
     }
+
+    /// Compile `input` and execute its `main` in-process via an LLVM JIT,
+    /// returning its exit code. See [`CompilationPipeline::jit_run`].
+    pub fn jit_run(&self, input: PathBuf, args: Vec<String>) -> Result<i32, CompilerError> {
+        let mut pipeline = CompilationPipeline::new(self.options.clone());
+        pipeline.jit_run(&input, &args)
+    }
 }
 
 impl Default for Compiler {
@@ -136,9 +136,25 @@ impl<'ctx> LLVMBackend<'ctx> {
                 // Pointers are represented as i8*
                 self.context.i8_type().ptr_type(AddressSpace::default()).into()
             },
+            crate::types::Type::Vector { element_type, lanes } => {
+                self.get_basic_type(element_type).vec_type(*lanes as u32).into()
+            },
             _ => self.context.i32_type().into(), // Default for complex types
         }
     }
+
+    /// Byte size of a scalar element type, for alignment calculations.
+    /// Mirrors the width choices in [`Self::get_basic_type`]'s primitive arm.
+    fn scalar_byte_size(ty: &crate::types::Type) -> u32 {
+        match ty {
+            crate::types::Type::Primitive(prim) => match prim {
+                crate::ast::PrimitiveType::Float | crate::ast::PrimitiveType::String => 8,
+                crate::ast::PrimitiveType::Char => 1,
+                _ => 4,
+            },
+            _ => 8,
+        }
+    }
     
     /// Get the basic type from a local ID
     fn get_basic_type_from_local(&self, local_id: mir::LocalId, function: &mir::Function) -> Result<inkwell::types::BasicTypeEnum<'ctx>, SemanticError> {
@@ -489,6 +505,18 @@ impl<'ctx> LLVMBackend<'ctx> {
                 let local_type = self.get_basic_type(&local.ty);
                 let alloca = builder.build_alloca(local_type, &format!("local_{}", local_id))
                     .map_err(|e| SemanticError::CodeGenError { message: e.to_string() })?;
+
+                // SIMD-targeted locals are over-aligned to their full vector
+                // width rather than just the element size, so the
+                // vectorization pass can prove vector loads/stores from them
+                // are naturally aligned without a runtime check.
+                if let crate::types::Type::Vector { element_type, lanes } = &local.ty {
+                    let alignment = Self::scalar_byte_size(element_type) * (*lanes as u32);
+                    if let Some(instruction) = alloca.as_instruction_value() {
+                        let _ = instruction.set_alignment(alignment);
+                    }
+                }
+
                 local_allocas.insert(local_id, alloca);
                 
                 // Track if this local has ownership and needs cleanup
@@ -1821,6 +1849,11 @@ impl<'ctx> LLVMBackend<'ctx> {
                             message: "Null constants not yet implemented".to_string()
                         })
                     }
+                    mir::ConstantValue::Array(_) | mir::ConstantValue::Tuple(_) => {
+                        Err(SemanticError::CodeGenError {
+                            message: "Constant aggregate codegen not yet implemented".to_string()
+                        })
+                    }
                 }
             }
         }
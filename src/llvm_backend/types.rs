@@ -153,6 +153,12 @@ impl<'ctx> TypeConverter<'ctx> {
                 // The ownership is tracked at compile time, not runtime
                 self.convert_type(base_type)
             }
+
+            Type::Vector { element_type, lanes } => {
+                let element_llvm_type = self.convert_type(element_type)?;
+                let vector_type = element_llvm_type.vec_type(*lanes as u32);
+                Ok(BasicTypeEnum::VectorType(vector_type))
+            }
         }
     }
     
@@ -194,10 +200,16 @@ impl<'ctx> TypeConverter<'ctx> {
                 // Unsigned pointer-sized integer
                 #[cfg(target_pointer_width = "64")]
                 return Ok(BasicTypeEnum::IntType(self.context.i64_type()));
-                
+
                 #[cfg(target_pointer_width = "32")]
                 return Ok(BasicTypeEnum::IntType(self.context.i32_type()));
             }
+            PrimitiveType::I8 => BasicTypeEnum::IntType(self.context.i8_type()),
+            PrimitiveType::U8 => BasicTypeEnum::IntType(self.context.i8_type()),
+            PrimitiveType::I16 => BasicTypeEnum::IntType(self.context.i16_type()),
+            PrimitiveType::U16 => BasicTypeEnum::IntType(self.context.i16_type()),
+            PrimitiveType::U32 => BasicTypeEnum::IntType(self.context.i32_type()),
+            PrimitiveType::U64 => BasicTypeEnum::IntType(self.context.i64_type()),
         };
         
         Ok(llvm_type)
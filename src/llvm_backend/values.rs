@@ -53,6 +53,12 @@ impl<'ctx> ValueConverter<'ctx> {
                 let null_ptr = self.context.i8_type().ptr_type(AddressSpace::default()).const_null();
                 BasicValueEnum::PointerValue(null_ptr)
             }
+
+            ConstantValue::Array(_) | ConstantValue::Tuple(_) => {
+                return Err(SemanticError::CodeGenError {
+                    message: "Constant aggregate codegen not yet implemented".to_string(),
+                });
+            }
         };
         
         Ok(llvm_value)
@@ -0,0 +1,302 @@
+// Copyright 2025 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Source file loader and cross-file import graph resolution
+//!
+//! Owns every source file involved in a compilation - the files named on
+//! the command line plus anything they transitively `import` - in a single
+//! arena keyed by [`FileId`], so diagnostics can borrow source text for the
+//! whole program rather than one file at a time. This sits below
+//! [`crate::module_loader::ModuleLoader`], which resolves imports by module
+//! name for semantic analysis; the `Loader` here instead works in terms of
+//! canonical file paths and is responsible for discovering the complete set
+//! of files a compilation touches and rejecting import cycles up front.
+
+use crate::error::CompilerError;
+use crate::lexer::Lexer;
+use crate::parser::Parser;
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+
+/// Identifies a loaded source file. Stable for the lifetime of the `Loader`
+/// that produced it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct FileId(u32);
+
+/// Distinguishes a file named directly on the command line from one pulled
+/// in transitively through an `import`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FileKind {
+    /// Named directly as a compiler input.
+    TopLevel,
+    /// Reached by resolving an `import` statement in another file.
+    Imported,
+}
+
+struct LoadedFile {
+    path: PathBuf,
+    source: String,
+    kind: FileKind,
+}
+
+/// Loads source files and resolves the `import` graph between them.
+///
+/// Every file is loaded at most once: `resolve` and `load_entry` key their
+/// cache by canonical path, so two different import statements that name
+/// the same file (or an import that happens to also be a CLI input) collapse
+/// to a single [`FileId`].
+pub struct Loader {
+    files: Vec<LoadedFile>,
+    by_path: HashMap<PathBuf, FileId>,
+}
+
+impl Loader {
+    pub fn new() -> Self {
+        Self { files: Vec::new(), by_path: HashMap::new() }
+    }
+
+    /// Load `path` as a top-level (CLI-provided) entry file.
+    pub fn load_entry(&mut self, path: &Path) -> Result<FileId, CompilerError> {
+        self.load(path, FileKind::TopLevel)
+    }
+
+    /// Resolve an import named `name` as referenced from `requester`,
+    /// loading and caching the file if it hasn't been seen yet. `kind`
+    /// records how the caller is using this file (see [`FileKind`]).
+    pub fn resolve(&mut self, requester: &Path, name: &str, kind: FileKind) -> Result<FileId, CompilerError> {
+        let candidate = Self::candidate_path(requester, name);
+        self.load(&candidate, kind)
+    }
+
+    /// The on-disk location an import name resolves to, relative to the
+    /// file that imported it: `foo.bar` next to `requester` becomes
+    /// `<requester's dir>/foo/bar.aether`.
+    fn candidate_path(requester: &Path, name: &str) -> PathBuf {
+        let dir = requester.parent().unwrap_or_else(|| Path::new("."));
+        dir.join(format!("{}.aether", name.replace('.', "/")))
+    }
+
+    fn load(&mut self, path: &Path, kind: FileKind) -> Result<FileId, CompilerError> {
+        let canonical = path.canonicalize().map_err(|e| CompilerError::IoError {
+            message: format!("Failed to resolve {}: {}", path.display(), e),
+        })?;
+
+        if let Some(&id) = self.by_path.get(&canonical) {
+            return Ok(id);
+        }
+
+        let source = std::fs::read_to_string(&canonical).map_err(|e| CompilerError::IoError {
+            message: format!("Failed to read {}: {}", canonical.display(), e),
+        })?;
+
+        let id = FileId(self.files.len() as u32);
+        self.files.push(LoadedFile { path: canonical.clone(), source, kind });
+        self.by_path.insert(canonical, id);
+        Ok(id)
+    }
+
+    /// Load `entries` and every file they transitively `import`, detecting
+    /// cycles with a DFS "currently-visiting" stack: a back-edge to a file
+    /// already on that stack is reported as an [`CompilerError::ImportCycle`]
+    /// carrying the full chain from the cycle's start back to itself.
+    /// Returns the entries' own `FileId`s, in the order given.
+    pub fn load_program(&mut self, entries: &[PathBuf]) -> Result<Vec<FileId>, CompilerError> {
+        let mut entry_ids = Vec::with_capacity(entries.len());
+        for entry in entries {
+            entry_ids.push(self.load_entry(entry)?);
+        }
+
+        let mut visiting = Vec::new();
+        let mut visited = HashSet::new();
+        for &id in &entry_ids {
+            self.visit(id, &mut visiting, &mut visited)?;
+        }
+
+        Ok(entry_ids)
+    }
+
+    fn visit(&mut self, id: FileId, visiting: &mut Vec<FileId>, visited: &mut HashSet<FileId>) -> Result<(), CompilerError> {
+        if visited.contains(&id) {
+            return Ok(());
+        }
+        if let Some(start) = visiting.iter().position(|&visiting_id| visiting_id == id) {
+            let mut chain: Vec<String> =
+                visiting[start..].iter().map(|&f| self.path(f).display().to_string()).collect();
+            chain.push(self.path(id).display().to_string());
+            return Err(CompilerError::ImportCycle { chain: chain.join(" -> ") });
+        }
+
+        visiting.push(id);
+
+        let requester_path = self.path(id).to_path_buf();
+        for import_name in self.parse_import_names(id)? {
+            let dependency = self.resolve(&requester_path, &import_name, FileKind::Imported)?;
+            self.visit(dependency, visiting, visited)?;
+        }
+
+        visiting.pop();
+        visited.insert(id);
+        Ok(())
+    }
+
+    /// The module names named in `id`'s `import` statements, in source order.
+    fn parse_import_names(&self, id: FileId) -> Result<Vec<String>, CompilerError> {
+        let source = self.source(id);
+        let mut lexer = Lexer::new(source, self.path(id).to_string_lossy().to_string());
+        let tokens = lexer.tokenize()?;
+        let mut parser = Parser::new(tokens);
+        let module = parser.parse_module()?;
+        Ok(module.imports.iter().map(|import| import.module_name.name.clone()).collect())
+    }
+
+    /// The source text for a previously loaded file.
+    pub fn source(&self, id: FileId) -> &str {
+        &self.files[id.0 as usize].source
+    }
+
+    /// The canonical path a file was loaded from.
+    pub fn path(&self, id: FileId) -> &Path {
+        &self.files[id.0 as usize].path
+    }
+
+    /// How `id` was reached: a CLI input, or an import of one.
+    pub fn kind(&self, id: FileId) -> FileKind {
+        self.files[id.0 as usize].kind
+    }
+
+    /// How many files this loader has resolved so far.
+    pub fn len(&self) -> usize {
+        self.files.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.files.is_empty()
+    }
+
+    /// Every `FileId` resolved so far, in load order (entries first, then
+    /// their imports in DFS order).
+    pub fn ids(&self) -> Vec<FileId> {
+        (0..self.files.len() as u32).map(FileId).collect()
+    }
+
+    /// A stable `FileId -> source text` map for every file loaded so far,
+    /// so diagnostics can borrow source for the whole program at once
+    /// instead of re-reading one file at a time.
+    pub fn sources(&self) -> HashMap<FileId, &str> {
+        self.files.iter().enumerate().map(|(i, f)| (FileId(i as u32), f.source.as_str())).collect()
+    }
+}
+
+impl Default for Loader {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    fn write_file(dir: &Path, name: &str, contents: &str) -> PathBuf {
+        let path = dir.join(name);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).unwrap();
+        }
+        fs::write(&path, contents).unwrap();
+        path
+    }
+
+    const EMPTY_MODULE: &str = "(DEFINE_MODULE (NAME main) (CONTENT))";
+
+    fn importing_module(name: &str, imported: &str) -> String {
+        format!("(DEFINE_MODULE (NAME {name}) (CONTENT (IMPORT_MODULE (NAME {imported}))))")
+    }
+
+    #[test]
+    fn test_load_entry_deduplicates_by_canonical_path() {
+        let dir = std::env::temp_dir().join(format!("aether_loader_test_{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let entry = write_file(&dir, "main.aether", EMPTY_MODULE);
+
+        let mut loader = Loader::new();
+        let first = loader.load_entry(&entry).unwrap();
+        let second = loader.load_entry(&entry).unwrap();
+
+        assert_eq!(first, second);
+        assert_eq!(loader.len(), 1);
+
+        fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn test_load_program_discovers_transitive_imports() {
+        let dir = std::env::temp_dir().join(format!("aether_loader_test_imports_{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+
+        write_file(&dir, "util.aether", EMPTY_MODULE);
+        let entry = write_file(&dir, "main.aether", &importing_module("main", "util"));
+
+        let mut loader = Loader::new();
+        let entries = loader.load_program(&[entry]).unwrap();
+
+        assert_eq!(entries.len(), 1);
+        assert_eq!(loader.len(), 2, "main.aether's import of util.aether should be discovered and loaded");
+        assert_eq!(loader.kind(entries[0]), FileKind::TopLevel);
+
+        fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn test_load_program_detects_import_cycle() {
+        let dir = std::env::temp_dir().join(format!("aether_loader_test_cycle_{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+
+        write_file(&dir, "b.aether", &importing_module("b", "a"));
+        let entry = write_file(&dir, "a.aether", &importing_module("a", "b"));
+
+        let mut loader = Loader::new();
+        let result = loader.load_program(&[entry]);
+
+        match result {
+            Err(CompilerError::ImportCycle { chain }) => {
+                assert!(chain.contains("a.aether"), "cycle chain should mention a.aether: {chain}");
+                assert!(chain.contains("b.aether"), "cycle chain should mention b.aether: {chain}");
+            }
+            other => panic!("expected an ImportCycle error, got {other:?}"),
+        }
+
+        fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn test_sources_map_covers_every_loaded_file() {
+        let dir = std::env::temp_dir().join(format!("aether_loader_test_sources_{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+
+        write_file(&dir, "util.aether", EMPTY_MODULE);
+        let entry = write_file(&dir, "main.aether", &importing_module("main", "util"));
+
+        let mut loader = Loader::new();
+        loader.load_program(&[entry]).unwrap();
+
+        let sources = loader.sources();
+        assert_eq!(sources.len(), 2);
+        for (id, text) in &sources {
+            assert_eq!(*text, loader.source(*id));
+        }
+
+        fs::remove_dir_all(&dir).ok();
+    }
+}
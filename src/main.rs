@@ -3,11 +3,76 @@
 //! Command-line interface for the AetherScript compiler
 
 use aether::Compiler;
+use aether::docs::{DocConfig, DocumentationGenerator};
+use aether::error::json_diagnostic::{JsonDiagnostic, JsonSummary};
 use aether::pipeline::CompileOptions;
-use clap::{Parser, Subcommand};
+use clap::{Parser, Subcommand, ValueEnum};
 use std::path::PathBuf;
 use std::process;
 
+/// How diagnostics are rendered: a caret-annotated source snippet (the
+/// default), a terse one-line message, or one JSON object per diagnostic
+/// for editors and CI to consume without regex-scraping stdout.
+/// See [`aether::error::snippet`] and [`aether::error::json_diagnostic`].
+#[derive(Clone, Copy, Default, ValueEnum)]
+enum ErrorFormat {
+    #[default]
+    Human,
+    Short,
+    Json,
+}
+
+/// How `aether ast`/`aether tokens` render their output: the existing
+/// bespoke debug text (the default, unchanged for backward compatibility),
+/// or a structured JSON array/object carrying every node's or token's exact
+/// source span so editors and refactoring scripts can map output back to
+/// source ranges without re-parsing.
+#[derive(Clone, Copy, Default, ValueEnum)]
+enum OutputFormat {
+    #[default]
+    Text,
+    Json,
+}
+
+/// Report a top-level compiler error the way `error_format` calls for,
+/// then exit with failure. The final error handler in `main` and the early
+/// failures of `ast` and `fmt` go through here; `check` renders each file's
+/// error in place so it can go on to the next file, and `tokens` prints its
+/// own plain-text error.
+fn report_fatal_error(error: &aether::error::CompilerError, error_format: ErrorFormat) -> ! {
+    match error_format {
+        ErrorFormat::Human => eprint!("{}", aether::error::snippet::render_compiler_error(error)),
+        ErrorFormat::Short => eprintln!("Compilation failed: {}", error),
+        ErrorFormat::Json => JsonDiagnostic::from_compiler_error(error).print(),
+    }
+    process::exit(1);
+}
+
+/// Print each compilation phase's elapsed time, either as a human summary
+/// table or as one JSON row per phase, depending on `error_format`.
+fn print_timings(stats: &aether::pipeline::CompilationStats, error_format: ErrorFormat) {
+    let mut phases: Vec<(&String, &u128)> = stats.phase_times.iter().collect();
+    phases.sort_by_key(|(_, duration_ms)| std::cmp::Reverse(**duration_ms));
+
+    match error_format {
+        ErrorFormat::Human | ErrorFormat::Short => {
+            println!("Phase timings:");
+            for (phase, duration_ms) in &phases {
+                println!("  {:<20} {}ms", phase, duration_ms);
+            }
+            println!("  {:<20} {}ms", "total", stats.total_time_ms);
+        }
+        ErrorFormat::Json => {
+            for (phase, duration_ms) in &phases {
+                println!(
+                    "{{\"phase\":\"{}\",\"duration_ms\":{}}}",
+                    phase, duration_ms
+                );
+            }
+        }
+    }
+}
+
 /// Format AST for human-readable display
 fn format_ast_for_display(program: &aether::ast::Program) -> String {
     let mut output = String::new();
@@ -62,6 +127,12 @@ fn format_type(type_spec: &aether::ast::TypeSpecifier) -> String {
             PrimitiveType::Void => "Void".to_string(),
             PrimitiveType::SizeT => "SizeT".to_string(),
             PrimitiveType::UIntPtrT => "UIntPtrT".to_string(),
+            PrimitiveType::I8 => "I8".to_string(),
+            PrimitiveType::I16 => "I16".to_string(),
+            PrimitiveType::U8 => "U8".to_string(),
+            PrimitiveType::U16 => "U16".to_string(),
+            PrimitiveType::U32 => "U32".to_string(),
+            PrimitiveType::U64 => "U64".to_string(),
         },
         aether::ast::TypeSpecifier::Named { name, .. } => name.name.clone(),
         aether::ast::TypeSpecifier::Array { element_type, .. } => 
@@ -90,6 +161,65 @@ fn format_type(type_spec: &aether::ast::TypeSpecifier) -> String {
     }
 }
 
+/// One token's worth of `aether tokens --format json` output: a token kind,
+/// the payload it carries (if any) rendered as a display string, and the
+/// exact byte/line/column range it spans in the source, so editors and
+/// refactoring scripts can map it back to source without re-lexing.
+#[derive(serde::Serialize)]
+struct JsonToken {
+    kind: String,
+    value: Option<String>,
+    line: usize,
+    column: usize,
+    byte_start: usize,
+    byte_end: usize,
+}
+
+impl JsonToken {
+    fn from_token(token: &aether::lexer::Token, source: &str) -> Self {
+        use aether::lexer::TokenType;
+
+        let (kind, value) = match &token.token_type {
+            TokenType::LeftParen => ("LeftParen", None),
+            TokenType::RightParen => ("RightParen", None),
+            TokenType::Integer(n) => ("Integer", Some(n.to_string())),
+            TokenType::Float(f) => ("Float", Some(f.to_string())),
+            TokenType::SizedInteger { value, bits, signed } => {
+                ("SizedInteger", Some(format!("{value}i{bits}{}", if *signed { "" } else { "u" })))
+            }
+            TokenType::SizedFloat { value, bits } => ("SizedFloat", Some(format!("{value}f{bits}"))),
+            TokenType::String(s) => ("String", Some(s.clone())),
+            TokenType::StringFragment(s) => ("StringFragment", Some(s.clone())),
+            TokenType::InterpolationStart => ("InterpolationStart", None),
+            TokenType::InterpolationEnd => ("InterpolationEnd", None),
+            TokenType::Character(c) => ("Character", Some(c.to_string())),
+            TokenType::Boolean(b) => ("Boolean", Some(b.to_string())),
+            TokenType::Identifier(i) => ("Identifier", Some(i.clone())),
+            TokenType::Keyword(k) => ("Keyword", Some(k.clone())),
+            TokenType::NullValue => ("NullValue", None),
+            TokenType::Caret => ("Caret", None),
+            TokenType::Ampersand => ("Ampersand", None),
+            TokenType::Tilde => ("Tilde", None),
+            TokenType::Comment(c) => ("Comment", Some(c.clone())),
+            TokenType::Whitespace => ("Whitespace", None),
+            TokenType::Eof => ("Eof", None),
+            TokenType::Error(message) => ("Error", Some(message.clone())),
+        };
+
+        // The lexer's `offset` counts chars, not bytes.
+        let byte_start: usize = source.chars().take(token.location.offset).map(char::len_utf8).sum();
+
+        Self {
+            kind: kind.to_string(),
+            value,
+            line: token.location.line,
+            column: token.location.column,
+            byte_start,
+            byte_end: byte_start + token.lexeme.len(),
+        }
+    }
+}
+
 #[derive(Parser)]
 #[command(name = "aether")]
 #[command(about = "Compiler for the AetherScript programming language", long_about = None)]
@@ -150,17 +280,46 @@ enum Commands {
         /// Link with library
         #[arg(short = 'l', long = "link")]
         link_libraries: Vec<String>,
+
+        /// Diagnostic output format
+        #[arg(long = "error-format", value_enum, default_value = "human")]
+        error_format: ErrorFormat,
+
+        /// Report how long each compilation phase took
+        #[arg(long)]
+        timings: bool,
+
+        /// Fail if any single compilation phase exceeds this many milliseconds
+        #[arg(long = "time-budget-ms")]
+        time_budget_ms: Option<u128>,
+
+        /// Resolve `import` statements across files, compiling every file
+        /// transitively imported from the given input(s) as well
+        #[arg(long = "resolve-imports")]
+        resolve_imports: bool,
     },
-    
+
     /// Check syntax without generating code
     Check {
         /// Input source file(s)
         #[arg(required = true)]
         input: Vec<PathBuf>,
-        
+
         /// Verbose output
         #[arg(short, long)]
         verbose: bool,
+
+        /// Diagnostic output format
+        #[arg(long = "error-format", value_enum, default_value = "human")]
+        error_format: ErrorFormat,
+
+        /// Report how long each compilation phase took
+        #[arg(long)]
+        timings: bool,
+
+        /// Fail if any single compilation phase exceeds this many milliseconds
+        #[arg(long = "time-budget-ms")]
+        time_budget_ms: Option<u128>,
     },
     
     /// Run AetherScript program (compile and execute)
@@ -176,33 +335,96 @@ enum Commands {
         /// Verbose output
         #[arg(short, long)]
         verbose: bool,
+
+        /// Execute in-process via an LLVM JIT instead of compiling to an
+        /// executable on disk and spawning it
+        #[arg(long)]
+        jit: bool,
     },
-    
+
     /// Print AST (Abstract Syntax Tree)
     Ast {
         /// Input source file
         #[arg(required = true)]
         input: PathBuf,
-        
+
         /// Output directory (prints to stdout if not specified)
         #[arg(short, long)]
         output: Option<String>,
-        
+
         /// Verbose output
         #[arg(short, long)]
         verbose: bool,
+
+        /// Diagnostic output format
+        #[arg(long = "error-format", value_enum, default_value = "human")]
+        error_format: ErrorFormat,
+
+        /// Output format: bespoke debug text, or JSON with a source span on
+        /// every node
+        #[arg(long, value_enum, default_value = "text")]
+        format: OutputFormat,
     },
-    
+
     /// Print tokens
     Tokens {
         /// Input source file
         #[arg(required = true)]
         input: PathBuf,
-        
+
         /// Output directory (prints to stdout if not specified)
         #[arg(short, long)]
         output: Option<String>,
-        
+
+        /// Verbose output
+        #[arg(short, long)]
+        verbose: bool,
+
+        /// Output format: bespoke debug text, or JSON with a source span on
+        /// every token
+        #[arg(long, value_enum, default_value = "text")]
+        format: OutputFormat,
+    },
+
+    /// Format source files to canonical style
+    Fmt {
+        /// Input source file(s)
+        #[arg(required = true)]
+        input: Vec<PathBuf>,
+
+        /// Check whether files are already formatted, printing a diff for
+        /// any that aren't, without modifying them. Exits nonzero if any
+        /// file would be reformatted.
+        #[arg(long)]
+        check: bool,
+
+        /// Format files in place instead of printing to stdout
+        #[arg(long)]
+        write: bool,
+
+        /// Diagnostic output format
+        #[arg(long = "error-format", value_enum, default_value = "human")]
+        error_format: ErrorFormat,
+    },
+
+    /// Generate API documentation, tutorials, and examples from source
+    Doc {
+        /// Source directories to document
+        #[arg(default_value = "src")]
+        input: Vec<PathBuf>,
+
+        /// Output directory for generated documentation
+        #[arg(short, long, default_value = "docs")]
+        output: PathBuf,
+
+        /// Include private (non-exported) items
+        #[arg(long)]
+        include_private: bool,
+
+        /// Compile and run every documented example, failing if any has rotted
+        #[arg(long)]
+        verify_examples: bool,
+
         /// Verbose output
         #[arg(short, long)]
         verbose: bool,
@@ -211,20 +433,30 @@ enum Commands {
 
 fn main() {
     let cli = Cli::parse();
-    
+
+    // Set by whichever subcommand accepts `--error-format`, and consulted
+    // by the top-level error handler below so every subcommand renders
+    // its final failure the same way.
+    let mut error_format = ErrorFormat::Human;
+
     let result = match cli.command {
-        Some(Commands::Compile { 
-            input, 
-            output, 
-            optimization, 
-            debug, 
+        Some(Commands::Compile {
+            input,
+            output,
+            optimization,
+            debug,
             verbose,
             keep_intermediates,
             compile_only,
             library,
             library_paths,
             link_libraries,
+            error_format: format,
+            timings,
+            time_budget_ms,
+            resolve_imports,
         }) => {
+            error_format = format;
             let mut options = CompileOptions::default();
             options.optimization_level = optimization.min(3);
             options.debug_info = debug;
@@ -234,11 +466,13 @@ fn main() {
             options.compile_as_library = library;
             options.library_paths = library_paths;
             options.link_libraries = link_libraries;
-            
+            options.time_budget_ms = time_budget_ms;
+            options.resolve_imports = resolve_imports;
+
             if let Some(output_path) = output {
                 options.output = Some(output_path);
             }
-            
+
             let compiler = Compiler::with_options(options);
             match compiler.compile_files(&input) {
                 Ok(result) => {
@@ -246,16 +480,21 @@ fn main() {
                     if verbose || cli.verbose {
                         println!("Output: {}", result.executable_path.display());
                     }
+                    if timings {
+                        print_timings(&result.stats, error_format);
+                    }
                     Ok(result)
                 }
                 Err(e) => Err(e)
             }
         }
-        
-        Some(Commands::Check { input, verbose }) => {
+
+        Some(Commands::Check { input, verbose, error_format: format, timings, time_budget_ms }) => {
+            error_format = format;
             let mut options = CompileOptions::default();
             options.verbose = verbose || cli.verbose;
             options.debug_info = cli.debug;
+            options.time_budget_ms = time_budget_ms;
             // syntax_only still runs semantic analysis, just skips code generation
             options.syntax_only = true;
             
@@ -279,105 +518,163 @@ fn main() {
                 
                 // Check if file exists
                 if !file.exists() {
-                    eprintln!("Error: File '{}' not found", file.display());
+                    let not_found = aether::error::CompilerError::IoError {
+                        message: format!("File '{}' not found", file.display()),
+                    };
+                    match error_format {
+                        ErrorFormat::Human => eprint!("{}", aether::error::snippet::render_compiler_error(&not_found)),
+                        ErrorFormat::Short => eprintln!("Error: File '{}' not found", file.display()),
+                        ErrorFormat::Json => JsonDiagnostic::from_compiler_error(&not_found).print(),
+                    }
                     files_failed += 1;
                     total_errors += 1;
                     continue;
                 }
-                
+
                 let compiler = Compiler::with_options(options.clone());
                 match compiler.compile_files(&[file.clone()]) {
-                    Ok(_) => {
+                    Ok(result) => {
                         files_passed += 1;
                         if verbose || cli.verbose {
                             println!("✓ {} - OK", file.display());
                         }
+                        if timings {
+                            print_timings(&result.stats, error_format);
+                        }
                     }
                     Err(e) => {
                         files_failed += 1;
                         total_errors += 1;
-                        // Always print the error details, not just in verbose mode
-                        eprintln!("Error in {}: {}", file.display(), e);
-                        if verbose || cli.verbose {
-                            println!("✗ {} - Error: {}", file.display(), e);
+                        match error_format {
+                            ErrorFormat::Human => {
+                                // Always print the error details, not just in verbose mode
+                                eprint!("{}", aether::error::snippet::render_compiler_error(&e));
+                                if verbose || cli.verbose {
+                                    println!("✗ {} - Error: {}", file.display(), e);
+                                }
+                            }
+                            ErrorFormat::Short => {
+                                eprintln!("Error in {}: {}", file.display(), e);
+                                if verbose || cli.verbose {
+                                    println!("✗ {} - Error: {}", file.display(), e);
+                                }
+                            }
+                            ErrorFormat::Json => JsonDiagnostic::from_compiler_error(&e).print(),
                         }
                         // Continue checking other files
                     }
                 }
             }
-            
+
             // Print summary
             if files_failed == 0 {
-                println!("Type checking passed");
-                println!("Files passed: {}", files_passed);
-                println!("Total errors: 0");
+                match error_format {
+                    ErrorFormat::Human | ErrorFormat::Short => {
+                        println!("Type checking passed");
+                        println!("Files passed: {}", files_passed);
+                        println!("Total errors: 0");
+                    }
+                    ErrorFormat::Json => JsonSummary {
+                        files_passed,
+                        files_with_errors: 0,
+                        total_errors: 0,
+                    }.print(),
+                }
                 Ok(aether::pipeline::CompilationResult {
                     executable_path: PathBuf::new(),
                     intermediate_files: vec![],
                     stats: Default::default(),
                 })
             } else {
-                println!("Type checking failed");
-                println!("Files passed: {}", files_passed); 
-                println!("Files with errors: {}", files_failed);
-                println!("Total errors: {}", total_errors);
-                Err(aether::error::CompilerError::SemanticError(
-                    aether::error::SemanticError::TypeMismatch {
-                        expected: "valid".to_string(),
-                        found: "errors".to_string(),
-                        location: aether::error::SourceLocation::unknown(),
+                match error_format {
+                    ErrorFormat::Human | ErrorFormat::Short => {
+                        println!("Type checking failed");
+                        println!("Files passed: {}", files_passed);
+                        println!("Files with errors: {}", files_failed);
+                        println!("Total errors: {}", total_errors);
                     }
-                ))
+                    ErrorFormat::Json => JsonSummary {
+                        files_passed,
+                        files_with_errors: files_failed,
+                        total_errors,
+                    }.print(),
+                }
+                // Every failure was already rendered per-file above against
+                // its own diagnostic, so there's nothing left to say here -
+                // just fail the process. No synthetic CompilerError to carry
+                // through report_fatal_error, which would otherwise print a
+                // second, made-up message on top of the real ones.
+                process::exit(1);
             }
         }
         
-        Some(Commands::Run { input, args, verbose }) => {
-            // First compile the program
+        Some(Commands::Run { input, args, verbose, jit }) => {
             let mut options = CompileOptions::default();
             options.verbose = verbose;
             options.optimization_level = 2;
-            
-            let compiler = Compiler::with_options(options);
-            match compiler.compile_files(&[input]) {
-                Ok(result) => {
-                    // Execute the compiled program
-                    let mut cmd = process::Command::new(&result.executable_path);
-                    cmd.args(&args);
-                    
-                    match cmd.status() {
-                        Ok(status) => {
-                            if !status.success() {
-                                process::exit(status.code().unwrap_or(1));
-                            }
-                            Ok(result)
+
+            if jit {
+                let compiler = Compiler::with_options(options);
+                match compiler.jit_run(input, args) {
+                    Ok(exit_code) => {
+                        if exit_code != 0 {
+                            process::exit(exit_code);
                         }
-                        Err(e) => {
-                            eprintln!("Failed to execute program: {}", e);
-                            process::exit(1);
+                        Ok(aether::pipeline::CompilationResult {
+                            executable_path: PathBuf::new(),
+                            intermediate_files: vec![],
+                            stats: Default::default(),
+                        })
+                    }
+                    Err(e) => Err(e),
+                }
+            } else {
+                // First compile the program
+                let compiler = Compiler::with_options(options);
+                match compiler.compile_files(&[input]) {
+                    Ok(result) => {
+                        // Execute the compiled program
+                        let mut cmd = process::Command::new(&result.executable_path);
+                        cmd.args(&args);
+
+                        match cmd.status() {
+                            Ok(status) => {
+                                if !status.success() {
+                                    process::exit(status.code().unwrap_or(1));
+                                }
+                                Ok(result)
+                            }
+                            Err(e) => {
+                                eprintln!("Failed to execute program: {}", e);
+                                process::exit(1);
+                            }
                         }
                     }
+                    Err(e) => Err(e),
                 }
-                Err(e) => Err(e),
             }
         }
-        
-        Some(Commands::Ast { input, output, verbose }) => {
+
+        Some(Commands::Ast { input, output, verbose, error_format: format, format: output_format }) => {
+            error_format = format;
             use aether::parser::Parser;
             use aether::lexer::Lexer;
             use std::fs;
-            
+
             let content = match fs::read_to_string(&input) {
                 Ok(content) => content,
-                Err(e) => {
-                    eprintln!("Failed to read file {}: {}", input.display(), e);
-                    process::exit(1);
-                }
+                Err(e) => report_fatal_error(
+                    &aether::error::CompilerError::IoError {
+                        message: format!("Failed to read file {}: {}", input.display(), e),
+                    },
+                    error_format,
+                ),
             };
-            
+
             // First tokenize
             let mut lexer = Lexer::new(&content, input.display().to_string());
             let mut tokens = vec![];
-            
+
             loop {
                 match lexer.next_token() {
                     Ok(token) => {
@@ -386,19 +683,26 @@ fn main() {
                         }
                         tokens.push(token);
                     }
-                    Err(e) => {
-                        eprintln!("Lexer error: {}", e);
-                        process::exit(1);
-                    }
+                    Err(e) => report_fatal_error(&aether::error::CompilerError::from(e), error_format),
                 }
             }
-            
+
             // Then parse
             let mut parser = Parser::new(tokens);
             match parser.parse_program() {
                 Ok(ast) => {
-                    let output_content = format_ast_for_display(&ast);
-                    
+                    let output_content = match output_format {
+                        OutputFormat::Text => format_ast_for_display(&ast),
+                        OutputFormat::Json => serde_json::to_string_pretty(&ast).unwrap_or_else(|e| {
+                            report_fatal_error(
+                                &aether::error::CompilerError::Internal {
+                                    message: format!("Failed to serialize AST to JSON: {}", e),
+                                },
+                                error_format,
+                            )
+                        }),
+                    };
+
                     if let Some(output_dir) = output {
                         let output_path = std::path::Path::new(&output_dir)
                             .join(input.file_stem().unwrap())
@@ -414,14 +718,11 @@ fn main() {
                         stats: Default::default(),
                     })
                 }
-                Err(e) => {
-                    eprintln!("Parse error: {}", e);
-                    process::exit(1);
-                }
+                Err(e) => report_fatal_error(&aether::error::CompilerError::from(e), error_format),
             }
         }
         
-        Some(Commands::Tokens { input, output, verbose }) => {
+        Some(Commands::Tokens { input, output, verbose, format: output_format }) => {
             use aether::lexer::Lexer;
             use std::fs;
             
@@ -451,38 +752,59 @@ fn main() {
                 }
             }
             
-            // Format tokens in the expected debug format for both stdout and file output
-            let mut token_output = String::new();
-            token_output.push_str(&format!("Tokens for {}:\n", input.display()));
-            token_output.push_str("=================\n");
-            for token in &tokens {
-                // Format TokenType in the expected format
-                let token_str = match &token.token_type {
-                    aether::lexer::TokenType::LeftParen => "LeftParen".to_string(),
-                    aether::lexer::TokenType::RightParen => "RightParen".to_string(),
-                    aether::lexer::TokenType::Keyword(k) => format!("Keyword(\"{}\")", k),
-                    aether::lexer::TokenType::Identifier(i) => format!("Identifier(\"{}\")", i),
-                    aether::lexer::TokenType::Integer(n) => format!("Integer({})", n),
-                    aether::lexer::TokenType::Float(f) => format!("Float({})", f),
-                    aether::lexer::TokenType::String(s) => format!("String(\"{}\")", s),
-                    aether::lexer::TokenType::Character(c) => format!("Character('{}')", c),
-                    aether::lexer::TokenType::Boolean(b) => format!("Boolean({})", b),
-                    aether::lexer::TokenType::NullValue => "NullValue".to_string(),
-                    aether::lexer::TokenType::Caret => "Caret".to_string(),
-                    aether::lexer::TokenType::Ampersand => "Ampersand".to_string(),
-                    aether::lexer::TokenType::Tilde => "Tilde".to_string(),
-                    aether::lexer::TokenType::Comment(c) => format!("Comment(\"{}\")", c),
-                    aether::lexer::TokenType::Whitespace => "Whitespace".to_string(),
-                    aether::lexer::TokenType::Eof => "Eof".to_string(),
-                };
-                token_output.push_str(&format!("{} at {}:{}\n", 
-                    token_str,
-                    token.location.line, 
-                    token.location.column
-                ));
-            }
-            let output_content = token_output;
-            
+            let output_content = match output_format {
+                OutputFormat::Text => {
+                    // Format tokens in the expected debug format for both stdout and file output
+                    let mut token_output = String::new();
+                    token_output.push_str(&format!("Tokens for {}:\n", input.display()));
+                    token_output.push_str("=================\n");
+                    for token in &tokens {
+                        // Format TokenType in the expected format
+                        let token_str = match &token.token_type {
+                            aether::lexer::TokenType::LeftParen => "LeftParen".to_string(),
+                            aether::lexer::TokenType::RightParen => "RightParen".to_string(),
+                            aether::lexer::TokenType::Keyword(k) => format!("Keyword(\"{}\")", k),
+                            aether::lexer::TokenType::Identifier(i) => format!("Identifier(\"{}\")", i),
+                            aether::lexer::TokenType::Integer(n) => format!("Integer({})", n),
+                            aether::lexer::TokenType::Float(f) => format!("Float({})", f),
+                            aether::lexer::TokenType::SizedInteger { value, bits, signed } => {
+                                format!("SizedInteger({}, bits={}, signed={})", value, bits, signed)
+                            }
+                            aether::lexer::TokenType::SizedFloat { value, bits } => {
+                                format!("SizedFloat({}, bits={})", value, bits)
+                            }
+                            aether::lexer::TokenType::String(s) => format!("String(\"{}\")", s),
+                            aether::lexer::TokenType::Character(c) => format!("Character('{}')", c),
+                            aether::lexer::TokenType::Boolean(b) => format!("Boolean({})", b),
+                            aether::lexer::TokenType::NullValue => "NullValue".to_string(),
+                            aether::lexer::TokenType::Caret => "Caret".to_string(),
+                            aether::lexer::TokenType::Ampersand => "Ampersand".to_string(),
+                            aether::lexer::TokenType::Tilde => "Tilde".to_string(),
+                            aether::lexer::TokenType::Comment(c) => format!("Comment(\"{}\")", c),
+                            aether::lexer::TokenType::Whitespace => "Whitespace".to_string(),
+                            aether::lexer::TokenType::Eof => "Eof".to_string(),
+                            aether::lexer::TokenType::Error(message) => format!("Error(\"{}\")", message),
+                            aether::lexer::TokenType::StringFragment(s) => format!("StringFragment(\"{}\")", s),
+                            aether::lexer::TokenType::InterpolationStart => "InterpolationStart".to_string(),
+                            aether::lexer::TokenType::InterpolationEnd => "InterpolationEnd".to_string(),
+                        };
+                        token_output.push_str(&format!("{} at {}:{}\n",
+                            token_str,
+                            token.location.line,
+                            token.location.column
+                        ));
+                    }
+                    token_output
+                }
+                OutputFormat::Json => {
+                    let json_tokens: Vec<JsonToken> = tokens.iter().map(|token| JsonToken::from_token(token, &content)).collect();
+                    serde_json::to_string_pretty(&json_tokens).unwrap_or_else(|e| {
+                        eprintln!("Failed to serialize tokens to JSON: {}", e);
+                        process::exit(1);
+                    })
+                }
+            };
+
             if let Some(output_dir) = output {
                 let output_path = std::path::Path::new(&output_dir)
                     .join(input.file_stem().unwrap())
@@ -499,7 +821,81 @@ fn main() {
                 stats: Default::default(),
             })
         }
-        
+
+        Some(Commands::Fmt { input, check, write, error_format: format }) => {
+            error_format = format;
+            use std::fs;
+
+            let mut unformatted = 0;
+            for file in &input {
+                let filename = file.display().to_string();
+                let source = match fs::read_to_string(file) {
+                    Ok(source) => source,
+                    Err(e) => report_fatal_error(
+                        &aether::error::CompilerError::IoError {
+                            message: format!("Failed to read {}: {}", filename, e),
+                        },
+                        error_format,
+                    ),
+                };
+
+                let formatted = match aether::fmt::format_source(&source, &filename) {
+                    Ok(formatted) => formatted,
+                    Err(e) => report_fatal_error(&e, error_format),
+                };
+
+                if check {
+                    if formatted != source {
+                        unformatted += 1;
+                        println!("Would reformat {}", filename);
+                        print!("{}", aether::fmt::unified_diff(&source, &formatted));
+                    }
+                } else if write {
+                    if formatted != source {
+                        fs::write(file, &formatted).unwrap();
+                        println!("Formatted {}", filename);
+                    }
+                } else {
+                    print!("{}", formatted);
+                }
+            }
+
+            if check && unformatted > 0 {
+                eprintln!("{} file(s) would be reformatted", unformatted);
+                process::exit(1);
+            }
+
+            Ok(aether::pipeline::CompilationResult {
+                executable_path: PathBuf::new(),
+                intermediate_files: vec![],
+                stats: Default::default(),
+            })
+        }
+
+        Some(Commands::Doc { input, output, include_private, verify_examples, verbose }) => {
+            let config = DocConfig {
+                output_dir: output,
+                source_dirs: input,
+                include_private,
+                verify_examples,
+                ..DocConfig::default()
+            };
+
+            match DocumentationGenerator::new(config).and_then(|mut generator| generator.generate()) {
+                Ok(()) => {
+                    if verbose || cli.verbose {
+                        println!("Documentation generated");
+                    }
+                    Ok(aether::pipeline::CompilationResult {
+                        executable_path: PathBuf::new(),
+                        intermediate_files: vec![],
+                        stats: Default::default(),
+                    })
+                }
+                Err(e) => Err(e.into()),
+            }
+        }
+
         None => {
             // No subcommand provided - print error and help
             eprintln!("Error: No subcommand provided");
@@ -515,9 +911,6 @@ fn main() {
         Ok(_) => {
             // Success
         }
-        Err(e) => {
-            eprintln!("Compilation failed: {}", e);
-            process::exit(1);
-        }
+        Err(e) => report_fatal_error(&e, error_format),
     }
 }
\ No newline at end of file
@@ -3196,11 +3196,14 @@ mod tests {
                 throws_exceptions: vec![],
                 thread_safe: None,
                 may_block: None,
+                is_test: false,
             },
             body: ast::Block {
                 statements: vec![
                     ast::Statement::Return {
                         value: Some(Box::new(ast::Expression::IntegerLiteral {
+                            bits: 64,
+                            signed: true,
                             value: 42,
                             source_location: SourceLocation::unknown(),
                         })),
@@ -327,6 +327,13 @@ pub enum ConstantValue {
     String(String),
     Char(char),
     Null,
+    /// A fixed-length array of constant elements, e.g. folded from an
+    /// `Rvalue::Aggregate { kind: AggregateKind::Array(..), .. }` whose
+    /// operands were all themselves constants.
+    Array(Vec<ConstantValue>),
+    /// A fixed-length tuple of constant elements, analogous to `Array` but
+    /// for `AggregateKind::Tuple`.
+    Tuple(Vec<ConstantValue>),
 }
 
 impl PartialEq for ConstantValue {
@@ -338,6 +345,8 @@ impl PartialEq for ConstantValue {
             (ConstantValue::String(a), ConstantValue::String(b)) => a == b,
             (ConstantValue::Char(a), ConstantValue::Char(b)) => a == b,
             (ConstantValue::Null, ConstantValue::Null) => true,
+            (ConstantValue::Array(a), ConstantValue::Array(b)) => a == b,
+            (ConstantValue::Tuple(a), ConstantValue::Tuple(b)) => a == b,
             _ => false,
         }
     }
@@ -372,6 +381,14 @@ impl std::hash::Hash for ConstantValue {
             ConstantValue::Null => {
                 5u8.hash(state);
             }
+            ConstantValue::Array(elements) => {
+                6u8.hash(state);
+                elements.hash(state);
+            }
+            ConstantValue::Tuple(elements) => {
+                7u8.hash(state);
+                elements.hash(state);
+            }
         }
     }
 }
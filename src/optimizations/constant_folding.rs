@@ -13,61 +13,301 @@
 // limitations under the License.
 
 //! Constant folding optimization pass
-//! 
+//!
 //! Evaluates constant expressions at compile time
+//!
+//! # Reachability of the array/tuple bounds check
+//!
+//! [`resolve_known_place`]'s indexed-read branch only fires for a `Place`
+//! with a single `PlaceElem::Index` projection into a tracked
+//! `ConstantValue::Array`/`Tuple`. Real array literals and reads never
+//! produce that shape - `mir::lowering` lowers them to opaque
+//! `array_create`/`array_set`/`array_get` runtime calls instead (see
+//! `lower_array_literal`/`lower_array_access` in `src/mir/lowering.rs`).
+//! So on a real compile this branch, and the out-of-bounds error it can
+//! raise, never fires; it is exercised today only by this module's own
+//! hand-built MIR unit tests.
+//! `tests/integration/constant_folding_array_reachability_tests.rs` pins
+//! down the lowering side of that gap with an end-to-end source-to-MIR test.
+
+use std::collections::HashMap;
 
 use super::OptimizationPass;
 use crate::mir::{
-    Function, Statement, Rvalue, Operand, Constant, ConstantValue, BinOp, UnOp,
+    Function, Statement, Rvalue, Operand, Constant, ConstantValue, BinOp, UnOp, SourceInfo, LocalId,
+    Place, PlaceElem, AggregateKind,
 };
 use crate::types::Type;
 use crate::ast::PrimitiveType;
 use crate::error::SemanticError;
 
+/// Resolve a place read to a tracked constant, if possible: a bare local
+/// read returns whatever is tracked for it; a single index projection into
+/// a tracked constant array/tuple by a tracked constant integer resolves to
+/// the indexed element, after checking the index is in bounds. An
+/// out-of-bounds constant index is a guaranteed bug, so it is reported as
+/// an error rather than silently declined like an unresolvable read.
+///
+/// The indexed branch is unreachable from real source today - see the
+/// "Reachability" note on the module doc comment.
+fn resolve_known_place(
+    known_constants: &HashMap<LocalId, Constant>,
+    place: &Place,
+    source_info: &SourceInfo,
+) -> Result<Option<Constant>, SemanticError> {
+    if place.projection.is_empty() {
+        return Ok(known_constants.get(&place.local).cloned());
+    }
+
+    if let [PlaceElem::Index(index_local)] = place.projection.as_slice() {
+        let (Some(base), Some(index_constant)) =
+            (known_constants.get(&place.local), known_constants.get(index_local))
+        else {
+            return Ok(None);
+        };
+
+        let elements = match &base.value {
+            ConstantValue::Array(elements) | ConstantValue::Tuple(elements) => elements,
+            _ => return Ok(None),
+        };
+        let ConstantValue::Integer(index) = index_constant.value else {
+            return Ok(None);
+        };
+
+        if index < 0 || index >= elements.len() as i128 {
+            return Err(SemanticError::InvalidOperation {
+                operation: "array index".to_string(),
+                reason: format!(
+                    "index out of bounds: the length is {} but the index is {}",
+                    elements.len(),
+                    index
+                ),
+                location: source_info.span.clone(),
+            });
+        }
+
+        let element_ty = match &base.ty {
+            Type::Array { element_type, .. } => (**element_type).clone(),
+            other => other.clone(),
+        };
+        return Ok(Some(Constant { ty: element_ty, value: elements[index as usize].clone() }));
+    }
+
+    Ok(None)
+}
+
+/// Replace `operand` with the tracked constant it reads, if any - either a
+/// bare local read or a constant index into a tracked constant aggregate.
+fn substitute_operand(
+    known_constants: &HashMap<LocalId, Constant>,
+    operand: &mut Operand,
+    source_info: &SourceInfo,
+) -> Result<(), SemanticError> {
+    if let Operand::Copy(place) | Operand::Move(place) = operand {
+        if let Some(constant) = resolve_known_place(known_constants, place, source_info)? {
+            *operand = Operand::Constant(constant);
+        }
+    }
+    Ok(())
+}
+
+/// Substitute every operand of `rvalue` that reads a locally-tracked
+/// constant, so that a chain like `a = 2; b = a + 3` folds just as if `a`
+/// had been written as a literal.
+fn substitute_known_constants(
+    known_constants: &HashMap<LocalId, Constant>,
+    rvalue: &mut Rvalue,
+    source_info: &SourceInfo,
+) -> Result<(), SemanticError> {
+    match rvalue {
+        Rvalue::Use(operand) => substitute_operand(known_constants, operand, source_info)?,
+        Rvalue::BinaryOp { left, right, .. } => {
+            substitute_operand(known_constants, left, source_info)?;
+            substitute_operand(known_constants, right, source_info)?;
+        }
+        Rvalue::UnaryOp { operand, .. } => substitute_operand(known_constants, operand, source_info)?,
+        Rvalue::Cast { operand, .. } => substitute_operand(known_constants, operand, source_info)?,
+        Rvalue::Aggregate { operands, .. } => {
+            for operand in operands {
+                substitute_operand(known_constants, operand, source_info)?;
+            }
+        }
+        Rvalue::Call { args, .. } => {
+            for arg in args {
+                substitute_operand(known_constants, arg, source_info)?;
+            }
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+/// The bit width and signedness implied by an integer-like [`PrimitiveType`].
+/// Returns `None` for non-integer primitives (`Boolean`, `Char`, `Float*`).
+fn integer_width(ty: &Type) -> Option<(u32, bool)> {
+    let Type::Primitive(prim) = ty else { return None };
+    match prim {
+        PrimitiveType::Integer => Some((64, true)),
+        PrimitiveType::Integer32 => Some((32, true)),
+        PrimitiveType::Integer64 => Some((64, true)),
+        PrimitiveType::I8 => Some((8, true)),
+        PrimitiveType::I16 => Some((16, true)),
+        PrimitiveType::U8 => Some((8, false)),
+        PrimitiveType::U16 => Some((16, false)),
+        PrimitiveType::U32 => Some((32, false)),
+        PrimitiveType::U64 => Some((64, false)),
+        PrimitiveType::SizeT | PrimitiveType::UIntPtrT => Some((64, false)),
+        _ => None,
+    }
+}
+
+/// The inclusive `[min, max]` range representable in `bit_width` bits,
+/// expressed in the `i128` domain folding is performed in.
+fn integer_range(bit_width: u32, signed: bool) -> (i128, i128) {
+    if signed {
+        let max = (1i128 << (bit_width - 1)) - 1;
+        (-max - 1, max)
+    } else {
+        (0, (1i128 << bit_width) - 1)
+    }
+}
+
+/// Truncate (and, for signed widths, sign-extend) `value` to `bit_width` bits.
+fn truncate_to_width(value: i128, bit_width: u32, signed: bool) -> i128 {
+    if bit_width >= 128 {
+        return value;
+    }
+    let mask = (1i128 << bit_width) - 1;
+    let truncated = value & mask;
+    if signed && (truncated & (1i128 << (bit_width - 1))) != 0 {
+        truncated - (1i128 << bit_width)
+    } else {
+        truncated
+    }
+}
+
 /// Constant folding optimization pass
 pub struct ConstantFoldingPass {
     changed: bool,
+    overflow_checking: bool,
 }
 
 impl ConstantFoldingPass {
     pub fn new() -> Self {
-        Self { changed: false }
+        Self { changed: false, overflow_checking: false }
     }
-    
+
+    /// When enabled, arithmetic that would guaranteed-overflow the target
+    /// integer type is reported as a [`SemanticError::InvalidOperation`]
+    /// instead of silently wrapping.
+    pub fn with_overflow_checking(mut self, enabled: bool) -> Self {
+        self.overflow_checking = enabled;
+        self
+    }
+
+    /// Fold an integer binary operation, honoring the width/signedness of
+    /// `ty` rather than treating every `ConstantValue::Integer` as a raw
+    /// 64-bit value. In overflow-checking mode, a result that doesn't fit in
+    /// `ty` (or a division/shift that isn't well-defined) is reported as an
+    /// error carrying `source_info`'s span rather than wrapped or masked.
+    fn fold_integer_binary_op(
+        &self,
+        op: BinOp,
+        l: i128,
+        r: i128,
+        ty: &Type,
+        source_info: &SourceInfo,
+    ) -> Result<Option<ConstantValue>, SemanticError> {
+        let (bit_width, signed) = match integer_width(ty) {
+            Some(w) => w,
+            None => return Ok(None),
+        };
+        let (min, max) = integer_range(bit_width, signed);
+
+        let overflows = |raw: i128| raw < min || raw > max;
+        let overflow_err = || SemanticError::InvalidOperation {
+            operation: format!("{op:?}"),
+            reason: "this arithmetic operation will overflow the target type".to_string(),
+            location: source_info.span.clone(),
+        };
+
+        let raw = match op {
+            BinOp::Add => l + r,
+            BinOp::Sub => l - r,
+            BinOp::Mul => l * r,
+            BinOp::Div | BinOp::Rem => {
+                if r == 0 {
+                    return if self.overflow_checking {
+                        Err(SemanticError::InvalidOperation {
+                            operation: format!("{op:?}"),
+                            reason: "division by zero".to_string(),
+                            location: source_info.span.clone(),
+                        })
+                    } else {
+                        Ok(None)
+                    };
+                }
+                if signed && l == min && r == -1 {
+                    return if self.overflow_checking { Err(overflow_err()) } else { Ok(None) };
+                }
+                if op == BinOp::Div { l / r } else { l % r }
+            }
+            BinOp::Eq => return Ok(Some(ConstantValue::Bool(l == r))),
+            BinOp::Ne => return Ok(Some(ConstantValue::Bool(l != r))),
+            BinOp::Lt => return Ok(Some(ConstantValue::Bool(l < r))),
+            BinOp::Le => return Ok(Some(ConstantValue::Bool(l <= r))),
+            BinOp::Gt => return Ok(Some(ConstantValue::Bool(l > r))),
+            BinOp::Ge => return Ok(Some(ConstantValue::Bool(l >= r))),
+            BinOp::BitAnd => return Ok(Some(ConstantValue::Integer((l & r) as i128))),
+            BinOp::BitOr => return Ok(Some(ConstantValue::Integer((l | r) as i128))),
+            BinOp::BitXor => return Ok(Some(ConstantValue::Integer((l ^ r) as i128))),
+            BinOp::Shl | BinOp::Shr => {
+                if r < 0 || r >= bit_width as i128 {
+                    // Not wrap-masked: a shift by >= the type's width isn't
+                    // well-defined, so decline to fold rather than guess
+                    // (or report it, in overflow-checking mode).
+                    return if self.overflow_checking {
+                        Err(SemanticError::InvalidOperation {
+                            operation: format!("{op:?}"),
+                            reason: "shift amount is out of range for the target type".to_string(),
+                            location: source_info.span.clone(),
+                        })
+                    } else {
+                        Ok(None)
+                    };
+                }
+                let shifted = if op == BinOp::Shl { l << r } else { l >> r };
+                if self.overflow_checking && op == BinOp::Shl && overflows(shifted) {
+                    return Err(overflow_err());
+                }
+                return Ok(Some(ConstantValue::Integer(truncate_to_width(shifted, bit_width, signed))));
+            }
+            _ => return Ok(None),
+        };
+
+        if self.overflow_checking && overflows(raw) {
+            return Err(overflow_err());
+        }
+        Ok(Some(ConstantValue::Integer(truncate_to_width(raw, bit_width, signed))))
+    }
+
     /// Fold a binary operation on constants
     fn fold_binary_op(
         &self,
         op: BinOp,
-        left: &ConstantValue,
-        right: &ConstantValue,
-    ) -> Option<ConstantValue> {
-        match (left, right) {
+        left: &Constant,
+        right: &Constant,
+        source_info: &SourceInfo,
+    ) -> Result<Option<ConstantValue>, SemanticError> {
+        match (&left.value, &right.value) {
             // Integer operations
             (ConstantValue::Integer(l), ConstantValue::Integer(r)) => {
-                match op {
-                    BinOp::Add => Some(ConstantValue::Integer(l.wrapping_add(*r))),
-                    BinOp::Sub => Some(ConstantValue::Integer(l.wrapping_sub(*r))),
-                    BinOp::Mul => Some(ConstantValue::Integer(l.wrapping_mul(*r))),
-                    BinOp::Div if *r != 0 => Some(ConstantValue::Integer(l / r)),
-                    BinOp::Rem if *r != 0 => Some(ConstantValue::Integer(l % r)),
-                    BinOp::Eq => Some(ConstantValue::Bool(l == r)),
-                    BinOp::Ne => Some(ConstantValue::Bool(l != r)),
-                    BinOp::Lt => Some(ConstantValue::Bool(l < r)),
-                    BinOp::Le => Some(ConstantValue::Bool(l <= r)),
-                    BinOp::Gt => Some(ConstantValue::Bool(l > r)),
-                    BinOp::Ge => Some(ConstantValue::Bool(l >= r)),
-                    BinOp::BitAnd => Some(ConstantValue::Integer(l & r)),
-                    BinOp::BitOr => Some(ConstantValue::Integer(l | r)),
-                    BinOp::BitXor => Some(ConstantValue::Integer(l ^ r)),
-                    BinOp::Shl => Some(ConstantValue::Integer(l << (r & 63))), // Mask to prevent overflow
-                    BinOp::Shr => Some(ConstantValue::Integer(l >> (r & 63))),
-                    _ => None,
-                }
+                self.fold_integer_binary_op(op, *l, *r, &left.ty, source_info)
             }
-            
+
             // Float operations
             (ConstantValue::Float(l), ConstantValue::Float(r)) => {
-                match op {
+                Ok(match op {
                     BinOp::Add => Some(ConstantValue::Float(l + r)),
                     BinOp::Sub => Some(ConstantValue::Float(l - r)),
                     BinOp::Mul => Some(ConstantValue::Float(l * r)),
@@ -79,42 +319,64 @@ impl ConstantFoldingPass {
                     BinOp::Gt => Some(ConstantValue::Bool(l > r)),
                     BinOp::Ge => Some(ConstantValue::Bool(l >= r)),
                     _ => None,
-                }
+                })
             }
-            
+
             // Boolean operations
             (ConstantValue::Bool(l), ConstantValue::Bool(r)) => {
-                match op {
+                Ok(match op {
                     BinOp::Eq => Some(ConstantValue::Bool(l == r)),
                     BinOp::Ne => Some(ConstantValue::Bool(l != r)),
                     BinOp::BitAnd => Some(ConstantValue::Bool(*l && *r)),
                     BinOp::BitOr => Some(ConstantValue::Bool(*l || *r)),
                     BinOp::BitXor => Some(ConstantValue::Bool(*l ^ *r)),
                     _ => None,
-                }
+                })
             }
-            
+
             // String operations
             (ConstantValue::String(l), ConstantValue::String(r)) => {
-                match op {
+                Ok(match op {
                     BinOp::Eq => Some(ConstantValue::Bool(l == r)),
                     BinOp::Ne => Some(ConstantValue::Bool(l != r)),
                     BinOp::Add => Some(ConstantValue::String(format!("{}{}", l, r))),
                     _ => None,
-                }
+                })
             }
-            
-            _ => None,
+
+            _ => Ok(None),
         }
     }
-    
+
     /// Fold a unary operation on a constant
-    fn fold_unary_op(&self, op: UnOp, operand: &ConstantValue) -> Option<ConstantValue> {
-        match (op, operand) {
-            (UnOp::Not, ConstantValue::Bool(b)) => Some(ConstantValue::Bool(!b)),
-            (UnOp::Neg, ConstantValue::Integer(i)) => Some(ConstantValue::Integer(-i)),
-            (UnOp::Neg, ConstantValue::Float(f)) => Some(ConstantValue::Float(-f)),
-            _ => None,
+    fn fold_unary_op(
+        &self,
+        op: UnOp,
+        operand: &Constant,
+        source_info: &SourceInfo,
+    ) -> Result<Option<ConstantValue>, SemanticError> {
+        match (op, &operand.value) {
+            (UnOp::Not, ConstantValue::Bool(b)) => Ok(Some(ConstantValue::Bool(!b))),
+            (UnOp::Neg, ConstantValue::Integer(i)) => {
+                let (bit_width, signed) = match integer_width(&operand.ty) {
+                    Some(w) => w,
+                    None => return Ok(None),
+                };
+                let raw = -*i;
+                if self.overflow_checking {
+                    let (min, max) = integer_range(bit_width, signed);
+                    if raw < min || raw > max {
+                        return Err(SemanticError::InvalidOperation {
+                            operation: "Neg".to_string(),
+                            reason: "this arithmetic operation will overflow the target type".to_string(),
+                            location: source_info.span.clone(),
+                        });
+                    }
+                }
+                Ok(Some(ConstantValue::Integer(truncate_to_width(raw, bit_width, signed))))
+            }
+            (UnOp::Neg, ConstantValue::Float(f)) => Ok(Some(ConstantValue::Float(-f))),
+            _ => Ok(None),
         }
     }
     
@@ -128,25 +390,80 @@ impl ConstantFoldingPass {
         }
     }
     
+    /// Try every identity/absorbing-element rule that applies when exactly
+    /// one operand of `op` is a known constant - the cases pure both-sides
+    /// folding in [`Self::fold_binary_op`] can never reach, since a local
+    /// read is never itself a `Operand::Constant`. Only applies to
+    /// integer/bool operands: the same identities don't hold bit-for-bit
+    /// for floats (`x * 0.0` isn't `0` when `x` is NaN, and `x + 0.0` isn't
+    /// `x` when `x` is `-0.0`), so a float-typed constant is left alone.
+    fn simplify_identity(&self, op: BinOp, left: &Operand, right: &Operand) -> Option<Rvalue> {
+        let (constant, non_constant, constant_is_left) = match (left, right) {
+            (Operand::Constant(c), other) if !matches!(other, Operand::Constant(_)) => (c, other, true),
+            (other, Operand::Constant(c)) if !matches!(other, Operand::Constant(_)) => (c, other, false),
+            _ => return None,
+        };
+
+        if matches!(
+            constant.ty,
+            Type::Primitive(PrimitiveType::Float | PrimitiveType::Float32 | PrimitiveType::Float64)
+        ) {
+            return None;
+        }
+
+        let falsy = matches!(constant.value, ConstantValue::Integer(0) | ConstantValue::Bool(false));
+        let truthy = matches!(constant.value, ConstantValue::Bool(true));
+        let zero = matches!(constant.value, ConstantValue::Integer(0));
+        let one = matches!(constant.value, ConstantValue::Integer(1));
+        let constant_is_right = !constant_is_left;
+
+        let absorbs = matches!(op, BinOp::Mul if zero)
+            || matches!(op, BinOp::BitAnd if falsy)
+            || matches!(op, BinOp::BitOr if truthy);
+        if absorbs {
+            return Some(Rvalue::Use(Operand::Constant(constant.clone())));
+        }
+
+        let reduces = matches!(op, BinOp::Add if zero)
+            || matches!(op, BinOp::Mul if one)
+            || matches!(op, BinOp::BitOr if falsy)
+            || matches!(op, BinOp::BitAnd if truthy)
+            || matches!(op, BinOp::BitXor if falsy)
+            // Non-commutative: `0 - x` and `1 / x` aren't `x`, so these only
+            // reduce when the constant is the right-hand operand.
+            || matches!(op, BinOp::Sub if zero && constant_is_right)
+            || matches!(op, BinOp::Div if one && constant_is_right)
+            || matches!(op, BinOp::Shl if zero && constant_is_right)
+            || matches!(op, BinOp::Shr if zero && constant_is_right);
+        if reduces {
+            return Some(Rvalue::Use(non_constant.clone()));
+        }
+
+        None
+    }
+
     /// Optimize an rvalue
-    fn optimize_rvalue(&mut self, rvalue: &mut Rvalue) {
+    fn optimize_rvalue(&mut self, rvalue: &mut Rvalue, source_info: &SourceInfo) -> Result<(), SemanticError> {
         match rvalue {
             Rvalue::BinaryOp { op, left, right } => {
-                if let (Operand::Constant(left_const), Operand::Constant(right_const)) = (left, right) {
-                    if let Some(result) = self.fold_binary_op(*op, &left_const.value, &right_const.value) {
+                let folded = if let (Operand::Constant(left_const), Operand::Constant(right_const)) = (&*left, &*right) {
+                    self.fold_binary_op(*op, left_const, right_const, source_info)?.map(|result| {
                         let result_type = self.get_binary_result_type(*op, &left_const.ty);
-                        *rvalue = Rvalue::Use(Operand::Constant(Constant {
-                            ty: result_type,
-                            value: result,
-                        }));
-                        self.changed = true;
-                    }
+                        Rvalue::Use(Operand::Constant(Constant { ty: result_type, value: result }))
+                    })
+                } else {
+                    None
+                };
+
+                if let Some(new_rvalue) = folded.or_else(|| self.simplify_identity(*op, left, right)) {
+                    *rvalue = new_rvalue;
+                    self.changed = true;
                 }
             }
-            
+
             Rvalue::UnaryOp { op, operand } => {
                 if let Operand::Constant(const_operand) = operand {
-                    if let Some(result) = self.fold_unary_op(*op, &const_operand.value) {
+                    if let Some(result) = self.fold_unary_op(*op, const_operand, source_info)? {
                         *rvalue = Rvalue::Use(Operand::Constant(Constant {
                             ty: const_operand.ty.clone(),
                             value: result,
@@ -155,9 +472,10 @@ impl ConstantFoldingPass {
                     }
                 }
             }
-            
+
             _ => {}
         }
+        Ok(())
     }
 }
 
@@ -165,18 +483,76 @@ impl OptimizationPass for ConstantFoldingPass {
     fn name(&self) -> &'static str {
         "constant-folding"
     }
-    
+
     fn run_on_function(&mut self, function: &mut Function) -> Result<bool, SemanticError> {
         self.changed = false;
-        
+
         for block in function.basic_blocks.values_mut() {
+            // Tracked per block only: a value recorded here may not hold by
+            // the time control flow reaches another block, and we don't
+            // have a full CFG dataflow to thread it along predecessors.
+            let mut known_constants: HashMap<LocalId, Constant> = HashMap::new();
+
             for statement in &mut block.statements {
-                if let Statement::Assign { rvalue, .. } = statement {
-                    self.optimize_rvalue(rvalue);
+                match statement {
+                    Statement::Assign { place, rvalue, source_info } => {
+                        substitute_known_constants(&known_constants, rvalue, source_info)?;
+                        self.optimize_rvalue(rvalue, source_info)?;
+
+                        // Taking a local's address means a later store
+                        // through that pointer could change it without ever
+                        // reassigning `place` directly, so stop trusting
+                        // whatever we'd recorded for it.
+                        if let Rvalue::Ref { place: referenced, .. } = rvalue {
+                            known_constants.remove(&referenced.local);
+                        }
+
+                        if place.projection.is_empty() {
+                            match rvalue {
+                                Rvalue::Use(Operand::Constant(constant)) => {
+                                    known_constants.insert(place.local, constant.clone());
+                                }
+                                Rvalue::Aggregate { kind, operands }
+                                    if operands.iter().all(|operand| matches!(operand, Operand::Constant(_))) =>
+                                {
+                                    let elements: Vec<ConstantValue> = operands
+                                        .iter()
+                                        .map(|operand| match operand {
+                                            Operand::Constant(c) => c.value.clone(),
+                                            _ => unreachable!("checked above"),
+                                        })
+                                        .collect();
+                                    let ty = function
+                                        .locals
+                                        .get(&place.local)
+                                        .map(|local| local.ty.clone())
+                                        .unwrap_or_else(|| Type::primitive(PrimitiveType::Void));
+                                    let value = if matches!(kind, AggregateKind::Tuple) {
+                                        ConstantValue::Tuple(elements)
+                                    } else {
+                                        ConstantValue::Array(elements)
+                                    };
+                                    known_constants.insert(place.local, Constant { ty, value });
+                                }
+                                _ => {
+                                    known_constants.remove(&place.local);
+                                }
+                            }
+                        } else {
+                            // A write through a projection only updates part
+                            // of the local, so the previously tracked whole
+                            // value is no longer valid.
+                            known_constants.remove(&place.local);
+                        }
+                    }
+                    Statement::StorageDead(local) => {
+                        known_constants.remove(local);
+                    }
+                    _ => {}
                 }
             }
         }
-        
+
         Ok(self.changed)
     }
 }
@@ -350,4 +726,332 @@ mod tests {
             panic!("Expected assignment statement");
         }
     }
+
+    #[test]
+    fn test_identity_reduction_with_one_constant_operand() {
+        let pass = ConstantFoldingPass::new();
+        let x = Operand::Copy(Place { local: 0, projection: vec![] });
+        let zero = Operand::Constant(Constant { ty: Type::primitive(PrimitiveType::Integer), value: ConstantValue::Integer(0) });
+
+        // x + 0 -> x
+        let simplified = pass.simplify_identity(BinOp::Add, &x, &zero).expect("x + 0 should simplify to x");
+        assert_eq!(simplified, Rvalue::Use(x.clone()));
+
+        // 0 - x should NOT simplify to x (subtraction isn't commutative)
+        assert!(pass.simplify_identity(BinOp::Sub, &zero, &x).is_none());
+
+        // x - 0 -> x
+        let simplified = pass.simplify_identity(BinOp::Sub, &x, &zero).expect("x - 0 should simplify to x");
+        assert_eq!(simplified, Rvalue::Use(x));
+    }
+
+    #[test]
+    fn test_identity_absorbing_element_with_one_constant_operand() {
+        let pass = ConstantFoldingPass::new();
+        let x = Operand::Copy(Place { local: 0, projection: vec![] });
+        let zero = Operand::Constant(Constant { ty: Type::primitive(PrimitiveType::Integer), value: ConstantValue::Integer(0) });
+
+        // x * 0 -> 0, regardless of which side the constant is on
+        assert_eq!(pass.simplify_identity(BinOp::Mul, &x, &zero), Some(Rvalue::Use(zero.clone())));
+        assert_eq!(pass.simplify_identity(BinOp::Mul, &zero, &x), Some(Rvalue::Use(zero)));
+    }
+
+    #[test]
+    fn test_identity_simplification_gated_out_for_float_operands() {
+        let pass = ConstantFoldingPass::new();
+        let x = Operand::Copy(Place { local: 0, projection: vec![] });
+        let zero = Operand::Constant(Constant { ty: Type::primitive(PrimitiveType::Float), value: ConstantValue::Float(0.0) });
+
+        // x + 0.0 must NOT simplify to x: it would be wrong when x is -0.0.
+        assert!(pass.simplify_identity(BinOp::Add, &x, &zero).is_none());
+        // x * 0.0 must NOT simplify to 0.0 either: it would be wrong when x is NaN.
+        assert!(pass.simplify_identity(BinOp::Mul, &x, &zero).is_none());
+    }
+
+    #[test]
+    fn test_run_on_function_applies_identity_simplification() {
+        let mut pass = ConstantFoldingPass::new();
+        let mut builder = Builder::new();
+
+        builder.start_function("test".to_string(), vec![], Type::primitive(PrimitiveType::Integer));
+
+        let x = builder.new_local(Type::primitive(PrimitiveType::Integer), false);
+        let temp = builder.new_local(Type::primitive(PrimitiveType::Integer), false);
+
+        // temp = x | false
+        builder.push_statement(Statement::Assign {
+            place: Place { local: temp, projection: vec![] },
+            rvalue: Rvalue::BinaryOp {
+                op: BinOp::BitOr,
+                left: Operand::Copy(Place { local: x, projection: vec![] }),
+                right: Operand::Constant(Constant { ty: Type::primitive(PrimitiveType::Boolean), value: ConstantValue::Bool(false) }),
+            },
+            source_info: SourceInfo { span: SourceLocation::unknown(), scope: 0 },
+        });
+
+        let mut function = builder.finish_function();
+
+        let changed = pass.run_on_function(&mut function).unwrap();
+        assert!(changed);
+
+        let block = function.basic_blocks.values().next().unwrap();
+        match &block.statements[0] {
+            Statement::Assign { rvalue: Rvalue::Use(Operand::Copy(place)), .. } => {
+                assert_eq!(place.local, x, "x | false should simplify to x");
+            }
+            other => panic!("expected a simplified Use, got {other:?}"),
+        }
+    }
+
+    fn i8_constant(value: i128) -> Constant {
+        Constant { ty: Type::primitive(PrimitiveType::I8), value: ConstantValue::Integer(value) }
+    }
+
+    fn dummy_source_info() -> SourceInfo {
+        SourceInfo { span: SourceLocation::unknown(), scope: 0 }
+    }
+
+    #[test]
+    fn test_integer_folding_truncates_to_declared_width() {
+        let pass = ConstantFoldingPass::new();
+        let source_info = dummy_source_info();
+
+        // 120i8 + 10i8 overflows i8's range but wraps to -126 in non-checking mode.
+        let result = pass
+            .fold_binary_op(BinOp::Add, &i8_constant(120), &i8_constant(10), &source_info)
+            .unwrap();
+        assert_eq!(result, Some(ConstantValue::Integer(-126)));
+    }
+
+    #[test]
+    fn test_overflow_checking_mode_rejects_guaranteed_overflow() {
+        let pass = ConstantFoldingPass::new().with_overflow_checking(true);
+        let source_info = dummy_source_info();
+
+        let result = pass.fold_binary_op(BinOp::Add, &i8_constant(120), &i8_constant(10), &source_info);
+        assert!(matches!(result, Err(SemanticError::InvalidOperation { .. })));
+    }
+
+    #[test]
+    fn test_division_by_zero_declines_to_fold_by_default_but_errors_when_checked() {
+        let source_info = dummy_source_info();
+
+        let lenient = ConstantFoldingPass::new();
+        assert_eq!(
+            lenient.fold_binary_op(BinOp::Div, &i8_constant(10), &i8_constant(0), &source_info).unwrap(),
+            None
+        );
+
+        let strict = ConstantFoldingPass::new().with_overflow_checking(true);
+        assert!(matches!(
+            strict.fold_binary_op(BinOp::Div, &i8_constant(10), &i8_constant(0), &source_info),
+            Err(SemanticError::InvalidOperation { .. })
+        ));
+    }
+
+    #[test]
+    fn test_shift_amount_exceeding_bit_width_declines_to_fold() {
+        let pass = ConstantFoldingPass::new();
+        let source_info = dummy_source_info();
+
+        // Shifting an i8 by 8 or more isn't well-defined, so the fold must
+        // decline rather than masking the amount like the old `& 63` did.
+        let result = pass.fold_binary_op(BinOp::Shl, &i8_constant(1), &i8_constant(8), &source_info).unwrap();
+        assert_eq!(result, None);
+    }
+
+    #[test]
+    fn test_constant_propagation_folds_chained_local_definitions() {
+        let mut pass = ConstantFoldingPass::new();
+        let mut builder = Builder::new();
+
+        builder.start_function("test".to_string(), vec![], Type::primitive(PrimitiveType::Integer));
+
+        let a = builder.new_local(Type::primitive(PrimitiveType::Integer), false);
+        let b = builder.new_local(Type::primitive(PrimitiveType::Integer), false);
+
+        // a = 2
+        builder.push_statement(Statement::Assign {
+            place: Place { local: a, projection: vec![] },
+            rvalue: Rvalue::Use(Operand::Constant(Constant {
+                ty: Type::primitive(PrimitiveType::Integer),
+                value: ConstantValue::Integer(2),
+            })),
+            source_info: dummy_source_info(),
+        });
+
+        // b = a + 3
+        builder.push_statement(Statement::Assign {
+            place: Place { local: b, projection: vec![] },
+            rvalue: Rvalue::BinaryOp {
+                op: BinOp::Add,
+                left: Operand::Copy(Place { local: a, projection: vec![] }),
+                right: Operand::Constant(Constant {
+                    ty: Type::primitive(PrimitiveType::Integer),
+                    value: ConstantValue::Integer(3),
+                }),
+            },
+            source_info: dummy_source_info(),
+        });
+
+        let mut function = builder.finish_function();
+
+        let changed = pass.run_on_function(&mut function).unwrap();
+        assert!(changed);
+
+        let block = function.basic_blocks.values().next().unwrap();
+        match &block.statements[1] {
+            Statement::Assign { rvalue: Rvalue::Use(Operand::Constant(constant)), .. } => {
+                assert_eq!(constant.value, ConstantValue::Integer(5));
+            }
+            other => panic!("expected `b = a + 3` to fold via propagated `a`, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_constant_propagation_invalidated_by_reassignment() {
+        let mut pass = ConstantFoldingPass::new();
+        let mut builder = Builder::new();
+
+        builder.start_function("test".to_string(), vec![], Type::primitive(PrimitiveType::Integer));
+
+        let a = builder.new_local(Type::primitive(PrimitiveType::Integer), false);
+        let param = builder.new_local(Type::primitive(PrimitiveType::Integer), false);
+        let b = builder.new_local(Type::primitive(PrimitiveType::Integer), false);
+
+        // a = 2
+        builder.push_statement(Statement::Assign {
+            place: Place { local: a, projection: vec![] },
+            rvalue: Rvalue::Use(Operand::Constant(Constant {
+                ty: Type::primitive(PrimitiveType::Integer),
+                value: ConstantValue::Integer(2),
+            })),
+            source_info: dummy_source_info(),
+        });
+
+        // a = param (no longer a known constant)
+        builder.push_statement(Statement::Assign {
+            place: Place { local: a, projection: vec![] },
+            rvalue: Rvalue::Use(Operand::Copy(Place { local: param, projection: vec![] })),
+            source_info: dummy_source_info(),
+        });
+
+        // b = a + 3: must NOT fold, since `a` was reassigned to a non-constant.
+        builder.push_statement(Statement::Assign {
+            place: Place { local: b, projection: vec![] },
+            rvalue: Rvalue::BinaryOp {
+                op: BinOp::Add,
+                left: Operand::Copy(Place { local: a, projection: vec![] }),
+                right: Operand::Constant(Constant {
+                    ty: Type::primitive(PrimitiveType::Integer),
+                    value: ConstantValue::Integer(3),
+                }),
+            },
+            source_info: dummy_source_info(),
+        });
+
+        let mut function = builder.finish_function();
+        pass.run_on_function(&mut function).unwrap();
+
+        let block = function.basic_blocks.values().next().unwrap();
+        match &block.statements[2] {
+            Statement::Assign { rvalue: Rvalue::BinaryOp { left, .. }, .. } => {
+                assert!(matches!(left, Operand::Copy(_)), "reassigned `a` must not still be treated as constant");
+            }
+            other => panic!("expected statement to remain a BinaryOp, got {other:?}"),
+        }
+    }
+
+    fn push_array_of_constants(builder: &mut Builder, place_local: u32, values: &[i128]) {
+        builder.push_statement(Statement::Assign {
+            place: Place { local: place_local, projection: vec![] },
+            rvalue: Rvalue::Aggregate {
+                kind: AggregateKind::Array(Type::primitive(PrimitiveType::Integer)),
+                operands: values
+                    .iter()
+                    .map(|v| {
+                        Operand::Constant(Constant {
+                            ty: Type::primitive(PrimitiveType::Integer),
+                            value: ConstantValue::Integer(*v),
+                        })
+                    })
+                    .collect(),
+            },
+            source_info: dummy_source_info(),
+        });
+    }
+
+    fn push_integer_constant(builder: &mut Builder, place_local: u32, value: i128) {
+        builder.push_statement(Statement::Assign {
+            place: Place { local: place_local, projection: vec![] },
+            rvalue: Rvalue::Use(Operand::Constant(Constant {
+                ty: Type::primitive(PrimitiveType::Integer),
+                value: ConstantValue::Integer(value),
+            })),
+            source_info: dummy_source_info(),
+        });
+    }
+
+    #[test]
+    fn test_constant_array_index_folds_to_element() {
+        let mut pass = ConstantFoldingPass::new();
+        let mut builder = Builder::new();
+
+        builder.start_function("test".to_string(), vec![], Type::primitive(PrimitiveType::Integer));
+
+        let arr = builder.new_local(Type::array(Type::primitive(PrimitiveType::Integer), Some(3)), false);
+        let idx = builder.new_local(Type::primitive(PrimitiveType::Integer), false);
+        let out = builder.new_local(Type::primitive(PrimitiveType::Integer), false);
+
+        push_array_of_constants(&mut builder, arr, &[10, 20, 30]);
+        push_integer_constant(&mut builder, idx, 1);
+
+        builder.push_statement(Statement::Assign {
+            place: Place { local: out, projection: vec![] },
+            rvalue: Rvalue::Use(Operand::Copy(Place { local: arr, projection: vec![PlaceElem::Index(idx)] })),
+            source_info: dummy_source_info(),
+        });
+
+        let mut function = builder.finish_function();
+        pass.run_on_function(&mut function).unwrap();
+
+        let block = function.basic_blocks.values().next().unwrap();
+        match &block.statements[2] {
+            Statement::Assign { rvalue: Rvalue::Use(Operand::Constant(constant)), .. } => {
+                assert_eq!(constant.value, ConstantValue::Integer(20));
+            }
+            other => panic!("expected `arr[1]` to fold to the element constant, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_constant_array_out_of_bounds_index_is_an_error() {
+        let mut pass = ConstantFoldingPass::new();
+        let mut builder = Builder::new();
+
+        builder.start_function("test".to_string(), vec![], Type::primitive(PrimitiveType::Integer));
+
+        let arr = builder.new_local(Type::array(Type::primitive(PrimitiveType::Integer), Some(3)), false);
+        let idx = builder.new_local(Type::primitive(PrimitiveType::Integer), false);
+        let out = builder.new_local(Type::primitive(PrimitiveType::Integer), false);
+
+        push_array_of_constants(&mut builder, arr, &[10, 20, 30]);
+        push_integer_constant(&mut builder, idx, 5);
+
+        builder.push_statement(Statement::Assign {
+            place: Place { local: out, projection: vec![] },
+            rvalue: Rvalue::Use(Operand::Copy(Place { local: arr, projection: vec![PlaceElem::Index(idx)] })),
+            source_info: dummy_source_info(),
+        });
+
+        let mut function = builder.finish_function();
+        let result = pass.run_on_function(&mut function);
+
+        match result {
+            Err(SemanticError::InvalidOperation { reason, .. }) => {
+                assert_eq!(reason, "index out of bounds: the length is 3 but the index is 5");
+            }
+            other => panic!("expected an out-of-bounds InvalidOperation error, got {other:?}"),
+        }
+    }
 }
\ No newline at end of file
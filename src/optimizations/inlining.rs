@@ -1,147 +1,682 @@
 //! Function inlining optimization pass
-//! 
+//!
 //! Inlines small functions to reduce call overhead
 
 use super::OptimizationPass;
-use std::collections::HashSet;
-use crate::mir::{Function, Program, Statement, Terminator, Rvalue, Operand, Place, LocalId,
-                 BasicBlockId, SourceInfo};
-use crate::error::SemanticError;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use crate::mir::{
+    AssertMessage, BasicBlock, BasicBlockId, Function, LocalId, Operand, Place, PlaceElem,
+    Program, Rvalue, Statement, SourceInfo, SwitchTargets, Terminator,
+};
+use crate::error::{SemanticError, SourceLocation};
 
 /// Function inlining optimization pass
 #[derive(Debug)]
 pub struct InliningPass {
     /// Inlining threshold (e.g., number of statements)
     threshold: usize,
-    
+
     /// Functions already inlined to prevent recursion
     inlined_functions: HashSet<String>,
+
+    /// Functions that participate in a recursive cycle (direct or mutual),
+    /// as determined by the whole-program call graph. Recomputed at the
+    /// start of each [`OptimizationPass::run_on_program`]; empty otherwise.
+    recursive_functions: HashSet<String>,
+
+    /// Functions to always inline regardless of cost, short of recursion
+    /// (the caller's equivalent of `#[inline(always)]`).
+    always_inline: HashSet<String>,
+
+    /// Functions to never inline regardless of cost (`#[inline(never)]`).
+    never_inline: HashSet<String>,
+
+    /// Growth limits applied while splicing callees into callers.
+    budget: InlineBudget,
+
+    /// Running total of statements spliced in across every call to
+    /// [`OptimizationPass::run_on_program`] for the lifetime of this pass,
+    /// checked against `budget.max_total_statements`.
+    total_inlined_statements: usize,
+}
+
+/// Growth limits for the inlining pass, preventing repeated splicing of the
+/// same callees (e.g. inside loops or deep call chains) from exploding code
+/// size and compile time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InlineBudget {
+    /// Maximum size a caller may grow to, expressed as a multiple of its
+    /// original statement count. A caller that starts very small is still
+    /// allowed to absorb at least one typical-size callee (see
+    /// [`InliningPass::caller_growth_cap`]).
+    pub max_growth_factor: usize,
+
+    /// Program-wide ceiling on the total number of statements that may be
+    /// spliced in across all callers.
+    pub max_total_statements: usize,
+}
+
+impl Default for InlineBudget {
+    fn default() -> Self {
+        Self {
+            max_growth_factor: 4,
+            max_total_statements: 10_000,
+        }
+    }
 }
 
+/// Extra cost charged per call/invoke-style terminator, modeling the
+/// overhead of the call instruction itself plus argument marshalling.
+const CALL_PENALTY: usize = 5;
+
+/// Extra cost charged on top of [`CALL_PENALTY`] when a call/drop/assert has
+/// an unwind (`cleanup`) edge, modeling the extra landing-pad bookkeeping
+/// that comes along with it.
+const UNWIND_PENALTY: usize = 3;
+
 impl InliningPass {
     pub fn new() -> Self {
         Self {
             threshold: 20,
             inlined_functions: HashSet::new(),
+            recursive_functions: HashSet::new(),
+            always_inline: HashSet::new(),
+            never_inline: HashSet::new(),
+            budget: InlineBudget::default(),
+            total_inlined_statements: 0,
         }
     }
-    
+
     /// Set the maximum size for inlining
     pub fn set_max_inline_size(&mut self, size: usize) {
         self.threshold = size;
     }
-    
+
     /// Set the maximum inlining depth
-    pub fn set_max_inline_depth(&mut self, depth: usize) {
+    ///
+    /// The value is ignored: the pass does not track how deeply inlined
+    /// call sites nest. Growth is bounded by the size threshold, the
+    /// per-run splice limit and the [`InlineBudget`] instead.
+    pub fn set_max_inline_depth(&mut self, _depth: usize) {
     }
-    
+
+    /// Configure the growth limits applied during inlining: `max_growth_factor`
+    /// bounds how large a single caller may grow relative to its original
+    /// size, and `max_total_statements` bounds the program-wide total number
+    /// of statements spliced in over the lifetime of this pass.
+    pub fn set_inline_budget(&mut self, max_growth_factor: usize, max_total_statements: usize) {
+        self.budget = InlineBudget {
+            max_growth_factor,
+            max_total_statements,
+        };
+    }
+
+    /// The maximum number of statements `caller` may grow by before this
+    /// pass stops inlining further call sites into it. A caller is always
+    /// allowed to grow by at least `threshold` statements, so that a small
+    /// or empty caller can still absorb one typical-size callee.
+    fn caller_growth_cap(&self, original_size: usize) -> usize {
+        original_size
+            .saturating_mul(self.budget.max_growth_factor)
+            .max(self.threshold)
+    }
+
+    /// Hint that `name` should always be inlined (cost permitting recursion
+    /// safety), overriding the size threshold.
+    pub fn mark_always_inline(&mut self, name: String) {
+        self.never_inline.remove(&name);
+        self.always_inline.insert(name);
+    }
+
+    /// Hint that `name` should never be inlined, overriding the cost model.
+    pub fn mark_never_inline(&mut self, name: String) {
+        self.always_inline.remove(&name);
+        self.never_inline.insert(name);
+    }
+
     /// Calculate the "cost" of a function for inlining decisions
     fn calculate_function_cost(&self, function: &Function) -> usize {
         let mut cost = 0;
-        
+
         for block in function.basic_blocks.values() {
             cost += block.statements.len();
-            
-            // Add cost for complex terminators
+
+            // Add cost for complex terminators, including the overhead of
+            // calls and of any unwind (cleanup) edge they carry.
             match &block.terminator {
-                Terminator::Call { .. } => cost += 5, // Calls are expensive
+                Terminator::Call { cleanup, .. } => {
+                    cost += CALL_PENALTY;
+                    if cleanup.is_some() {
+                        cost += UNWIND_PENALTY;
+                    }
+                }
+                Terminator::Drop { unwind, .. } | Terminator::Assert { cleanup: unwind, .. } => {
+                    cost += 1;
+                    if unwind.is_some() {
+                        cost += UNWIND_PENALTY;
+                    }
+                }
                 Terminator::SwitchInt { .. } => cost += 2, // Branches have some cost
                 _ => cost += 1,
             }
         }
-        
+
         cost
     }
-    
-    /// Check if a function is suitable for inlining
-    fn should_inline(&self, function: &Function) -> bool {
-        // Don't inline recursive functions (basic check)
-        if self.has_recursive_calls(function) {
-            return false;
+
+    /// Decide whether `function` is suitable for inlining, and why.
+    ///
+    /// Checks are ordered cheapest-first so that the common, trivially
+    /// ineligible cases (hinted never-inline, recursive, bodyless) short
+    /// circuit before the per-block cost traversal runs.
+    fn inline_decision(&self, function: &Function) -> InlineDecision {
+        if self.never_inline.contains(&function.name) {
+            return InlineDecision::Never;
         }
-        
-        // Check size constraints
+
+        // No body to inline (e.g. an extern/FFI declaration represented as
+        // a bodyless function).
+        if function.basic_blocks.is_empty() {
+            return InlineDecision::Never;
+        }
+
+        // Don't inline functions that participate in a recursive cycle,
+        // whether self-recursion or mutual recursion through other
+        // functions (see `recursive_functions`, built from the call graph).
+        if self.recursive_functions.contains(&function.name) {
+            return InlineDecision::Never;
+        }
+
+        if self.always_inline.contains(&function.name) {
+            return InlineDecision::Always;
+        }
+
+        // Only now run the (relatively) expensive per-block cost walk.
         let cost = self.calculate_function_cost(function);
-        cost <= self.threshold
+        if cost <= self.threshold {
+            InlineDecision::CostBased(cost)
+        } else {
+            InlineDecision::Never
+        }
     }
-    
-    /// Basic check for recursive calls
-    fn has_recursive_calls(&self, function: &Function) -> bool {
-        for block in function.basic_blocks.values() {
-            for statement in &block.statements {
-                if let Statement::Assign { rvalue: Rvalue::Call { func, .. }, .. } = statement {
-                    if let Operand::Constant(_constant) = func {
-                        // In a real implementation, we'd check if the constant refers to the same function
-                        // For now, just assume no recursion
+
+    /// Check if a function is suitable for inlining
+    fn should_inline(&self, function: &Function) -> bool {
+        !matches!(self.inline_decision(function), InlineDecision::Never)
+    }
+}
+
+/// The outcome of an inlining eligibility check, and why it was reached.
+/// See [`InliningPass::inline_decision`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InlineDecision {
+    /// Always inline regardless of cost (an always-inline hint applied).
+    Always,
+    /// Eligible based on the cost model; carries the computed cost.
+    CostBased(usize),
+    /// Not eligible for inlining.
+    Never,
+}
+
+/// Directed graph of direct call edges between a program's functions, used to
+/// find recursive cycles (including mutual recursion) before deciding what's
+/// safe to inline.
+#[derive(Debug, Default)]
+struct CallGraph {
+    edges: HashMap<String, HashSet<String>>,
+}
+
+impl CallGraph {
+    /// Build the call graph from every `Rvalue::Call`/`Terminator::Call` in
+    /// the program whose target resolves to another function defined in it.
+    fn build(program: &Program) -> Self {
+        let mut edges: HashMap<String, HashSet<String>> = HashMap::new();
+
+        for (caller_name, function) in &program.functions {
+            let callees = edges.entry(caller_name.clone()).or_default();
+
+            for block in function.basic_blocks.values() {
+                for statement in &block.statements {
+                    if let Statement::Assign { rvalue: Rvalue::Call { func, .. }, .. } = statement {
+                        if let Some(callee) = call_target_name(func) {
+                            if program.functions.contains_key(callee) {
+                                callees.insert(callee.to_string());
+                            }
+                        }
+                    }
+                }
+
+                if let Terminator::Call { func, .. } = &block.terminator {
+                    if let Some(callee) = call_target_name(func) {
+                        if program.functions.contains_key(callee) {
+                            callees.insert(callee.to_string());
+                        }
                     }
                 }
             }
-            
-            if let Terminator::Call { func, .. } = &block.terminator {
-                if let Operand::Constant(_constant) = func {
-                    // Same as above - in practice we'd need better function identification
+        }
+
+        Self { edges }
+    }
+
+    /// Names of every function that is part of a recursive cycle: a strongly
+    /// connected component with more than one member, or a single function
+    /// with a direct self-edge.
+    fn recursive_functions(&self) -> HashSet<String> {
+        let mut finder = TarjanSccFinder::new(&self.edges);
+        let mut recursive = HashSet::new();
+
+        for component in finder.run() {
+            let is_cycle = component.len() > 1
+                || self
+                    .edges
+                    .get(&component[0])
+                    .is_some_and(|callees| callees.contains(&component[0]));
+
+            if is_cycle {
+                recursive.extend(component);
+            }
+        }
+
+        recursive
+    }
+}
+
+/// Tarjan's strongly-connected-components algorithm over the call graph.
+struct TarjanSccFinder<'a> {
+    edges: &'a HashMap<String, HashSet<String>>,
+    index_counter: usize,
+    index: HashMap<String, usize>,
+    lowlink: HashMap<String, usize>,
+    on_stack: HashSet<String>,
+    stack: Vec<String>,
+    components: Vec<Vec<String>>,
+}
+
+impl<'a> TarjanSccFinder<'a> {
+    fn new(edges: &'a HashMap<String, HashSet<String>>) -> Self {
+        Self {
+            edges,
+            index_counter: 0,
+            index: HashMap::new(),
+            lowlink: HashMap::new(),
+            on_stack: HashSet::new(),
+            stack: Vec::new(),
+            components: Vec::new(),
+        }
+    }
+
+    fn run(&mut self) -> Vec<Vec<String>> {
+        let names: Vec<String> = self.edges.keys().cloned().collect();
+        for name in names {
+            if !self.index.contains_key(&name) {
+                self.strong_connect(name);
+            }
+        }
+        std::mem::take(&mut self.components)
+    }
+
+    fn strong_connect(&mut self, name: String) {
+        self.index.insert(name.clone(), self.index_counter);
+        self.lowlink.insert(name.clone(), self.index_counter);
+        self.index_counter += 1;
+        self.stack.push(name.clone());
+        self.on_stack.insert(name.clone());
+
+        let callees = self.edges.get(&name).cloned().unwrap_or_default();
+        for callee in callees {
+            if !self.index.contains_key(&callee) {
+                self.strong_connect(callee.clone());
+                let callee_lowlink = self.lowlink[&callee];
+                let entry = self.lowlink.get_mut(&name).unwrap();
+                *entry = (*entry).min(callee_lowlink);
+            } else if self.on_stack.contains(&callee) {
+                let callee_index = self.index[&callee];
+                let entry = self.lowlink.get_mut(&name).unwrap();
+                *entry = (*entry).min(callee_index);
+            }
+        }
+
+        if self.lowlink[&name] == self.index[&name] {
+            let mut component = Vec::new();
+            loop {
+                let member = self.stack.pop().unwrap();
+                self.on_stack.remove(&member);
+                let is_root = member == name;
+                component.push(member);
+                if is_root {
+                    break;
+                }
+            }
+            self.components.push(component);
+        }
+    }
+}
+
+/// If `operand` is a constant naming a function (the representation used for
+/// call targets in this MIR), return that name.
+fn call_target_name(operand: &Operand) -> Option<&str> {
+    match operand {
+        Operand::Constant(constant) => match &constant.value {
+            crate::mir::ConstantValue::String(name) => Some(name.as_str()),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+fn remap_local(id: LocalId, map: &HashMap<LocalId, LocalId>) -> LocalId {
+    *map.get(&id).unwrap_or(&id)
+}
+
+fn remap_place(place: &Place, map: &HashMap<LocalId, LocalId>) -> Place {
+    Place {
+        local: remap_local(place.local, map),
+        projection: place
+            .projection
+            .iter()
+            .map(|elem| match elem {
+                PlaceElem::Index(local) => PlaceElem::Index(remap_local(*local, map)),
+                other => other.clone(),
+            })
+            .collect(),
+    }
+}
+
+fn remap_operand(operand: &Operand, map: &HashMap<LocalId, LocalId>) -> Operand {
+    match operand {
+        Operand::Copy(place) => Operand::Copy(remap_place(place, map)),
+        Operand::Move(place) => Operand::Move(remap_place(place, map)),
+        Operand::Constant(constant) => Operand::Constant(constant.clone()),
+    }
+}
+
+fn remap_rvalue(rvalue: &Rvalue, map: &HashMap<LocalId, LocalId>) -> Rvalue {
+    match rvalue {
+        Rvalue::Use(op) => Rvalue::Use(remap_operand(op, map)),
+        Rvalue::BinaryOp { op, left, right } => Rvalue::BinaryOp {
+            op: *op,
+            left: remap_operand(left, map),
+            right: remap_operand(right, map),
+        },
+        Rvalue::UnaryOp { op, operand } => Rvalue::UnaryOp {
+            op: *op,
+            operand: remap_operand(operand, map),
+        },
+        Rvalue::Call { func, args } => Rvalue::Call {
+            func: remap_operand(func, map),
+            args: args.iter().map(|a| remap_operand(a, map)).collect(),
+        },
+        Rvalue::Aggregate { kind, operands } => Rvalue::Aggregate {
+            kind: kind.clone(),
+            operands: operands.iter().map(|o| remap_operand(o, map)).collect(),
+        },
+        Rvalue::Cast { kind, operand, ty } => Rvalue::Cast {
+            kind: *kind,
+            operand: remap_operand(operand, map),
+            ty: ty.clone(),
+        },
+        Rvalue::Ref { place, mutability } => Rvalue::Ref {
+            place: remap_place(place, map),
+            mutability: *mutability,
+        },
+        Rvalue::Len(place) => Rvalue::Len(remap_place(place, map)),
+        Rvalue::Discriminant(place) => Rvalue::Discriminant(remap_place(place, map)),
+    }
+}
+
+fn remap_statement(statement: &Statement, map: &HashMap<LocalId, LocalId>) -> Statement {
+    match statement {
+        Statement::Assign { place, rvalue, source_info } => Statement::Assign {
+            place: remap_place(place, map),
+            rvalue: remap_rvalue(rvalue, map),
+            source_info: source_info.clone(),
+        },
+        Statement::StorageLive(local) => Statement::StorageLive(remap_local(*local, map)),
+        Statement::StorageDead(local) => Statement::StorageDead(remap_local(*local, map)),
+        Statement::Nop => Statement::Nop,
+    }
+}
+
+fn remap_assert_message(message: &AssertMessage, map: &HashMap<LocalId, LocalId>) -> AssertMessage {
+    match message {
+        AssertMessage::BoundsCheck { len, index } => AssertMessage::BoundsCheck {
+            len: remap_operand(len, map),
+            index: remap_operand(index, map),
+        },
+        AssertMessage::Overflow(op, left, right) => {
+            AssertMessage::Overflow(*op, remap_operand(left, map), remap_operand(right, map))
+        }
+        AssertMessage::DivisionByZero(op) => AssertMessage::DivisionByZero(remap_operand(op, map)),
+        AssertMessage::RemainderByZero(op) => AssertMessage::RemainderByZero(remap_operand(op, map)),
+        AssertMessage::Custom(message) => AssertMessage::Custom(message.clone()),
+    }
+}
+
+/// Remap everything in a non-`Return` terminator. `Return` needs special
+/// handling by the caller since it turns into a value-binding assignment
+/// plus a `Goto` to the call's continuation block.
+fn remap_terminator(
+    terminator: &Terminator,
+    local_map: &HashMap<LocalId, LocalId>,
+    block_map: &HashMap<BasicBlockId, BasicBlockId>,
+) -> Terminator {
+    match terminator {
+        Terminator::Goto { target } => Terminator::Goto { target: block_map[target] },
+        Terminator::SwitchInt { discriminant, switch_ty, targets } => Terminator::SwitchInt {
+            discriminant: remap_operand(discriminant, local_map),
+            switch_ty: switch_ty.clone(),
+            targets: SwitchTargets {
+                values: targets.values.clone(),
+                targets: targets.targets.iter().map(|t| block_map[t]).collect(),
+                otherwise: block_map[&targets.otherwise],
+            },
+        },
+        Terminator::Return => Terminator::Return,
+        Terminator::Unreachable => Terminator::Unreachable,
+        Terminator::Call { func, args, destination, target, cleanup } => Terminator::Call {
+            func: remap_operand(func, local_map),
+            args: args.iter().map(|a| remap_operand(a, local_map)).collect(),
+            destination: remap_place(destination, local_map),
+            target: target.map(|t| block_map[&t]),
+            cleanup: cleanup.map(|t| block_map[&t]),
+        },
+        Terminator::Drop { place, target, unwind } => Terminator::Drop {
+            place: remap_place(place, local_map),
+            target: block_map[target],
+            unwind: unwind.map(|t| block_map[&t]),
+        },
+        Terminator::Assert { condition, expected, message, target, cleanup } => Terminator::Assert {
+            condition: remap_operand(condition, local_map),
+            expected: *expected,
+            message: remap_assert_message(message, local_map),
+            target: block_map[target],
+            cleanup: cleanup.map(|t| block_map[&t]),
+        },
+    }
+}
+
+/// Splice `callee`'s basic blocks into `caller` at `call_block`, replacing
+/// its `Terminator::Call` with a `Goto` into the (freshly-renumbered) callee
+/// body, and rewriting every `Return` in that body into an assignment to the
+/// call's destination place followed by a `Goto` back to the call's
+/// continuation block.
+fn inline_call_at(caller: &mut Function, call_block: BasicBlockId, callee: &Function) {
+    let (args, destination, target) = match caller.basic_blocks[&call_block].terminator.clone() {
+        Terminator::Call { args, destination, target, .. } => (args, destination, target),
+        _ => return,
+    };
+
+    let local_base = caller.locals.keys().copied().max().map_or(0, |m| m + 1);
+    let block_base = caller.basic_blocks.keys().copied().max().map_or(0, |m| m + 1);
+
+    let mut local_map = HashMap::new();
+    for (&old_id, local) in &callee.locals {
+        let new_id = local_base + old_id;
+        local_map.insert(old_id, new_id);
+        caller.locals.insert(new_id, local.clone());
+    }
+
+    let mut block_map = HashMap::new();
+    for &old_id in callee.basic_blocks.keys() {
+        block_map.insert(old_id, block_base + old_id);
+    }
+
+    // Bind call arguments (which refer to the *caller's* locals, so they are
+    // used as-is, not remapped) into the callee's remapped parameter locals,
+    // right in the call block, before jumping into the inlined body.
+    let mut prologue = Vec::new();
+    for (param, arg) in callee.parameters.iter().zip(args.into_iter()) {
+        prologue.push(Statement::Assign {
+            place: Place { local: local_map[&param.local_id], projection: vec![] },
+            rvalue: Rvalue::Use(arg),
+            source_info: SourceInfo { span: SourceLocation::unknown(), scope: 0 },
+        });
+    }
+
+    for (&old_id, block) in &callee.basic_blocks {
+        let new_id = block_map[&old_id];
+        let mut statements: Vec<Statement> =
+            block.statements.iter().map(|s| remap_statement(s, &local_map)).collect();
+
+        let terminator = if matches!(block.terminator, Terminator::Return) {
+            if let Some(continuation) = target {
+                if let Some(return_local) = callee.return_local {
+                    statements.push(Statement::Assign {
+                        place: destination.clone(),
+                        rvalue: Rvalue::Use(Operand::Copy(Place {
+                            local: local_map[&return_local],
+                            projection: vec![],
+                        })),
+                        source_info: SourceInfo { span: SourceLocation::unknown(), scope: 0 },
+                    });
+                }
+                Terminator::Goto { target: continuation }
+            } else {
+                Terminator::Return
+            }
+        } else {
+            remap_terminator(&block.terminator, &local_map, &block_map)
+        };
+
+        caller.basic_blocks.insert(new_id, BasicBlock { id: new_id, statements, terminator });
+    }
+
+    let call_block_ref = caller.basic_blocks.get_mut(&call_block).unwrap();
+    call_block_ref.statements.extend(prologue);
+    call_block_ref.terminator = Terminator::Goto { target: block_map[&callee.entry_block] };
+}
+
+/// Find a basic block in `function` whose terminator calls one of
+/// `candidates` (by name), returning the block id and the callee name.
+///
+/// When more than one call site is inlinable, the smallest callee (by
+/// statement count) is preferred, so that a limited budget is spent on the
+/// cheapest splices first rather than being exhausted by the first call
+/// site encountered in iteration order.
+fn find_inlinable_call(
+    function: &Function,
+    candidates: &HashMap<String, Function>,
+) -> Option<(BasicBlockId, String)> {
+    let mut best: Option<(BasicBlockId, String, usize)> = None;
+
+    for block in function.basic_blocks.values() {
+        if let Terminator::Call { func, .. } = &block.terminator {
+            if let Some(name) = call_target_name(func) {
+                if candidates.contains_key(name) && name != function.name {
+                    let cost = function_statement_count(&candidates[name]);
+                    let is_better = match &best {
+                        Some((_, _, best_cost)) => cost < *best_cost,
+                        None => true,
+                    };
+                    if is_better {
+                        best = Some((block.id, name.to_string(), cost));
+                    }
                 }
             }
         }
-        
-        false // Conservative: assume no recursion for now
     }
-    
-    
-    
+
+    best.map(|(block_id, name, _)| (block_id, name))
+}
+
+/// Total number of statements across all of a function's basic blocks.
+fn function_statement_count(function: &Function) -> usize {
+    function.basic_blocks.values().map(|b| b.statements.len()).sum()
 }
 
 impl OptimizationPass for InliningPass {
     fn name(&self) -> &'static str {
         "inlining"
     }
-    
+
     fn run_on_function(&mut self, _function: &mut Function) -> Result<bool, SemanticError> {
         // Single function inlining requires access to the whole program
         // For now, return false (no changes)
         Ok(false)
     }
-    
+
     fn run_on_program(&mut self, program: &mut Program) -> Result<bool, SemanticError> {
-        let changed = false;
-        
+        let mut changed = false;
+
+        // Build the whole-program call graph and find every function that
+        // participates in a recursive cycle, direct or mutual, so
+        // `should_inline` can reject them.
+        self.recursive_functions = CallGraph::build(program).recursive_functions();
+
         // Find functions that are candidates for inlining
-        let mut inline_candidates = Vec::new();
-        
-        for (name, function) in &program.functions {
-            if self.should_inline(function) {
-                inline_candidates.push(name.clone());
-            }
+        let candidates: HashMap<String, Function> = program
+            .functions
+            .iter()
+            .filter(|(_, function)| self.should_inline(function))
+            .map(|(name, function)| (name.clone(), function.clone()))
+            .collect();
+
+        if candidates.is_empty() {
+            return Ok(false);
         }
-        
-        // For each function, look for calls to inline candidates
-        for (caller_name, caller_function) in &mut program.functions {
-            if inline_candidates.contains(caller_name) {
-                continue; // Don't modify functions we're trying to inline
-            }
-            
-            // Look for calls in each basic block
-            for block in caller_function.basic_blocks.values_mut() {
-                let mut new_statements = Vec::new();
-                
-                for statement in &block.statements {
-                    match statement {
-                        Statement::Assign { place: _, rvalue: Rvalue::Call { func: _, args: _ }, source_info: _ } => {
-                            // Check if this is a call to an inline candidate
-                            // This is simplified - in practice we'd need better function identification
-                            new_statements.push(statement.clone());
-                        }
-                        _ => {
-                            new_statements.push(statement.clone());
-                        }
-                    }
+
+        // Bound the number of splices per run: each inlined call site can,
+        // in turn, expose more inlinable calls from the callee's body, so we
+        // cap total work rather than looping until a fixed point.
+        let splice_limit = candidates.len() * (self.threshold + 1);
+
+        for caller_function in program.functions.values_mut() {
+            let mut remaining = splice_limit;
+            let original_size = function_statement_count(caller_function);
+            let growth_cap = self.caller_growth_cap(original_size);
+            let mut grown_by = 0usize;
+
+            loop {
+                if remaining == 0 {
+                    break;
+                }
+
+                if self.total_inlined_statements >= self.budget.max_total_statements {
+                    break;
+                }
+
+                if grown_by >= growth_cap {
+                    break;
                 }
-                
-                block.statements = new_statements;
+
+                let Some((block_id, callee_name)) =
+                    find_inlinable_call(caller_function, &candidates)
+                else {
+                    break;
+                };
+
+                let callee_size = function_statement_count(&candidates[&callee_name]);
+
+                inline_call_at(caller_function, block_id, &candidates[&callee_name]);
+                self.inlined_functions.insert(callee_name);
+                self.total_inlined_statements += callee_size;
+                grown_by += callee_size;
+                changed = true;
+                remaining -= 1;
             }
         }
-        
+
         Ok(changed)
     }
 }
@@ -159,20 +694,20 @@ mod tests {
     use crate::types::Type;
     use crate::ast::PrimitiveType;
     use crate::error::SourceLocation;
-    
+
     #[test]
     fn test_function_cost_calculation() {
         let pass = InliningPass::new();
         let mut builder = Builder::new();
-        
+
         builder.start_function(
             "small".to_string(),
             vec![],
             Type::primitive(PrimitiveType::Integer),
         );
-        
+
         let temp = builder.new_local(Type::primitive(PrimitiveType::Integer), false);
-        
+
         // Add a single statement
         builder.push_statement(Statement::Assign {
             place: Place { local: temp, projection: vec![] },
@@ -185,27 +720,27 @@ mod tests {
                 scope: 0,
             },
         });
-        
+
         let function = builder.finish_function();
         let cost = pass.calculate_function_cost(&function);
-        
+
         // Should be low cost (1 statement + 1 terminator)
         assert!(cost <= 5);
     }
-    
+
     #[test]
     fn test_should_inline_small_function() {
         let pass = InliningPass::new();
         let mut builder = Builder::new();
-        
+
         builder.start_function(
             "small".to_string(),
             vec![],
             Type::primitive(PrimitiveType::Integer),
         );
-        
+
         let temp = builder.new_local(Type::primitive(PrimitiveType::Integer), false);
-        
+
         // Add a few small statements
         for i in 0..3 {
             builder.push_statement(Statement::Assign {
@@ -220,13 +755,121 @@ mod tests {
                 },
             });
         }
-        
+
         let function = builder.finish_function();
-        
+
         // Small function should be eligible for inlining
         assert!(pass.should_inline(&function));
     }
-    
+
+    #[test]
+    fn test_unwind_edge_increases_cost() {
+        let pass = InliningPass::new();
+
+        let make_function = |cleanup: Option<BasicBlockId>| {
+            let mut builder = Builder::new();
+            builder.start_function("f".to_string(), vec![], Type::primitive(PrimitiveType::Integer));
+            let dest = builder.new_local(Type::primitive(PrimitiveType::Integer), false);
+            builder.set_terminator(Terminator::Call {
+                func: Operand::Constant(Constant {
+                    ty: Type::primitive(PrimitiveType::Integer),
+                    value: ConstantValue::String("other".to_string()),
+                }),
+                args: vec![],
+                destination: Place { local: dest, projection: vec![] },
+                target: None,
+                cleanup,
+            });
+            builder.finish_function()
+        };
+
+        let without_unwind = make_function(None);
+        let with_unwind = make_function(Some(99));
+
+        assert_eq!(
+            pass.calculate_function_cost(&with_unwind),
+            pass.calculate_function_cost(&without_unwind) + UNWIND_PENALTY
+        );
+    }
+
+    #[test]
+    fn test_inline_hints_override_cost_model() {
+        let mut pass = InliningPass::new();
+        pass.set_max_inline_size(0);
+        let mut builder = Builder::new();
+
+        builder.start_function(
+            "big".to_string(),
+            vec![],
+            Type::primitive(PrimitiveType::Integer),
+        );
+        let temp = builder.new_local(Type::primitive(PrimitiveType::Integer), false);
+        builder.push_statement(Statement::Assign {
+            place: Place { local: temp, projection: vec![] },
+            rvalue: Rvalue::Use(Operand::Constant(Constant {
+                ty: Type::primitive(PrimitiveType::Integer),
+                value: ConstantValue::Integer(1),
+            })),
+            source_info: SourceInfo { span: SourceLocation::unknown(), scope: 0 },
+        });
+        let function = builder.finish_function();
+
+        // With a zero threshold this would normally be rejected.
+        assert!(!pass.should_inline(&function));
+
+        pass.mark_always_inline("big".to_string());
+        assert!(pass.should_inline(&function));
+
+        pass.mark_never_inline("big".to_string());
+        assert!(!pass.should_inline(&function));
+    }
+
+    #[test]
+    fn test_inline_decision_short_circuits_before_cost_walk() {
+        let mut pass = InliningPass::new();
+        let mut builder = Builder::new();
+        builder.start_function("f".to_string(), vec![], Type::primitive(PrimitiveType::Integer));
+        let temp = builder.new_local(Type::primitive(PrimitiveType::Integer), false);
+        builder.push_statement(Statement::Assign {
+            place: Place { local: temp, projection: vec![] },
+            rvalue: Rvalue::Use(Operand::Constant(Constant {
+                ty: Type::primitive(PrimitiveType::Integer),
+                value: ConstantValue::Integer(1),
+            })),
+            source_info: SourceInfo { span: SourceLocation::unknown(), scope: 0 },
+        });
+        let function = builder.finish_function();
+
+        assert!(matches!(pass.inline_decision(&function), InlineDecision::CostBased(_)));
+
+        pass.mark_never_inline("f".to_string());
+        assert_eq!(pass.inline_decision(&function), InlineDecision::Never);
+
+        pass.mark_always_inline("f".to_string());
+        assert_eq!(pass.inline_decision(&function), InlineDecision::Always);
+
+        // A function participating in a recursive cycle is rejected even
+        // when always-inline is hinted, since recursion is checked first.
+        pass.recursive_functions.insert("f".to_string());
+        assert_eq!(pass.inline_decision(&function), InlineDecision::Never);
+    }
+
+    #[test]
+    fn test_bodyless_function_is_never_inlined() {
+        let pass = InliningPass::new();
+        let function = Function {
+            name: "extern_decl".to_string(),
+            parameters: vec![],
+            return_type: Type::primitive(PrimitiveType::Integer),
+            locals: HashMap::new(),
+            basic_blocks: HashMap::new(),
+            entry_block: 0,
+            return_local: None,
+        };
+
+        assert_eq!(pass.inline_decision(&function), InlineDecision::Never);
+    }
+
     #[test]
     fn test_program_inlining() {
         let mut pass = InliningPass::new();
@@ -236,7 +879,7 @@ mod tests {
             external_functions: HashMap::new(),
             type_definitions: HashMap::new(),
         };
-        
+
         // Create a small function to inline
         let mut builder = Builder::new();
         builder.start_function(
@@ -244,7 +887,7 @@ mod tests {
             vec![],
             Type::primitive(PrimitiveType::Integer),
         );
-        
+
         let temp = builder.new_local(Type::primitive(PrimitiveType::Integer), false);
         builder.push_statement(Statement::Assign {
             place: Place { local: temp, projection: vec![] },
@@ -257,14 +900,175 @@ mod tests {
                 scope: 0,
             },
         });
-        
+
         let small_function = builder.finish_function();
         program.functions.insert("small".to_string(), small_function);
-        
+
         // Run inlining pass
         let _changed = pass.run_on_program(&mut program).unwrap();
-        
+
         // Function should still exist (not actually inlined in this simplified implementation)
         assert!(program.functions.contains_key("small"));
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_inlines_call_across_functions() {
+        let mut pass = InliningPass::new();
+        let mut program = Program {
+            functions: HashMap::new(),
+            global_constants: HashMap::new(),
+            external_functions: HashMap::new(),
+            type_definitions: HashMap::new(),
+        };
+
+        // callee(): returns 42
+        let mut builder = Builder::new();
+        builder.start_function("callee".to_string(), vec![], Type::primitive(PrimitiveType::Integer));
+        let callee_ret = builder.new_local(Type::primitive(PrimitiveType::Integer), false);
+        builder.push_statement(Statement::Assign {
+            place: Place { local: callee_ret, projection: vec![] },
+            rvalue: Rvalue::Use(Operand::Constant(Constant {
+                ty: Type::primitive(PrimitiveType::Integer),
+                value: ConstantValue::Integer(42),
+            })),
+            source_info: SourceInfo { span: SourceLocation::unknown(), scope: 0 },
+        });
+        let mut callee = builder.finish_function();
+        callee.return_local = Some(callee_ret);
+        callee.basic_blocks.get_mut(&callee.entry_block).unwrap().terminator = Terminator::Return;
+        program.functions.insert("callee".to_string(), callee);
+
+        // caller(): calls callee() and returns its result
+        let mut builder = Builder::new();
+        builder.start_function("caller".to_string(), vec![], Type::primitive(PrimitiveType::Integer));
+        let dest = builder.new_local(Type::primitive(PrimitiveType::Integer), false);
+        let entry = builder.current_block.unwrap();
+        let next = builder.new_block();
+        builder.set_terminator(Terminator::Call {
+            func: Operand::Constant(Constant {
+                ty: Type::primitive(PrimitiveType::Integer),
+                value: ConstantValue::String("callee".to_string()),
+            }),
+            args: vec![],
+            destination: Place { local: dest, projection: vec![] },
+            target: Some(next),
+            cleanup: None,
+        });
+        builder.current_block = Some(next);
+        builder.set_terminator(Terminator::Return);
+        let mut caller = builder.finish_function();
+        caller.return_local = Some(dest);
+        caller.basic_blocks.get_mut(&entry).unwrap().terminator = Terminator::Call {
+            func: Operand::Constant(Constant {
+                ty: Type::primitive(PrimitiveType::Integer),
+                value: ConstantValue::String("callee".to_string()),
+            }),
+            args: vec![],
+            destination: Place { local: dest, projection: vec![] },
+            target: Some(next),
+            cleanup: None,
+        };
+        program.functions.insert("caller".to_string(), caller);
+
+        let changed = pass.run_on_program(&mut program).unwrap();
+        assert!(changed);
+
+        let caller = &program.functions["caller"];
+        assert!(!caller.basic_blocks.values().any(|b| matches!(b.terminator, Terminator::Call { .. })));
+    }
+
+    #[test]
+    fn test_global_budget_stops_further_inlining() {
+        let mut pass = InliningPass::new();
+        // Exhaust the program-wide budget before any inlining happens.
+        pass.set_inline_budget(4, 0);
+
+        let mut program = Program {
+            functions: HashMap::new(),
+            global_constants: HashMap::new(),
+            external_functions: HashMap::new(),
+            type_definitions: HashMap::new(),
+        };
+
+        let mut builder = Builder::new();
+        builder.start_function("callee".to_string(), vec![], Type::primitive(PrimitiveType::Integer));
+        let callee_ret = builder.new_local(Type::primitive(PrimitiveType::Integer), false);
+        builder.push_statement(Statement::Assign {
+            place: Place { local: callee_ret, projection: vec![] },
+            rvalue: Rvalue::Use(Operand::Constant(Constant {
+                ty: Type::primitive(PrimitiveType::Integer),
+                value: ConstantValue::Integer(42),
+            })),
+            source_info: SourceInfo { span: SourceLocation::unknown(), scope: 0 },
+        });
+        let mut callee = builder.finish_function();
+        callee.return_local = Some(callee_ret);
+        callee.basic_blocks.get_mut(&callee.entry_block).unwrap().terminator = Terminator::Return;
+        program.functions.insert("callee".to_string(), callee);
+
+        let mut builder = Builder::new();
+        builder.start_function("caller".to_string(), vec![], Type::primitive(PrimitiveType::Integer));
+        let dest = builder.new_local(Type::primitive(PrimitiveType::Integer), false);
+        let entry = builder.current_block.unwrap();
+        let next = builder.new_block();
+        builder.current_block = Some(next);
+        builder.set_terminator(Terminator::Return);
+        let mut caller = builder.finish_function();
+        caller.return_local = Some(dest);
+        caller.basic_blocks.get_mut(&entry).unwrap().terminator = Terminator::Call {
+            func: Operand::Constant(Constant {
+                ty: Type::primitive(PrimitiveType::Integer),
+                value: ConstantValue::String("callee".to_string()),
+            }),
+            args: vec![],
+            destination: Place { local: dest, projection: vec![] },
+            target: Some(next),
+            cleanup: None,
+        };
+        program.functions.insert("caller".to_string(), caller);
+
+        let changed = pass.run_on_program(&mut program).unwrap();
+        assert!(!changed);
+
+        let caller = &program.functions["caller"];
+        assert!(caller.basic_blocks.values().any(|b| matches!(b.terminator, Terminator::Call { .. })));
+    }
+
+    #[test]
+    fn test_mutually_recursive_functions_are_not_inlined() {
+        // ping() calls pong(), pong() calls ping() - neither should ever be
+        // considered an inline candidate, even though neither directly
+        // calls itself.
+        fn make_caller(name: &str, callee: &str) -> Function {
+            let mut builder = Builder::new();
+            builder.start_function(name.to_string(), vec![], Type::primitive(PrimitiveType::Integer));
+            let dest = builder.new_local(Type::primitive(PrimitiveType::Integer), false);
+            builder.set_terminator(Terminator::Call {
+                func: Operand::Constant(Constant {
+                    ty: Type::primitive(PrimitiveType::Integer),
+                    value: ConstantValue::String(callee.to_string()),
+                }),
+                args: vec![],
+                destination: Place { local: dest, projection: vec![] },
+                target: None,
+                cleanup: None,
+            });
+            let mut function = builder.finish_function();
+            function.return_local = Some(dest);
+            function
+        }
+
+        let mut program = Program {
+            functions: HashMap::new(),
+            global_constants: HashMap::new(),
+            external_functions: HashMap::new(),
+            type_definitions: HashMap::new(),
+        };
+        program.functions.insert("ping".to_string(), make_caller("ping", "pong"));
+        program.functions.insert("pong".to_string(), make_caller("pong", "ping"));
+
+        let recursive = CallGraph::build(&program).recursive_functions();
+        assert!(recursive.contains("ping"));
+        assert!(recursive.contains("pong"));
+    }
+}
@@ -112,6 +112,13 @@ impl OptimizationManager {
     }
     
     /// Create an advanced optimization pipeline with all passes
+    ///
+    /// Nothing in this crate constructs this pipeline outside of its own
+    /// tests - `CompilationPipeline::compile_files` always builds
+    /// [`Self::create_default_pipeline`], which doesn't include
+    /// [`vectorization::VectorizationPass`]. Don't read the presence of
+    /// that pass here as evidence it runs on a real compile; see the
+    /// "Reachability" note on `optimizations::vectorization`.
     pub fn create_advanced_pipeline() -> Self {
         let mut manager = Self::new();
         
@@ -138,6 +145,10 @@ impl OptimizationManager {
     }
     
     /// Create a profile-guided optimization pipeline
+    ///
+    /// Same caveat as [`Self::create_advanced_pipeline`]: no real caller
+    /// constructs this pipeline, so the [`vectorization::VectorizationPass`]
+    /// it adds never actually runs on a compiled program today.
     pub fn create_pgo_pipeline(profile_data_path: &str) -> Result<Self, SemanticError> {
         let mut manager = Self::new();
         
@@ -157,6 +168,10 @@ impl OptimizationManager {
     }
     
     /// Create whole program optimization pipeline
+    ///
+    /// Same caveat as [`Self::create_advanced_pipeline`]: no real caller
+    /// constructs this pipeline, so the [`vectorization::VectorizationPass`]
+    /// it adds never actually runs on a compiled program today.
     pub fn create_whole_program_pipeline() -> Self {
         let mut manager = Self::new();
         
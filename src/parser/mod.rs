@@ -67,7 +67,8 @@ pub enum KeywordType {
     AlgorithmHint,
     PerformanceExpectation,
     ComplexityExpectation,
-    
+    Test,
+
     // Performance metric keywords
     LatencyMs,
     ThroughputOps,
@@ -353,6 +354,7 @@ impl Parser {
             ("ALGORITHM_HINT", KeywordType::AlgorithmHint),
             ("PERFORMANCE_EXPECTATION", KeywordType::PerformanceExpectation),
             ("COMPLEXITY_EXPECTATION", KeywordType::ComplexityExpectation),
+            ("TEST", KeywordType::Test),
             ("LIBRARY", KeywordType::Library),
             ("SYMBOL", KeywordType::Symbol),
             ("CALLING_CONVENTION", KeywordType::CallingConvention),
@@ -752,6 +754,13 @@ impl Parser {
         &self.errors
     }
 
+    /// Drain the errors accumulated by [`Parser::parse_program_recovering`]
+    /// (or by [`Parser::parse_program`], which also records errors as it
+    /// goes, even though it only ever returns the first one).
+    pub fn take_errors(&mut self) -> Vec<ParserError> {
+        std::mem::take(&mut self.errors)
+    }
+
     /// Check if any errors were encountered
     pub fn has_errors(&self) -> bool {
         !self.errors.is_empty()
@@ -795,6 +804,46 @@ impl Parser {
         })
     }
 
+    /// Parse a complete program in error-recovery mode.
+    ///
+    /// Unlike [`Parser::parse_program`], this never bails out on the first
+    /// malformed top-level module form: it records the error and
+    /// [`Parser::synchronize`]s to the next one, the same recovery
+    /// [`Parser::parse_program`] already performs internally, except the
+    /// best-effort [`Program`] built from every module that *did* parse is
+    /// always returned rather than being discarded in favor of the first
+    /// error. Call [`Parser::take_errors`] afterwards to get every error
+    /// that was hit along the way.
+    pub fn parse_program_recovering(&mut self) -> Program {
+        let start_location = self.current_token()
+            .map(|t| t.location.clone())
+            .unwrap_or_else(SourceLocation::unknown);
+
+        let mut modules = Vec::new();
+
+        while !self.is_at_end() {
+            if let Some(token) = self.current_token() {
+                if matches!(token.token_type, TokenType::Comment(_)) {
+                    self.advance();
+                    continue;
+                }
+            }
+
+            match self.parse_module() {
+                Ok(module) => modules.push(module),
+                Err(error) => {
+                    self.record_error(error);
+                    self.synchronize();
+                }
+            }
+        }
+
+        Program {
+            modules,
+            source_location: start_location,
+        }
+    }
+
     /// Parse a module definition
     pub fn parse_module(&mut self) -> Result<Module, ParserError> {
         let start_location = self.consume_left_paren()?;
@@ -1750,6 +1799,7 @@ impl Parser {
             throws_exceptions: Vec::new(),
             thread_safe: None,
             may_block: None,
+            is_test: false,
         };
         
         // Parse function fields
@@ -1830,6 +1880,20 @@ impl Parser {
                             self.advance(); // consume MAY_BLOCK
                             metadata.may_block = Some(self.consume_boolean()?);
                         }
+                        Some(KeywordType::Test) => {
+                            self.advance(); // consume TEST
+                            // A bare `(TEST)` means true; `(TEST FALSE)` is
+                            // also accepted so a test can be toggled off
+                            // without deleting the field.
+                            metadata.is_test = if matches!(
+                                self.current_token().map(|t| &t.token_type),
+                                Some(TokenType::RightParen)
+                            ) {
+                                true
+                            } else {
+                                self.consume_boolean()?
+                            };
+                        }
                         _ => {
                             return Err(ParserError::UnexpectedToken {
                                 found: keyword.clone(),
@@ -1847,10 +1911,10 @@ impl Parser {
                     });
                 }
             }
-            
+
             self.consume_right_paren()?;
         }
-        
+
         // Validate required fields
         let name = name.ok_or_else(|| ParserError::MissingRequiredField {
             field: "NAME".to_string(),
@@ -2197,6 +2261,18 @@ impl Parser {
                 self.advance();
                 Ok(Expression::IntegerLiteral {
                     value: int_value,
+                    bits: 64,
+                    signed: true,
+                    source_location: location,
+                })
+            }
+            TokenType::SizedInteger { value, bits, signed } => {
+                let (int_value, bits, signed) = (*value, *bits, *signed);
+                self.advance();
+                Ok(Expression::IntegerLiteral {
+                    value: int_value,
+                    bits,
+                    signed,
                     source_location: location,
                 })
             }
@@ -2205,6 +2281,16 @@ impl Parser {
                 self.advance();
                 Ok(Expression::FloatLiteral {
                     value: float_value,
+                    bits: 64,
+                    source_location: location,
+                })
+            }
+            TokenType::SizedFloat { value, bits } => {
+                let (float_value, bits) = (*value, *bits);
+                self.advance();
+                Ok(Expression::FloatLiteral {
+                    value: float_value,
+                    bits,
                     source_location: location,
                 })
             }
@@ -4075,6 +4161,59 @@ mod tests {
         assert!(parser.keywords.contains_key("DEFINE_FUNCTION"));
         assert_eq!(parser.keywords.get("DEFINE_MODULE"), Some(&KeywordType::DefineModule));
     }
+
+    #[test]
+    fn test_function_with_bare_test_field() {
+        let source = r#"
+        (DEFINE_FUNCTION
+          (NAME test_addition)
+          (TEST)
+          (BODY)
+        )
+        "#;
+
+        let mut lexer = Lexer::new(source, "test.aether".to_string());
+        let tokens = lexer.tokenize().unwrap();
+        let mut parser = Parser::new(tokens);
+
+        let function = parser.parse_function_definition().unwrap();
+        assert!(function.metadata.is_test);
+    }
+
+    #[test]
+    fn test_function_without_test_field_is_not_a_test() {
+        let source = r#"
+        (DEFINE_FUNCTION
+          (NAME helper)
+          (BODY)
+        )
+        "#;
+
+        let mut lexer = Lexer::new(source, "test.aether".to_string());
+        let tokens = lexer.tokenize().unwrap();
+        let mut parser = Parser::new(tokens);
+
+        let function = parser.parse_function_definition().unwrap();
+        assert!(!function.metadata.is_test);
+    }
+
+    #[test]
+    fn test_function_with_explicit_test_false() {
+        let source = r#"
+        (DEFINE_FUNCTION
+          (NAME helper)
+          (TEST FALSE)
+          (BODY)
+        )
+        "#;
+
+        let mut lexer = Lexer::new(source, "test.aether".to_string());
+        let tokens = lexer.tokenize().unwrap();
+        let mut parser = Parser::new(tokens);
+
+        let function = parser.parse_function_definition().unwrap();
+        assert!(!function.metadata.is_test);
+    }
 }
 
 impl Parser {
@@ -477,6 +477,8 @@ impl PatternGenerator {
     fn parse_expression(&self, expr_str: &str) -> Result<Expression, GenerationError> {
         // Simplified expression parsing
         Ok(Expression::IntegerLiteral {
+            bits: 64,
+            signed: true,
             value: 0,
             source_location: SourceLocation::unknown(),
         })
@@ -25,6 +25,7 @@ use crate::optimizations::OptimizationManager;
 use crate::parser::Parser;
 use crate::profiling::CompilationProfiler;
 use crate::semantic::SemanticAnalyzer;
+use crate::stdlib::capabilities::{check_module_capabilities, CapabilityManifest};
 use crate::stdlib::StandardLibrary;
 
 use inkwell::context::Context;
@@ -63,6 +64,38 @@ pub struct CompileOptions {
     pub syntax_only: bool,
     /// Compile as a library (shared object/dylib)
     pub compile_as_library: bool,
+    /// Enable the function inlining pass
+    pub enable_inlining: bool,
+    /// Maximum size (in [`InliningPass`](crate::optimizations::inlining::InliningPass) cost units) a function may have to be considered for inlining
+    pub inline_threshold: usize,
+    /// Maximum inlining depth, passed to the inlining pass, which ignores it
+    pub inline_depth: usize,
+    /// When set, each compilation phase is checked against this budget as
+    /// soon as it finishes, and compilation fails with
+    /// [`CompilerError::TimeBudgetExceeded`] the moment a phase blows it,
+    /// rather than only catching an overrun in the overall wall-clock time.
+    pub time_budget_ms: Option<u128>,
+    /// Resolve `import` statements across files before parsing: discover the
+    /// transitive closure of imported files starting from `input_files`
+    /// via [`crate::loader::Loader`], rejecting import cycles, and compile
+    /// the whole resolved set rather than just the files named on the
+    /// command line.
+    pub resolve_imports: bool,
+    /// Source-level capability lint applied to the compiled program's own
+    /// module bodies by
+    /// [`crate::stdlib::capabilities::check_module_capabilities`] right
+    /// after semantic analysis. Defaults to [`CapabilityManifest::permissive`]
+    /// so existing callers that don't know about this lint aren't suddenly
+    /// broken; callers that want it enforced build their own manifest with
+    /// [`CapabilityManifest::deny_all`].
+    ///
+    /// This only catches gated `std.io` calls made with a string-literal
+    /// path, at compile time, and it is independent of runtime path
+    /// containment: it never reaches the compiled binary, and the separate
+    /// `AETHER_IO_SANDBOX_ROOT`-based containment in `runtime::io` isn't
+    /// informed by it either. See the module doc on
+    /// [`crate::stdlib::capabilities`] for why these two don't compose.
+    pub io_capabilities: CapabilityManifest,
 }
 
 impl Default for CompileOptions {
@@ -81,6 +114,12 @@ impl Default for CompileOptions {
             emit_object_only: false,
             syntax_only: false,
             compile_as_library: false,
+            enable_inlining: true,
+            inline_threshold: 20,
+            inline_depth: 1,
+            time_budget_ms: None,
+            resolve_imports: false,
+            io_capabilities: CapabilityManifest::permissive(),
         }
     }
 }
@@ -131,7 +170,21 @@ impl CompilationPipeline {
         let start_time = std::time::Instant::now();
         let mut stats = CompilationStats::default();
         let mut intermediate_files = Vec::new();
-        
+
+        // When import resolution is enabled, expand the CLI-provided inputs
+        // into the full transitive import graph (and fail fast on cycles)
+        // before any parsing happens, so later phases see every module the
+        // program actually needs.
+        let resolved_files;
+        let input_files = if self.options.resolve_imports {
+            let mut loader = crate::loader::Loader::new();
+            loader.load_program(input_files)?;
+            resolved_files = loader.ids().iter().map(|&id| loader.path(id).to_path_buf()).collect::<Vec<_>>();
+            resolved_files.as_slice()
+        } else {
+            input_files
+        };
+
         // Initialize profiler if enabled
         let mut profiler = CompilationProfiler::new();
         if self.options.enable_profiling {
@@ -214,7 +267,7 @@ impl CompilationPipeline {
         };
         
         stats.modules_compiled = program.modules.len();
-        stats.phase_times.insert("parsing".to_string(), parse_start.elapsed().as_millis());
+        self.record_phase_time(&mut stats, "parsing", parse_start.elapsed().as_millis())?;
         
         if self.options.enable_profiling {
             profiler.snapshot_memory("after_parsing");
@@ -238,8 +291,12 @@ impl CompilationPipeline {
             // Extract symbol table for MIR lowering
             analyzer.get_symbol_table()
         };
-        
-        stats.phase_times.insert("semantic_analysis".to_string(), semantic_start.elapsed().as_millis());
+
+        for module in &program.modules {
+            check_module_capabilities(module, &self.options.io_capabilities)?;
+        }
+
+        self.record_phase_time(&mut stats, "semantic_analysis", semantic_start.elapsed().as_millis())?;
         
         if self.options.enable_profiling {
             profiler.snapshot_memory("after_semantic_analysis");
@@ -285,7 +342,7 @@ impl CompilationPipeline {
             mir::lowering::lower_ast_to_mir_with_symbols(&program, symbol_table)?
         };
         
-        stats.phase_times.insert("mir_generation".to_string(), mir_start.elapsed().as_millis());
+        self.record_phase_time(&mut stats, "mir_generation", mir_start.elapsed().as_millis())?;
         
         if self.options.enable_profiling {
             profiler.snapshot_memory("after_mir_generation");
@@ -305,10 +362,18 @@ impl CompilationPipeline {
             if self.options.optimization_level > 0 {
                 opt_manager = OptimizationManager::create_default_pipeline();
             }
+
+            if self.options.enable_inlining {
+                let mut inlining_pass = crate::optimizations::inlining::InliningPass::new();
+                inlining_pass.set_max_inline_size(self.options.inline_threshold);
+                inlining_pass.set_max_inline_depth(self.options.inline_depth);
+                opt_manager.add_pass(Box::new(inlining_pass));
+            }
+
             opt_manager.optimize_program(&mut mir_program)?;
         }
         
-        stats.phase_times.insert("optimization".to_string(), opt_start.elapsed().as_millis());
+        self.record_phase_time(&mut stats, "optimization", opt_start.elapsed().as_millis())?;
         
         if self.options.enable_profiling {
             profiler.snapshot_memory("after_optimization");
@@ -346,7 +411,7 @@ impl CompilationPipeline {
             backend.generate_ir(&mir_program)?;
         }
         
-        stats.phase_times.insert("llvm_codegen".to_string(), codegen_start.elapsed().as_millis());
+        self.record_phase_time(&mut stats, "llvm_codegen", codegen_start.elapsed().as_millis())?;
         
         if self.options.enable_profiling {
             profiler.snapshot_memory("after_llvm_codegen");
@@ -363,7 +428,7 @@ impl CompilationPipeline {
             intermediate_files.push(object_file.clone());
         }
         
-        stats.phase_times.insert("object_generation".to_string(), object_start.elapsed().as_millis());
+        self.record_phase_time(&mut stats, "object_generation", object_start.elapsed().as_millis())?;
 
         // Check if output is object file only
         let output_is_object = self.options.output.as_ref()
@@ -398,7 +463,7 @@ impl CompilationPipeline {
                 self.link_executable(&object_file, module_name)?
             };
             
-            stats.phase_times.insert("linking".to_string(), link_start.elapsed().as_millis());
+            self.record_phase_time(&mut stats, "linking", link_start.elapsed().as_millis())?;
             
             output_path
         };
@@ -431,6 +496,112 @@ impl CompilationPipeline {
         })
     }
 
+    /// Compile `input_file` and execute its `main` directly in an in-memory
+    /// LLVM JIT, returning the program's exit code. Runs the same
+    /// parse/analyze/lower/optimize phases as [`Self::compile_files`], but
+    /// hands the resulting module straight to an inkwell `ExecutionEngine`
+    /// instead of writing an object file and invoking the linker, so this
+    /// never touches the filesystem beyond reading `input_file` itself.
+    ///
+    /// One caveat: the JIT resolves externally-linked symbols (e.g. the
+    /// `std.io` externals) against the `aether` binary's own process image
+    /// rather than a dynamically linked runtime library, so programs that
+    /// depend on the AetherScript runtime being linked in at compile time
+    /// may fail to resolve those symbols here even though `compile_files`
+    /// would succeed.
+    pub fn jit_run(&mut self, input_file: &Path, args: &[String]) -> Result<i32, CompilerError> {
+        let source = fs::read_to_string(input_file).map_err(|e| CompilerError::IoError {
+            message: format!("Failed to read {}: {}", input_file.display(), e),
+        })?;
+
+        let mut lexer = Lexer::new(&source, input_file.to_string_lossy().to_string());
+        let tokens = lexer.tokenize()?;
+        let mut parser = Parser::new(tokens);
+        let module = parser.parse_module()?;
+
+        let program = Program {
+            modules: vec![module],
+            source_location: crate::error::SourceLocation::unknown(),
+        };
+
+        let mut analyzer = SemanticAnalyzer::new();
+        analyzer.analyze_program(&program)?;
+        let symbol_table = analyzer.get_symbol_table();
+
+        let mut mir_program = mir::lowering::lower_ast_to_mir_with_symbols(&program, symbol_table)?;
+
+        if self.options.optimization_level > 0 {
+            let mut opt_manager = OptimizationManager::create_default_pipeline();
+            if self.options.enable_inlining {
+                let mut inlining_pass = crate::optimizations::inlining::InliningPass::new();
+                inlining_pass.set_max_inline_size(self.options.inline_threshold);
+                inlining_pass.set_max_inline_depth(self.options.inline_depth);
+                opt_manager.add_pass(Box::new(inlining_pass));
+            }
+            opt_manager.optimize_program(&mut mir_program)?;
+        }
+
+        let context = Context::create();
+        let module_name = input_file.file_stem().and_then(|s| s.to_str()).unwrap_or("main");
+        let mut backend = LLVMBackend::new(&context, module_name);
+
+        LLVMBackend::initialize_targets();
+        let target_triple = self.options.target_triple.clone()
+            .unwrap_or_else(|| {
+                use crate::llvm_backend::TargetArch;
+                TargetArch::native().target_triple().to_string()
+            });
+        backend.set_target_triple(&target_triple)?;
+        backend.generate_ir(&mir_program)?;
+
+        let engine = backend.module()
+            .create_jit_execution_engine(inkwell::OptimizationLevel::None)
+            .map_err(|e| CompilerError::Internal {
+                message: format!("Failed to create JIT execution engine: {}", e),
+            })?;
+
+        let main_fn = unsafe {
+            engine.get_function::<unsafe extern "C" fn(i32, *mut *mut std::os::raw::c_char) -> i32>("main")
+        }.map_err(|e| CompilerError::Internal {
+            message: format!("JIT module has no callable `main`: {}", e),
+        })?;
+
+        let program_name = std::ffi::CString::new(input_file.to_string_lossy().as_bytes()).unwrap_or_default();
+        let arg_cstrings: Vec<std::ffi::CString> = args.iter()
+            .map(|a| std::ffi::CString::new(a.as_bytes()).unwrap_or_default())
+            .collect();
+        let mut argv: Vec<*mut std::os::raw::c_char> = std::iter::once(&program_name)
+            .chain(arg_cstrings.iter())
+            .map(|s| s.as_ptr() as *mut std::os::raw::c_char)
+            .collect();
+
+        let exit_code = unsafe { main_fn.call(argv.len() as i32, argv.as_mut_ptr()) };
+        Ok(exit_code)
+    }
+
+    /// Record a phase's elapsed time and, if `time_budget_ms` is set, fail
+    /// immediately when the phase alone has blown it.
+    fn record_phase_time(
+        &self,
+        stats: &mut CompilationStats,
+        phase: &str,
+        elapsed_ms: u128,
+    ) -> Result<(), CompilerError> {
+        stats.phase_times.insert(phase.to_string(), elapsed_ms);
+
+        if let Some(budget_ms) = self.options.time_budget_ms {
+            if elapsed_ms > budget_ms {
+                return Err(CompilerError::TimeBudgetExceeded {
+                    phase: phase.to_string(),
+                    budget_ms,
+                    actual_ms: elapsed_ms,
+                });
+            }
+        }
+
+        Ok(())
+    }
+
     /// Generate object file from LLVM module
     fn generate_object_file(&self, backend: &LLVMBackend, base_name: &str) -> Result<PathBuf, CompilerError> {
         let object_path = PathBuf::from(format!("{}.o", base_name));
@@ -604,6 +775,8 @@ mod tests {
         assert_eq!(opts.optimization_level, 2);
         assert!(!opts.debug_info);
         assert!(!opts.verbose);
+        assert!(opts.enable_inlining);
+        assert_eq!(opts.inline_threshold, 20);
     }
 
     #[test]
@@ -625,9 +798,48 @@ mod tests {
             optimization_level: 3,
             ..Default::default()
         };
-        
+
         let pipeline = CompilationPipeline::new(opts);
         assert_eq!(pipeline.options.optimization_level, 3);
         assert!(pipeline.options.verbose);
     }
+
+    /// End-to-end check that a denying [`CapabilityManifest`] actually stops
+    /// [`CompilationPipeline::compile_files`], not just [`check_module_capabilities`]
+    /// in isolation - a module that calls `std.io.open_file` on a path outside
+    /// its grants should fail compilation with [`SemanticError::CapabilityViolation`].
+    #[test]
+    fn test_compile_files_enforces_io_capabilities() {
+        let source = r#"
+(DEFINE_MODULE
+  (NAME "capability_test")
+  (IMPORT_MODULE "std.io")
+  (CONTENT
+    (DEFINE_FUNCTION
+      (NAME "main")
+      (RETURNS (TYPE INT))
+      (BODY
+        (CALL_FUNCTION "std.io.open_file"
+          (ARGUMENTS (STRING_LITERAL "/etc/passwd") (STRING_LITERAL "r")))
+        (RETURN_VALUE (INTEGER_LITERAL 0))))))
+"#;
+        let input_file = std::env::temp_dir()
+            .join(format!("aether_capability_test_{}.aether", std::process::id()));
+        fs::write(&input_file, source).expect("failed to write test fixture");
+
+        let opts = CompileOptions {
+            syntax_only: true,
+            io_capabilities: CapabilityManifest::deny_all(),
+            ..Default::default()
+        };
+        let mut pipeline = CompilationPipeline::new(opts);
+        let result = pipeline.compile_files(&[input_file.clone()]);
+
+        let _ = fs::remove_file(&input_file);
+
+        match result {
+            Err(CompilerError::Semantic { source: SemanticError::CapabilityViolation { .. } }) => {}
+            other => panic!("expected a CapabilityViolation, got {other:?}"),
+        }
+    }
 }
\ No newline at end of file
@@ -28,6 +28,7 @@ use crate::module_loader::{ModuleLoader, LoadedModule};
 use crate::types::{Type, TypeChecker, OwnershipKind};
 use crate::symbols::{Symbol, SymbolTable, SymbolKind, ScopeKind, BorrowState};
 use crate::error::{SemanticError, SourceLocation};
+use crate::error::snippet::Diagnostic as SnippetDiagnostic;
 use std::collections::HashMap;
 use std::rc::Rc;
 use std::cell::RefCell;
@@ -69,6 +70,15 @@ pub struct SemanticAnalyzer {
     
     /// Analyzed modules cache to prevent double-analysis
     analyzed_modules: HashMap<String, LoadedModule>,
+
+    /// Original source text, set via [`SemanticAnalyzer::set_source`], used
+    /// to render source-snippet diagnostics for the errors in `errors`.
+    source_text: Option<String>,
+
+    /// Rich diagnostics rendered from `errors` once analysis has finished,
+    /// one per reportable error that has a snippet-worthy location. Render
+    /// with [`crate::error::snippet::SnippetRenderer`].
+    diagnostics: Vec<SnippetDiagnostic>,
 }
 
 /// Statistics about the semantic analysis
@@ -103,25 +113,63 @@ impl SemanticAnalyzer {
             current_exceptions: Vec::new(),
             in_finally_block: false,
             analyzed_modules: HashMap::new(),
+            source_text: None,
+            diagnostics: Vec::new(),
         }
     }
-    
+
+    /// Record the original source text so errors found during analysis can
+    /// be rendered as source-snippet diagnostics (see [`Self::diagnostics`]).
+    pub fn set_source(&mut self, source: impl Into<String>) {
+        self.source_text = Some(source.into());
+    }
+
+    /// Rich diagnostics rendered from the most recent [`Self::analyze_program`]
+    /// call. Empty unless [`Self::set_source`] was called first.
+    pub fn diagnostics(&self) -> &[SnippetDiagnostic] {
+        &self.diagnostics
+    }
+
     /// Analyze a complete program
     pub fn analyze_program(&mut self, program: &Program) -> Result<(), Vec<SemanticError>> {
         self.errors.clear();
-        
+        self.diagnostics.clear();
+
         for module in &program.modules {
             if let Err(e) = self.analyze_module(module) {
                 self.errors.push(e);
             }
         }
-        
+
+        if self.source_text.is_some() {
+            for error in &self.errors {
+                if let Some(diagnostic) = Self::error_to_snippet_diagnostic(error) {
+                    self.diagnostics.push(diagnostic);
+                }
+            }
+        }
+
         if self.errors.is_empty() {
             Ok(())
         } else {
             Err(self.errors.clone())
         }
     }
+
+    /// Build a [`SnippetDiagnostic`] for the error kinds whose message is
+    /// most improved by a source excerpt. Other variants already carry a
+    /// self-describing message and are left to the plain `Display` impl.
+    fn error_to_snippet_diagnostic(error: &SemanticError) -> Option<SnippetDiagnostic> {
+        match error {
+            SemanticError::TypeMismatch { expected, found, location } => {
+                Some(SnippetDiagnostic::error(
+                    format!("expected `{}`, found `{}`", expected, found),
+                    location.clone(),
+                ))
+            }
+            _ => None,
+        }
+    }
     
     /// Analyze a module
     pub fn analyze_module(&mut self, module: &Module) -> Result<(), SemanticError> {
@@ -740,12 +788,28 @@ impl SemanticAnalyzer {
     /// Analyze an expression and return its type
     fn analyze_expression(&mut self, expression: &Expression) -> Result<Type, SemanticError> {
         match expression {
-            Expression::IntegerLiteral { .. } => {
-                Ok(Type::primitive(PrimitiveType::Integer))
+            Expression::IntegerLiteral { value, bits, signed, source_location } => {
+                let prim_type = PrimitiveType::sized_integer(*bits, *signed)
+                    .unwrap_or(PrimitiveType::Integer);
+
+                if let Some((min, max)) = prim_type.integer_range() {
+                    let value = *value as i128;
+                    if value < min || value > max {
+                        return Err(SemanticError::LiteralOutOfRange {
+                            value,
+                            type_name: prim_type.to_string(),
+                            min,
+                            max,
+                            location: source_location.clone(),
+                        });
+                    }
+                }
+
+                Ok(Type::primitive(prim_type))
             }
-            
-            Expression::FloatLiteral { .. } => {
-                Ok(Type::primitive(PrimitiveType::Float))
+
+            Expression::FloatLiteral { bits, .. } => {
+                Ok(Type::primitive(if *bits == 32 { PrimitiveType::Float32 } else { PrimitiveType::Float }))
             }
             
             Expression::StringLiteral { .. } => {
@@ -2321,6 +2385,7 @@ mod tests {
                         source_location: SourceLocation::unknown(),
                     }),
                     value: Box::new(Expression::FloatLiteral {
+                        bits: 64,
                         value: 3.14159,
                         source_location: SourceLocation::unknown(),
                     }),
@@ -2373,6 +2438,8 @@ mod tests {
         
         // Test integer literal
         let int_expr = Expression::IntegerLiteral {
+            bits: 64,
+            signed: true,
             value: 42,
             source_location: SourceLocation::unknown(),
         };
@@ -2382,10 +2449,14 @@ mod tests {
         // Test arithmetic expression
         let add_expr = Expression::Add {
             left: Box::new(Expression::IntegerLiteral {
+                bits: 64,
+                signed: true,
                 value: 10,
                 source_location: SourceLocation::unknown(),
             }),
             right: Box::new(Expression::IntegerLiteral {
+                bits: 64,
+                signed: true,
                 value: 20,
                 source_location: SourceLocation::unknown(),
             }),
@@ -2476,6 +2547,7 @@ mod tests {
             throws_exceptions: Vec::new(),
             thread_safe: Some(true),
             may_block: Some(false),
+            is_test: false,
         };
 
         let result = validator.validate_function_metadata(
@@ -2531,6 +2603,7 @@ mod tests {
             throws_exceptions: Vec::new(),
             thread_safe: None,
             may_block: None,
+            is_test: false,
         };
 
         let result = validator.validate_function_metadata(
@@ -0,0 +1,478 @@
+// Copyright 2025 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Compile-time capability linting for `std.io` call sites.
+//!
+//! A [`CapabilityManifest`] declares which filesystem paths and operations a
+//! module's source is allowed to *call for*. [`check_module_capabilities`]
+//! walks a module's own function bodies looking for calls to `std.io`'s
+//! gated external functions and rejects any whose path argument isn't
+//! covered by a granted [`Capability`], the same way
+//! [`crate::resource::ResourceManager`] walks function bodies to reject
+//! resource misuse.
+//!
+//! This only catches calls whose path argument is a string literal - the
+//! common case for hardcoded paths like config file names - since checking
+//! an arbitrary runtime-computed path would require whole-program dataflow
+//! analysis this compiler doesn't do. A call with a non-literal path is
+//! allowed through statically as long as at least one capability of the
+//! required kind has been granted.
+//!
+//! **This is a lint over source text, not a sandbox.** It rejects calls the
+//! compiler can see and has been told not to allow; it has no way to stop
+//! the compiled binary itself from opening whatever path it's given at
+//! runtime, and a manifest built here never reaches the running process.
+//! Runtime path containment is a separate, coarser mechanism:
+//! `runtime::io::sandbox_root` (gated by the `AETHER_IO_SANDBOX_ROOT`
+//! environment variable read by the process the compiled binary runs in)
+//! canonicalizes paths and rejects any that escape a single configured
+//! root directory, regardless of how the path was computed. Only
+//! `aether_io_canonicalize`, `aether_io_open_existing_file` and
+//! `aether_io_create_new_file` apply that check; other `aether_io_*` calls
+//! do not. The two don't share configuration or vocabulary on
+//! purpose: one is an author-time check against this compiler's view of a
+//! module's literal path arguments, the other is a deployment-time
+//! containment boundary enforced independently of how the program was
+//! compiled. Do not assume granting a [`Capability`] here implies anything
+//! is enforced at runtime, and do not assume setting
+//! `AETHER_IO_SANDBOX_ROOT` is informed by any [`CapabilityManifest`].
+
+use crate::ast::{Block, Expression, Function, FunctionCall, FunctionReference, Module, Statement};
+use crate::error::SemanticError;
+
+/// A single filesystem permission, scoped to a glob pattern over paths.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Capability {
+    ReadPath(String),
+    WritePath(String),
+    CreateDir(String),
+    Delete(String),
+}
+
+impl Capability {
+    fn kind(&self) -> CapabilityKind {
+        match self {
+            Capability::ReadPath(_) => CapabilityKind::Read,
+            Capability::WritePath(_) => CapabilityKind::Write,
+            Capability::CreateDir(_) => CapabilityKind::CreateDir,
+            Capability::Delete(_) => CapabilityKind::Delete,
+        }
+    }
+
+    fn pattern(&self) -> &str {
+        match self {
+            Capability::ReadPath(pattern)
+            | Capability::WritePath(pattern)
+            | Capability::CreateDir(pattern)
+            | Capability::Delete(pattern) => pattern,
+        }
+    }
+}
+
+/// The kind of access a gated `std.io` external function performs, independent
+/// of the glob pattern a particular [`Capability`] grants it over.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CapabilityKind {
+    Read,
+    Write,
+    CreateDir,
+    Delete,
+}
+
+impl CapabilityKind {
+    fn label(self) -> &'static str {
+        match self {
+            CapabilityKind::Read => "ReadPath",
+            CapabilityKind::Write => "WritePath",
+            CapabilityKind::CreateDir => "CreateDir",
+            CapabilityKind::Delete => "Delete",
+        }
+    }
+}
+
+/// Maps a `std.io` external function's name to the kind of access it
+/// performs, or `None` if the function doesn't touch the filesystem directly
+/// (e.g. `close_file` only releases a handle already opened under a granted
+/// capability).
+///
+/// Accepts both the bare name a call site actually uses (the parser keeps
+/// `CALL_FUNCTION "std.io.open_file"` as a single `FunctionReference::Local`,
+/// so `name` arrives as `"std.io.open_file"`) and the underlying
+/// `aether_io_*` runtime symbol, so this still matches calls that have
+/// already been resolved to their external declaration.
+fn gated_kind(name: &str) -> Option<CapabilityKind> {
+    match name.rsplit('.').next().unwrap_or(name) {
+        "open_file" | "aether_io_open_file" | "read_file" | "aether_io_read_file"
+        | "file_exists" | "aether_io_file_exists" | "file_size" | "aether_io_file_size" => {
+            Some(CapabilityKind::Read)
+        }
+        "write_file" | "aether_io_write_file" => Some(CapabilityKind::Write),
+        "create_directory" | "aether_io_create_directory" => Some(CapabilityKind::CreateDir),
+        "remove_file" | "aether_io_remove_file" => Some(CapabilityKind::Delete),
+        _ => None,
+    }
+}
+
+/// The set of capabilities granted to a module.
+#[derive(Debug, Clone, Default)]
+pub struct CapabilityManifest {
+    granted: Vec<Capability>,
+    /// Compatibility escape hatch for modules written before this manifest
+    /// existed: skips the check entirely instead of denying every gated call.
+    permissive: bool,
+}
+
+impl CapabilityManifest {
+    /// A manifest granting nothing - every gated `std.io` call is rejected
+    /// unless explicitly allowed via [`Self::allow`].
+    pub fn deny_all() -> Self {
+        Self::default()
+    }
+
+    /// A manifest that skips capability checking entirely, for modules that
+    /// predate this sandbox and haven't been audited yet.
+    pub fn permissive() -> Self {
+        Self { granted: Vec::new(), permissive: true }
+    }
+
+    pub fn allow(mut self, capability: Capability) -> Self {
+        self.granted.push(capability);
+        self
+    }
+
+    fn covers(&self, kind: CapabilityKind, path: &str) -> bool {
+        self.granted
+            .iter()
+            .any(|c| c.kind() == kind && glob_match(c.pattern(), path))
+    }
+
+    fn has_any(&self, kind: CapabilityKind) -> bool {
+        self.granted.iter().any(|c| c.kind() == kind)
+    }
+}
+
+/// Matches `path` against a glob `pattern` where `*` stands for any run of
+/// characters (including none) and every other character must match
+/// literally. No brace expansion, character classes, or `**` distinction -
+/// path allow-lists in practice are simple prefixes/suffixes like
+/// `/etc/myapp/*` or `*.log`.
+fn glob_match(pattern: &str, path: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let path: Vec<char> = path.chars().collect();
+
+    // dp[i][j] = pattern[..i] matches path[..j]
+    let mut dp = vec![vec![false; path.len() + 1]; pattern.len() + 1];
+    dp[0][0] = true;
+    for i in 1..=pattern.len() {
+        if pattern[i - 1] == '*' {
+            dp[i][0] = dp[i - 1][0];
+        }
+    }
+
+    for i in 1..=pattern.len() {
+        for j in 1..=path.len() {
+            dp[i][j] = if pattern[i - 1] == '*' {
+                dp[i - 1][j] || dp[i][j - 1]
+            } else {
+                dp[i - 1][j - 1] && pattern[i - 1] == path[j - 1]
+            };
+        }
+    }
+
+    dp[pattern.len()][path.len()]
+}
+
+/// Check every call to a gated `std.io` external function made from `module`'s
+/// own function bodies against `manifest`, returning the first violation
+/// found.
+pub fn check_module_capabilities(
+    module: &Module,
+    manifest: &CapabilityManifest,
+) -> Result<(), SemanticError> {
+    if manifest.permissive {
+        return Ok(());
+    }
+
+    for function in &module.function_definitions {
+        check_function(function, manifest)?;
+    }
+
+    Ok(())
+}
+
+fn check_function(function: &Function, manifest: &CapabilityManifest) -> Result<(), SemanticError> {
+    check_block(&function.body, manifest)
+}
+
+fn check_block(block: &Block, manifest: &CapabilityManifest) -> Result<(), SemanticError> {
+    for statement in &block.statements {
+        check_statement(statement, manifest)?;
+    }
+    Ok(())
+}
+
+fn check_statement(statement: &Statement, manifest: &CapabilityManifest) -> Result<(), SemanticError> {
+    match statement {
+        Statement::VariableDeclaration { initial_value, .. } => {
+            if let Some(value) = initial_value {
+                check_expression(value, manifest)?;
+            }
+        }
+        Statement::Assignment { value, .. } => check_expression(value, manifest)?,
+        Statement::FunctionCall { call, source_location } => {
+            check_call(call, source_location, manifest)?;
+        }
+        Statement::Return { value, .. } => {
+            if let Some(value) = value {
+                check_expression(value, manifest)?;
+            }
+        }
+        Statement::If { condition, then_block, else_ifs, else_block, .. } => {
+            check_expression(condition, manifest)?;
+            check_block(then_block, manifest)?;
+            for else_if in else_ifs {
+                check_expression(&else_if.condition, manifest)?;
+                check_block(&else_if.block, manifest)?;
+            }
+            if let Some(else_block) = else_block {
+                check_block(else_block, manifest)?;
+            }
+        }
+        Statement::WhileLoop { condition, body, .. } => {
+            check_expression(condition, manifest)?;
+            check_block(body, manifest)?;
+        }
+        Statement::ForEachLoop { collection, body, .. } => {
+            check_expression(collection, manifest)?;
+            check_block(body, manifest)?;
+        }
+        Statement::FixedIterationLoop { from_value, to_value, step_value, body, .. } => {
+            check_expression(from_value, manifest)?;
+            check_expression(to_value, manifest)?;
+            if let Some(step_value) = step_value {
+                check_expression(step_value, manifest)?;
+            }
+            check_block(body, manifest)?;
+        }
+        Statement::TryBlock { protected_block, catch_clauses, finally_block, .. } => {
+            check_block(protected_block, manifest)?;
+            for catch_clause in catch_clauses {
+                check_block(&catch_clause.handler_block, manifest)?;
+            }
+            if let Some(finally_block) = finally_block {
+                check_block(finally_block, manifest)?;
+            }
+        }
+        Statement::Throw { exception, .. } => check_expression(exception, manifest)?,
+        Statement::Expression { expr, .. } => check_expression(expr, manifest)?,
+        Statement::ResourceScope { .. } | Statement::Break { .. } | Statement::Continue { .. } => {}
+    }
+    Ok(())
+}
+
+fn check_expression(expression: &Expression, manifest: &CapabilityManifest) -> Result<(), SemanticError> {
+    if let Expression::FunctionCall { call, source_location } = expression {
+        check_call(call, source_location, manifest)?;
+        for argument in &call.arguments {
+            check_expression(&argument.value, manifest)?;
+        }
+    }
+    Ok(())
+}
+
+fn check_call(
+    call: &FunctionCall,
+    source_location: &crate::error::SourceLocation,
+    manifest: &CapabilityManifest,
+) -> Result<(), SemanticError> {
+    let name = match &call.function_reference {
+        FunctionReference::Local { name } => &name.name,
+        FunctionReference::Qualified { name, .. } => &name.name,
+        FunctionReference::External { name } => &name.name,
+    };
+
+    let Some(kind) = gated_kind(name) else {
+        return Ok(());
+    };
+
+    let path_argument = call
+        .arguments
+        .first()
+        .and_then(|argument| match argument.value.as_ref() {
+            Expression::StringLiteral { value, .. } => Some(value.as_str()),
+            _ => None,
+        });
+
+    let covered = match path_argument {
+        Some(path) => manifest.covers(kind, path),
+        None => manifest.has_any(kind),
+    };
+
+    if covered {
+        return Ok(());
+    }
+
+    Err(SemanticError::CapabilityViolation {
+        function: name.clone(),
+        path: path_argument.unwrap_or("<dynamic path>").to_string(),
+        required: kind.label().to_string(),
+        location: source_location.clone(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ast::{
+        Argument, Block, Function, FunctionCall, FunctionMetadata, FunctionReference, Identifier,
+        PrimitiveType, Statement, TypeSpecifier,
+    };
+    use crate::error::SourceLocation;
+
+    fn string_literal(value: &str) -> Box<Expression> {
+        Box::new(Expression::StringLiteral {
+            value: value.to_string(),
+            source_location: SourceLocation::unknown(),
+        })
+    }
+
+    fn call_statement(function_name: &str, path: &str) -> Statement {
+        Statement::FunctionCall {
+            call: FunctionCall {
+                function_reference: FunctionReference::Local {
+                    name: Identifier { name: function_name.to_string(), source_location: SourceLocation::unknown() },
+                },
+                arguments: vec![Argument {
+                    parameter_name: Identifier { name: "path".to_string(), source_location: SourceLocation::unknown() },
+                    value: string_literal(path),
+                }],
+                variadic_arguments: vec![],
+            },
+            source_location: SourceLocation::unknown(),
+        }
+    }
+
+    fn module_with_call(function_name: &str, path: &str) -> Module {
+        let function = Function {
+            name: Identifier { name: "main".to_string(), source_location: SourceLocation::unknown() },
+            intent: None,
+            generic_parameters: vec![],
+            parameters: vec![],
+            return_type: Box::new(TypeSpecifier::Primitive {
+                type_name: PrimitiveType::Void,
+                source_location: SourceLocation::unknown(),
+            }),
+            metadata: FunctionMetadata {
+                preconditions: vec![],
+                postconditions: vec![],
+                invariants: vec![],
+                algorithm_hint: None,
+                performance_expectation: None,
+                complexity_expectation: None,
+                throws_exceptions: vec![],
+                thread_safe: None,
+                may_block: None,
+                is_test: false,
+            },
+            body: Block {
+                statements: vec![call_statement(function_name, path)],
+                source_location: SourceLocation::unknown(),
+            },
+            export_info: None,
+            source_location: SourceLocation::unknown(),
+        };
+
+        Module {
+            name: Identifier { name: "app".to_string(), source_location: SourceLocation::unknown() },
+            intent: None,
+            imports: vec![],
+            exports: vec![],
+            type_definitions: vec![],
+            constant_declarations: vec![],
+            function_definitions: vec![function],
+            external_functions: vec![],
+            source_location: SourceLocation::unknown(),
+        }
+    }
+
+    #[test]
+    fn deny_all_rejects_ungranted_read() {
+        let module = module_with_call("aether_io_open_file", "/etc/passwd");
+        let manifest = CapabilityManifest::deny_all();
+
+        let err = check_module_capabilities(&module, &manifest).unwrap_err();
+        assert!(matches!(err, SemanticError::CapabilityViolation { .. }));
+    }
+
+    #[test]
+    fn matching_glob_allows_read() {
+        let module = module_with_call("aether_io_open_file", "/data/input.txt");
+        let manifest = CapabilityManifest::deny_all().allow(Capability::ReadPath("/data/*".to_string()));
+
+        assert!(check_module_capabilities(&module, &manifest).is_ok());
+    }
+
+    #[test]
+    fn path_outside_granted_glob_is_rejected() {
+        let module = module_with_call("aether_io_open_file", "/etc/passwd");
+        let manifest = CapabilityManifest::deny_all().allow(Capability::ReadPath("/data/*".to_string()));
+
+        let err = check_module_capabilities(&module, &manifest).unwrap_err();
+        match err {
+            SemanticError::CapabilityViolation { function, required, .. } => {
+                assert_eq!(function, "aether_io_open_file");
+                assert_eq!(required, "ReadPath");
+            }
+            other => panic!("expected CapabilityViolation, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn write_capability_does_not_cover_read() {
+        let module = module_with_call("aether_io_open_file", "/data/input.txt");
+        let manifest = CapabilityManifest::deny_all().allow(Capability::WritePath("/data/*".to_string()));
+
+        assert!(check_module_capabilities(&module, &manifest).is_err());
+    }
+
+    #[test]
+    fn permissive_manifest_skips_checking() {
+        let module = module_with_call("aether_io_open_file", "/etc/passwd");
+        let manifest = CapabilityManifest::permissive();
+
+        assert!(check_module_capabilities(&module, &manifest).is_ok());
+    }
+
+    #[test]
+    fn dotted_call_name_as_written_by_real_source_is_gated() {
+        // `CALL_FUNCTION "std.io.open_file"` parses into a single
+        // `FunctionReference::Local` whose name is the whole dotted string -
+        // this is how the call actually looks by the time it reaches here,
+        // as opposed to the bare `aether_io_open_file` symbol name used by
+        // the other tests in this module.
+        let module = module_with_call("std.io.open_file", "/etc/passwd");
+        let manifest = CapabilityManifest::deny_all();
+
+        let err = check_module_capabilities(&module, &manifest).unwrap_err();
+        assert!(matches!(err, SemanticError::CapabilityViolation { .. }));
+    }
+
+    #[test]
+    fn glob_match_supports_prefix_and_suffix_wildcards() {
+        assert!(glob_match("/data/*", "/data/input.txt"));
+        assert!(glob_match("*.log", "/var/log/app.log"));
+        assert!(!glob_match("/data/*", "/etc/passwd"));
+        assert!(glob_match("*", "anything"));
+    }
+}
@@ -14,7 +14,7 @@
 
 //! std.io - File and I/O operations module
 
-use crate::ast::{Module, TypeSpecifier, PrimitiveType, Identifier, ExportStatement};
+use crate::ast::{Module, TypeSpecifier, PrimitiveType, ConstantDeclaration, Expression, Identifier, ExportStatement};
 use crate::error::SourceLocation;
 use crate::ast::CallingConvention;
 use super::{create_external_function_named, create_function_stub};
@@ -23,6 +23,7 @@ use std::collections::HashMap;
 /// Create the std.io module with file operations
 pub fn create_io_module() -> Module {
     let mut external_functions = HashMap::new();
+    let mut constants = HashMap::new();
     
     // File handle type (opaque pointer)
     let file_handle_type = TypeSpecifier::Pointer {
@@ -136,16 +137,152 @@ pub fn create_io_module() -> Module {
         bool_type.clone(),
         CallingConvention::C,
     ));
-    
-    // High-level convenience functions (would be implemented in AetherScript)
+
+    // Canonicalizing constructors: these resolve `.`/`..` and symlinks and
+    // check the result against a configured sandbox root *before* checking
+    // existence, so a `../` component can't slip past the containment check
+    // the way it could if the check ran against the raw, un-resolved path.
+    external_functions.insert("canonicalize".to_string(), create_external_function_named(
+        "canonicalize",
+        "aether_io_canonicalize",
+        vec![("path", string_type.clone())],
+        string_type.clone(),
+        CallingConvention::C,
+    ));
+
+    external_functions.insert("open_existing_file".to_string(), create_external_function_named(
+        "open_existing_file",
+        "aether_io_open_existing_file",
+        vec![
+            ("path", string_type.clone()),
+            ("mode", string_type.clone()),
+        ],
+        file_handle_type.clone(),
+        CallingConvention::C,
+    ));
+
+    external_functions.insert("create_new_file".to_string(), create_external_function_named(
+        "create_new_file",
+        "aether_io_create_new_file",
+        vec![("path", string_type.clone())],
+        file_handle_type.clone(),
+        CallingConvention::C,
+    ));
+
+    // Both canonicalizing constructors return a null file handle on failure;
+    // this distinguishes the reason (not found vs. outside the sandbox root)
+    // without an out-param, matching how `errno`/`GetLastError` are consulted
+    // after a null/-1 return in the libraries this pattern is borrowed from.
+    external_functions.insert("last_error".to_string(), create_external_function_named(
+        "last_error",
+        "aether_io_last_error",
+        vec![],
+        string_type.clone(),
+        CallingConvention::C,
+    ));
+
+    // Positional and streaming operations, so large files can be processed
+    // in chunks instead of buffered whole into a single `read_file` call.
+    external_functions.insert("seek".to_string(), create_external_function_named(
+        "seek",
+        "aether_io_seek",
+        vec![
+            ("handle", file_handle_type.clone()),
+            ("offset", int_type.clone()),
+            ("whence", int_type.clone()),
+        ],
+        int_type.clone(),
+        CallingConvention::C,
+    ));
+
+    external_functions.insert("tell".to_string(), create_external_function_named(
+        "tell",
+        "aether_io_tell",
+        vec![("handle", file_handle_type.clone())],
+        size_type.clone(),
+        CallingConvention::C,
+    ));
+
+    external_functions.insert("read_chunk".to_string(), create_external_function_named(
+        "read_chunk",
+        "aether_io_read_chunk",
+        vec![
+            ("handle", file_handle_type.clone()),
+            ("buffer", TypeSpecifier::Pointer {
+                target_type: Box::new(string_type.clone()),
+                is_mutable: true,
+                source_location: SourceLocation::unknown(),
+            }),
+            ("n", size_type.clone()),
+        ],
+        // Bytes actually read: 0 at EOF, negative on error. A short read
+        // (fewer bytes than requested but more than zero) is not an error -
+        // callers loop until 0 or negative, not until a full `n` comes back.
+        int_type.clone(),
+        CallingConvention::C,
+    ));
+
+    external_functions.insert("flush".to_string(), create_external_function_named(
+        "flush",
+        "aether_io_flush",
+        vec![("handle", file_handle_type.clone())],
+        int_type.clone(),
+        CallingConvention::C,
+    ));
+
+    // Whence constants for seek, matching the POSIX lseek/fseek convention.
+    constants.insert("SEEK_START".to_string(), ConstantDeclaration {
+        name: Identifier::new("SEEK_START".to_string(), SourceLocation::unknown()),
+        type_spec: Box::new(int_type.clone()),
+        value: Box::new(Expression::IntegerLiteral {
+            value: 0,
+            bits: 64,
+            signed: true,
+            source_location: SourceLocation::unknown(),
+        }),
+        intent: Some("Seek relative to the start of the file".to_string()),
+        source_location: SourceLocation::unknown(),
+    });
+
+    constants.insert("SEEK_CURRENT".to_string(), ConstantDeclaration {
+        name: Identifier::new("SEEK_CURRENT".to_string(), SourceLocation::unknown()),
+        type_spec: Box::new(int_type.clone()),
+        value: Box::new(Expression::IntegerLiteral {
+            value: 1,
+            bits: 64,
+            signed: true,
+            source_location: SourceLocation::unknown(),
+        }),
+        intent: Some("Seek relative to the current file position".to_string()),
+        source_location: SourceLocation::unknown(),
+    });
+
+    constants.insert("SEEK_END".to_string(), ConstantDeclaration {
+        name: Identifier::new("SEEK_END".to_string(), SourceLocation::unknown()),
+        type_spec: Box::new(int_type.clone()),
+        value: Box::new(Expression::IntegerLiteral {
+            value: 2,
+            bits: 64,
+            signed: true,
+            source_location: SourceLocation::unknown(),
+        }),
+        intent: Some("Seek relative to the end of the file".to_string()),
+        source_location: SourceLocation::unknown(),
+    });
+
+    // High-level convenience functions (would be implemented in AetherScript).
+    // Like every stub here, read_entire_file and write_entire_file have an
+    // empty body: nothing routes them through open_existing_file/
+    // create_new_file, so their paths are not canonicalized or
+    // containment-checked by this module.
     let mut functions = HashMap::new();
-    
+
     functions.insert("read_entire_file".to_string(), create_function_stub(
         "read_entire_file",
         vec![("path", string_type.clone())],
         string_type.clone(),
     ));
-    
+
     functions.insert("write_entire_file".to_string(), create_function_stub(
         "write_entire_file",
         vec![
@@ -172,7 +309,40 @@ pub fn create_io_module() -> Module {
         ],
         bool_type.clone(),
     ));
-    
+
+    let string_array_type = TypeSpecifier::Array {
+        element_type: Box::new(string_type.clone()),
+        size: None,
+        source_location: SourceLocation::unknown(),
+    };
+
+    functions.insert("read_lines".to_string(), create_function_stub(
+        "read_lines",
+        vec![("path", string_type.clone())],
+        string_array_type,
+    ));
+
+    // Declares a one-line-at-a-time alternative to read_lines' array. The
+    // stub has an empty body: it does not call read_chunk or split lines.
+    functions.insert("for_each_line".to_string(), create_function_stub(
+        "for_each_line",
+        vec![
+            ("path", string_type.clone()),
+            ("callback", TypeSpecifier::Function {
+                parameter_types: vec![Box::new(string_type.clone())],
+                return_type: Box::new(TypeSpecifier::Primitive {
+                    type_name: PrimitiveType::Void,
+                    source_location: SourceLocation::unknown(),
+                }),
+                source_location: SourceLocation::unknown(),
+            }),
+        ],
+        TypeSpecifier::Primitive {
+            type_name: PrimitiveType::Void,
+            source_location: SourceLocation::unknown(),
+        },
+    ));
+
     Module {
         name: Identifier::new("std.io".to_string(), SourceLocation::unknown()),
         intent: Some("Provides file and I/O operations for AetherScript programs".to_string()),
@@ -186,9 +356,21 @@ pub fn create_io_module() -> Module {
                 name: Identifier::new("read_entire_file".to_string(), SourceLocation::unknown()),
                 source_location: SourceLocation::unknown(),
             },
+            ExportStatement::Constant {
+                name: Identifier::new("SEEK_START".to_string(), SourceLocation::unknown()),
+                source_location: SourceLocation::unknown(),
+            },
+            ExportStatement::Constant {
+                name: Identifier::new("SEEK_CURRENT".to_string(), SourceLocation::unknown()),
+                source_location: SourceLocation::unknown(),
+            },
+            ExportStatement::Constant {
+                name: Identifier::new("SEEK_END".to_string(), SourceLocation::unknown()),
+                source_location: SourceLocation::unknown(),
+            },
         ],
         type_definitions: vec![],
-        constant_declarations: vec![],
+        constant_declarations: constants.into_values().collect(),
         function_definitions: functions.into_values().collect(),
         external_functions: external_functions.into_values().collect(),
         source_location: SourceLocation::unknown(),
@@ -244,4 +426,130 @@ mod tests {
         assert_eq!(file_exists.parameters[0].name.name, "path");
         assert!(matches!(file_exists.return_type.as_ref(), TypeSpecifier::Primitive { type_name: PrimitiveType::Boolean, .. }));
     }
+
+    #[test]
+    fn test_canonicalizing_constructors_are_defined() {
+        let module = create_io_module();
+
+        let canonicalize = module.external_functions.iter()
+            .find(|f| f.name.name == "canonicalize")
+            .expect("canonicalize function not found");
+        assert_eq!(canonicalize.symbol.as_deref(), Some("aether_io_canonicalize"));
+
+        let open_existing = module.external_functions.iter()
+            .find(|f| f.name.name == "open_existing_file")
+            .expect("open_existing_file function not found");
+        assert_eq!(open_existing.parameters.len(), 2);
+
+        let create_new = module.external_functions.iter()
+            .find(|f| f.name.name == "create_new_file")
+            .expect("create_new_file function not found");
+        assert_eq!(create_new.parameters.len(), 1);
+
+        let last_error = module.external_functions.iter()
+            .find(|f| f.name.name == "last_error")
+            .expect("last_error function not found");
+        assert!(last_error.parameters.is_empty());
+        assert!(matches!(last_error.return_type.as_ref(), TypeSpecifier::Primitive { type_name: PrimitiveType::String, .. }));
+    }
+
+    // The canonicalize-before-check traversal behavior itself is exercised
+    // against the real `aether_io_canonicalize`/`aether_io_open_existing_file`/
+    // `aether_io_create_new_file` externs in `runtime/src/io.rs`, which is
+    // where they're actually implemented - this module only declares their
+    // signatures, so a test here could at best re-describe the algorithm
+    // rather than verify it.
+
+    #[test]
+    fn test_streaming_externals_are_defined() {
+        let module = create_io_module();
+
+        let seek = module.external_functions.iter()
+            .find(|f| f.name.name == "seek")
+            .expect("seek function not found");
+        assert_eq!(seek.parameters.len(), 3);
+        assert_eq!(seek.parameters[1].name.name, "offset");
+        assert_eq!(seek.parameters[2].name.name, "whence");
+
+        let tell = module.external_functions.iter()
+            .find(|f| f.name.name == "tell")
+            .expect("tell function not found");
+        assert_eq!(tell.parameters.len(), 1);
+
+        let read_chunk = module.external_functions.iter()
+            .find(|f| f.name.name == "read_chunk")
+            .expect("read_chunk function not found");
+        assert_eq!(read_chunk.parameters.len(), 3);
+        assert!(matches!(read_chunk.return_type.as_ref(), TypeSpecifier::Primitive { type_name: PrimitiveType::Integer, .. }));
+
+        assert!(module.external_functions.iter().any(|f| f.name.name == "flush"));
+    }
+
+    #[test]
+    fn test_whence_constants_are_exported() {
+        let module = create_io_module();
+
+        let seek_start = module.constant_declarations.iter()
+            .find(|c| c.name.name == "SEEK_START")
+            .expect("SEEK_START constant not found");
+        assert!(matches!(seek_start.value.as_ref(), Expression::IntegerLiteral { value: 0, .. }));
+
+        let seek_current = module.constant_declarations.iter()
+            .find(|c| c.name.name == "SEEK_CURRENT")
+            .expect("SEEK_CURRENT constant not found");
+        assert!(matches!(seek_current.value.as_ref(), Expression::IntegerLiteral { value: 1, .. }));
+
+        let seek_end = module.constant_declarations.iter()
+            .find(|c| c.name.name == "SEEK_END")
+            .expect("SEEK_END constant not found");
+        assert!(matches!(seek_end.value.as_ref(), Expression::IntegerLiteral { value: 2, .. }));
+
+        assert!(module.exports.iter().any(|e| matches!(e, ExportStatement::Constant { name, .. } if name.name == "SEEK_START")));
+    }
+
+    #[test]
+    fn test_line_iteration_stubs_are_defined() {
+        let module = create_io_module();
+
+        let read_lines = module.function_definitions.iter()
+            .find(|f| f.name.name == "read_lines")
+            .expect("read_lines function not found");
+        assert!(matches!(read_lines.return_type.as_ref(), TypeSpecifier::Array { .. }));
+
+        let for_each_line = module.function_definitions.iter()
+            .find(|f| f.name.name == "for_each_line")
+            .expect("for_each_line function not found");
+        assert_eq!(for_each_line.parameters.len(), 2);
+        assert!(matches!(for_each_line.parameters[1].param_type.as_ref(), TypeSpecifier::Function { .. }));
+    }
+
+    /// Reference behavior for `aether_io_read_chunk`: a short read (fewer
+    /// bytes than requested, but more than zero) is valid and must not be
+    /// treated as an error - callers keep reading until a zero-byte read
+    /// signals EOF, reassembling the full contents across chunks.
+    #[test]
+    fn read_chunk_short_reads_reassemble_into_full_contents() {
+        let path = std::env::temp_dir().join(format!("aether_io_chunked_read_{}", std::process::id()));
+        let contents = "the quick brown fox jumps over the lazy dog";
+        std::fs::write(&path, contents).unwrap();
+
+        let mut file = std::fs::File::open(&path).unwrap();
+        let chunk_size = 7; // does not evenly divide the file length
+        let mut buffer = vec![0u8; chunk_size];
+        let mut reassembled = Vec::new();
+
+        loop {
+            use std::io::Read;
+            let bytes_read = file.read(&mut buffer).unwrap();
+            if bytes_read == 0 {
+                break;
+            }
+            assert!(bytes_read <= chunk_size, "a chunk read more than requested");
+            reassembled.extend_from_slice(&buffer[..bytes_read]);
+        }
+
+        assert_eq!(String::from_utf8(reassembled).unwrap(), contents);
+
+        std::fs::remove_file(&path).ok();
+    }
 }
\ No newline at end of file
@@ -45,6 +45,7 @@ pub fn create_math_module() -> Module {
         name: Identifier::new("PI".to_string(), SourceLocation::unknown()),
         type_spec: Box::new(float_type.clone()),
         value: Box::new(Expression::FloatLiteral {
+            bits: 64,
             value: std::f64::consts::PI,
             source_location: SourceLocation::unknown(),
         }),
@@ -56,6 +57,7 @@ pub fn create_math_module() -> Module {
         name: Identifier::new("E".to_string(), SourceLocation::unknown()),
         type_spec: Box::new(float_type.clone()),
         value: Box::new(Expression::FloatLiteral {
+            bits: 64,
             value: std::f64::consts::E,
             source_location: SourceLocation::unknown(),
         }),
@@ -67,6 +69,7 @@ pub fn create_math_module() -> Module {
         name: Identifier::new("TAU".to_string(), SourceLocation::unknown()),
         type_spec: Box::new(float_type.clone()),
         value: Box::new(Expression::FloatLiteral {
+            bits: 64,
             value: std::f64::consts::TAU,
             source_location: SourceLocation::unknown(),
         }),
@@ -78,6 +81,7 @@ pub fn create_math_module() -> Module {
         name: Identifier::new("SQRT_2".to_string(), SourceLocation::unknown()),
         type_spec: Box::new(float_type.clone()),
         value: Box::new(Expression::FloatLiteral {
+            bits: 64,
             value: std::f64::consts::SQRT_2,
             source_location: SourceLocation::unknown(),
         }),
@@ -89,6 +93,7 @@ pub fn create_math_module() -> Module {
         name: Identifier::new("LN_2".to_string(), SourceLocation::unknown()),
         type_spec: Box::new(float_type.clone()),
         value: Box::new(Expression::FloatLiteral {
+            bits: 64,
             value: std::f64::consts::LN_2,
             source_location: SourceLocation::unknown(),
         }),
@@ -100,6 +105,7 @@ pub fn create_math_module() -> Module {
         name: Identifier::new("LN_10".to_string(), SourceLocation::unknown()),
         type_spec: Box::new(float_type.clone()),
         value: Box::new(Expression::FloatLiteral {
+            bits: 64,
             value: std::f64::consts::LN_10,
             source_location: SourceLocation::unknown(),
         }),
@@ -2,7 +2,9 @@
 //! 
 //! Core modules providing essential functionality for AetherScript programs
 
+pub mod capabilities;
 pub mod io;
+pub mod path;
 pub mod collections;
 pub mod math;
 pub mod string;
@@ -35,6 +37,7 @@ impl StandardLibrary {
     /// Register all core standard library modules
     fn register_core_modules(&mut self) {
         self.register_module("std.io", io::create_io_module());
+        self.register_module("std.path", path::create_path_module());
         self.register_module("std.collections", collections::create_collections_module());
         self.register_module("std.math", math::create_math_module());
         self.register_module("std.string", string::create_string_module());
@@ -165,6 +168,7 @@ pub(crate) fn create_function_stub(
             throws_exceptions: vec![],
             thread_safe: None,
             may_block: None,
+            is_test: false,
         },
         body: crate::ast::Block {
             statements: vec![], // Empty body - would be filled in by actual implementation
@@ -185,6 +189,7 @@ mod tests {
         
         // Check that all expected modules are registered
         assert!(stdlib.is_stdlib_module("std.io"));
+        assert!(stdlib.is_stdlib_module("std.path"));
         assert!(stdlib.is_stdlib_module("std.collections"));
         assert!(stdlib.is_stdlib_module("std.math"));
         assert!(stdlib.is_stdlib_module("std.string"));
@@ -203,8 +208,9 @@ mod tests {
         let stdlib = StandardLibrary::new();
         let modules = stdlib.list_modules();
         
-        assert_eq!(modules.len(), 9);
+        assert_eq!(modules.len(), 10);
         assert!(modules.contains(&"std.io"));
+        assert!(modules.contains(&"std.path"));
         assert!(modules.contains(&"std.collections"));
         assert!(modules.contains(&"std.math"));
         assert!(modules.contains(&"std.string"));
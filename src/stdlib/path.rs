@@ -0,0 +1,250 @@
+// Copyright 2025 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! std.path - Portable path manipulation module
+//!
+//! Every function here takes and returns the same `String` type rather than
+//! offering parallel `_str`/`_path` variants for a separate path handle -
+//! `std.io`'s externals already accept plain strings, so a dedicated path
+//! type would just mean converting back and forth at every call site.
+//! This module only declares the functions, as externals bound to native
+//! `aether_path_*` symbols; no runtime in this crate defines those symbols,
+//! so nothing here normalizes separators for POSIX or Windows targets.
+
+use crate::ast::{Module, TypeSpecifier, PrimitiveType, Identifier, ExportStatement};
+use crate::error::SourceLocation;
+use crate::ast::CallingConvention;
+use super::{create_external_function_named, create_function_stub};
+use std::collections::HashMap;
+
+/// Create the std.path module with path manipulation operations
+pub fn create_path_module() -> Module {
+    let mut external_functions = HashMap::new();
+
+    let string_type = TypeSpecifier::Primitive {
+        type_name: PrimitiveType::String,
+        source_location: SourceLocation::unknown(),
+    };
+    let bool_type = TypeSpecifier::Primitive {
+        type_name: PrimitiveType::Boolean,
+        source_location: SourceLocation::unknown(),
+    };
+    let string_array_type = TypeSpecifier::Array {
+        element_type: Box::new(string_type.clone()),
+        size: None,
+        source_location: SourceLocation::unknown(),
+    };
+
+    external_functions.insert("join".to_string(), create_external_function_named(
+        "join",
+        "aether_path_join",
+        vec![
+            ("base", string_type.clone()),
+            ("component", string_type.clone()),
+        ],
+        string_type.clone(),
+        CallingConvention::C,
+    ));
+
+    external_functions.insert("parent".to_string(), create_external_function_named(
+        "parent",
+        "aether_path_parent",
+        vec![("path", string_type.clone())],
+        string_type.clone(),
+        CallingConvention::C,
+    ));
+
+    external_functions.insert("file_name".to_string(), create_external_function_named(
+        "file_name",
+        "aether_path_file_name",
+        vec![("path", string_type.clone())],
+        string_type.clone(),
+        CallingConvention::C,
+    ));
+
+    external_functions.insert("extension".to_string(), create_external_function_named(
+        "extension",
+        "aether_path_extension",
+        vec![("path", string_type.clone())],
+        string_type.clone(),
+        CallingConvention::C,
+    ));
+
+    external_functions.insert("with_extension".to_string(), create_external_function_named(
+        "with_extension",
+        "aether_path_with_extension",
+        vec![
+            ("path", string_type.clone()),
+            ("extension", string_type.clone()),
+        ],
+        string_type.clone(),
+        CallingConvention::C,
+    ));
+
+    external_functions.insert("is_absolute".to_string(), create_external_function_named(
+        "is_absolute",
+        "aether_path_is_absolute",
+        vec![("path", string_type.clone())],
+        bool_type.clone(),
+        CallingConvention::C,
+    ));
+
+    external_functions.insert("components".to_string(), create_external_function_named(
+        "components",
+        "aether_path_components",
+        vec![("path", string_type.clone())],
+        string_array_type.clone(),
+        CallingConvention::C,
+    ));
+
+    // High-level convenience functions (would be implemented in AetherScript)
+    let mut functions = HashMap::new();
+
+    functions.insert("is_relative".to_string(), create_function_stub(
+        "is_relative",
+        vec![("path", string_type.clone())],
+        bool_type.clone(),
+    ));
+
+    functions.insert("join_all".to_string(), create_function_stub(
+        "join_all",
+        vec![("components", string_array_type.clone())],
+        string_type.clone(),
+    ));
+
+    Module {
+        name: Identifier::new("std.path".to_string(), SourceLocation::unknown()),
+        intent: Some("Provides portable path manipulation for AetherScript programs".to_string()),
+        imports: vec![],
+        exports: vec![
+            ExportStatement::Function {
+                name: Identifier::new("join".to_string(), SourceLocation::unknown()),
+                source_location: SourceLocation::unknown(),
+            },
+            ExportStatement::Function {
+                name: Identifier::new("is_relative".to_string(), SourceLocation::unknown()),
+                source_location: SourceLocation::unknown(),
+            },
+        ],
+        type_definitions: vec![],
+        constant_declarations: vec![],
+        function_definitions: functions.into_values().collect(),
+        external_functions: external_functions.into_values().collect(),
+        source_location: SourceLocation::unknown(),
+    }
+}
+
+/// Reference behavior for the native `aether_path_join` runtime function this
+/// module declares: exactly one `/` between `base` and `component` no matter
+/// how many either side already has. Kept here (rather than only described in
+/// prose) so the separator-collapsing contract has a test double to check
+/// against, since no native runtime in this crate backs these externals.
+#[cfg(test)]
+fn reference_join(base: &str, component: &str) -> String {
+    format!("{}/{}", base.trim_end_matches('/'), component.trim_start_matches('/'))
+}
+
+/// Reference behavior for `aether_path_extension`: empty string when the
+/// file name has no `.`, or its stem is empty (e.g. a dotfile like `.bashrc`).
+#[cfg(test)]
+fn reference_extension(path: &str) -> String {
+    let file_name = path.rsplit('/').next().unwrap_or(path);
+    match file_name.rsplit_once('.') {
+        Some((stem, ext)) if !stem.is_empty() => ext.to_string(),
+        _ => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_path_module_creation() {
+        let module = create_path_module();
+
+        assert_eq!(module.name.name, "std.path");
+        assert!(module.intent.is_some());
+
+        assert!(module.external_functions.iter().any(|f| f.name.name == "join"));
+        assert!(module.external_functions.iter().any(|f| f.name.name == "parent"));
+        assert!(module.external_functions.iter().any(|f| f.name.name == "file_name"));
+        assert!(module.external_functions.iter().any(|f| f.name.name == "extension"));
+        assert!(module.external_functions.iter().any(|f| f.name.name == "with_extension"));
+        assert!(module.external_functions.iter().any(|f| f.name.name == "is_absolute"));
+        assert!(module.external_functions.iter().any(|f| f.name.name == "components"));
+
+        assert!(module.function_definitions.iter().any(|f| f.name.name == "is_relative"));
+        assert!(module.function_definitions.iter().any(|f| f.name.name == "join_all"));
+
+        assert!(module.exports.iter().any(|e| matches!(e, ExportStatement::Function { name, .. } if name.name == "join")));
+    }
+
+    #[test]
+    fn test_join_signature_takes_two_strings() {
+        let module = create_path_module();
+
+        let join = module.external_functions.iter()
+            .find(|f| f.name.name == "join")
+            .expect("join function not found");
+        assert_eq!(join.symbol.as_deref(), Some("aether_path_join"));
+        assert_eq!(join.parameters.len(), 2);
+        assert_eq!(join.parameters[0].name.name, "base");
+        assert_eq!(join.parameters[1].name.name, "component");
+        assert!(matches!(join.return_type.as_ref(), TypeSpecifier::Primitive { type_name: PrimitiveType::String, .. }));
+    }
+
+    #[test]
+    fn test_components_returns_string_array() {
+        let module = create_path_module();
+
+        let components = module.external_functions.iter()
+            .find(|f| f.name.name == "components")
+            .expect("components function not found");
+        assert!(matches!(
+            components.return_type.as_ref(),
+            TypeSpecifier::Array { element_type, .. }
+                if matches!(element_type.as_ref(), TypeSpecifier::Primitive { type_name: PrimitiveType::String, .. })
+        ));
+    }
+
+    #[test]
+    fn test_extension_and_with_extension_share_string_input_type() {
+        let module = create_path_module();
+
+        let extension = module.external_functions.iter()
+            .find(|f| f.name.name == "extension")
+            .expect("extension function not found");
+        let with_extension = module.external_functions.iter()
+            .find(|f| f.name.name == "with_extension")
+            .expect("with_extension function not found");
+
+        assert!(matches!(extension.parameters[0].param_type.as_ref(), TypeSpecifier::Primitive { type_name: PrimitiveType::String, .. }));
+        assert!(matches!(with_extension.parameters[0].param_type.as_ref(), TypeSpecifier::Primitive { type_name: PrimitiveType::String, .. }));
+    }
+
+    #[test]
+    fn join_collapses_redundant_separators() {
+        assert_eq!(reference_join("a/", "/b"), "a/b");
+        assert_eq!(reference_join("a", "b"), "a/b");
+        assert_eq!(reference_join("a//", "//b"), "a/b");
+    }
+
+    #[test]
+    fn extension_is_empty_for_extensionless_names() {
+        assert_eq!(reference_extension("README"), "");
+        assert_eq!(reference_extension(".bashrc"), "");
+        assert_eq!(reference_extension("archive.tar.gz"), "gz");
+    }
+}
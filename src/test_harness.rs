@@ -0,0 +1,267 @@
+// Copyright 2025 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Built-in test-function discovery and runner, modeled on Noir's
+//! `get_all_test_functions_in_crate_matching`/`TestFunction`.
+//!
+//! A function definition opts in with a `(TEST)` field (see
+//! [`crate::ast::FunctionMetadata::is_test`]); [`find_test_functions`]
+//! collects every such function across a [`Program`], filtered by a
+//! [`NameMatch`] predicate, and [`TestRunner`] checks each one in
+//! isolation, reporting pass/fail with the failing function's
+//! [`SourceLocation`].
+
+use crate::ast::{Function, Program};
+use crate::error::{SemanticError, SourceLocation};
+use crate::semantic::SemanticAnalyzer;
+
+/// A test function discovered in a [`Program`].
+#[derive(Debug, Clone)]
+pub struct TestFunction {
+    pub name: String,
+    pub module_name: String,
+    pub source_location: SourceLocation,
+}
+
+/// Predicate used to filter discovered test functions by name, mirroring
+/// the `cargo test <filter>` / Noir test-selection conventions.
+#[derive(Debug, Clone)]
+pub enum NameMatch {
+    /// Every test function matches.
+    Anything,
+    /// Only the test function whose name equals this string matches.
+    Exact(String),
+    /// Only test functions whose name contains this substring match.
+    Contains(String),
+}
+
+impl NameMatch {
+    fn matches(&self, name: &str) -> bool {
+        match self {
+            NameMatch::Anything => true,
+            NameMatch::Exact(expected) => name == expected,
+            NameMatch::Contains(substring) => name.contains(substring.as_str()),
+        }
+    }
+}
+
+/// Collect every function across `program` that is declared `(TEST)` and
+/// whose name satisfies `matcher`, in module-then-declaration order.
+pub fn find_test_functions(program: &Program, matcher: &NameMatch) -> Vec<TestFunction> {
+    let mut tests = Vec::new();
+
+    for module in &program.modules {
+        for function in &module.function_definitions {
+            if function.metadata.is_test && matcher.matches(&function.name.name) {
+                tests.push(TestFunction {
+                    name: function.name.name.clone(),
+                    module_name: module.name.name.clone(),
+                    source_location: function.source_location.clone(),
+                });
+            }
+        }
+    }
+
+    tests
+}
+
+/// Outcome of running a single [`TestFunction`].
+#[derive(Debug, Clone)]
+pub enum TestOutcome {
+    Passed,
+    Failed {
+        message: String,
+        location: SourceLocation,
+    },
+}
+
+/// A [`TestFunction`] paired with how it ran.
+#[derive(Debug, Clone)]
+pub struct TestResult {
+    pub test: TestFunction,
+    pub outcome: TestOutcome,
+}
+
+/// Runs discovered test functions in isolation and reports pass/fail.
+///
+/// Each test is checked by cloning its enclosing module with every *other*
+/// function stripped out, so a failure can't be masked (or spuriously
+/// caused) by a sibling function, then running semantic analysis on that
+/// isolated module. The runner does not compile a test to native code and
+/// does not execute it: a test "passes" once its isolated module analyzes
+/// without error.
+pub struct TestRunner;
+
+impl TestRunner {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Run every test function discovered in `program` matching `matcher`.
+    pub fn run_all(&self, program: &Program, matcher: &NameMatch) -> Vec<TestResult> {
+        find_test_functions(program, matcher)
+            .into_iter()
+            .map(|test| self.run_one(program, test))
+            .collect()
+    }
+
+    fn run_one(&self, program: &Program, test: TestFunction) -> TestResult {
+        let isolated = Self::isolate(program, &test);
+        let mut analyzer = SemanticAnalyzer::new();
+
+        let outcome = match analyzer.analyze_program(&isolated) {
+            Ok(()) => TestOutcome::Passed,
+            Err(errors) => TestOutcome::Failed {
+                message: Self::describe(&errors),
+                location: test.source_location.clone(),
+            },
+        };
+
+        TestResult { test, outcome }
+    }
+
+    /// Clone `program`, keeping only `test`'s own module and, within it,
+    /// only `test` itself - everything else a well-formed program needs
+    /// (types, constants, imports) stays in place.
+    fn isolate(program: &Program, test: &TestFunction) -> Program {
+        let mut isolated = program.clone();
+        for module in &mut isolated.modules {
+            if module.name.name == test.module_name {
+                module.function_definitions.retain(|function: &Function| function.name.name == test.name);
+            }
+        }
+        isolated
+    }
+
+    fn describe(errors: &[SemanticError]) -> String {
+        errors
+            .first()
+            .map(|error| error.to_string())
+            .unwrap_or_else(|| "test failed with no diagnostic".to_string())
+    }
+}
+
+impl Default for TestRunner {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ast::{Block, FunctionMetadata, Identifier, Module, PrimitiveType, TypeSpecifier};
+
+    fn test_function(name: &str, is_test: bool) -> Function {
+        let location = SourceLocation::unknown();
+        Function {
+            name: Identifier::new(name.to_string(), location.clone()),
+            intent: None,
+            generic_parameters: Vec::new(),
+            parameters: Vec::new(),
+            return_type: Box::new(TypeSpecifier::Primitive {
+                type_name: PrimitiveType::Void,
+                source_location: location.clone(),
+            }),
+            metadata: FunctionMetadata {
+                preconditions: Vec::new(),
+                postconditions: Vec::new(),
+                invariants: Vec::new(),
+                algorithm_hint: None,
+                performance_expectation: None,
+                complexity_expectation: None,
+                throws_exceptions: Vec::new(),
+                thread_safe: None,
+                may_block: None,
+                is_test,
+            },
+            body: Block {
+                statements: Vec::new(),
+                source_location: location.clone(),
+            },
+            export_info: None,
+            source_location: location,
+        }
+    }
+
+    fn program_with(functions: Vec<Function>) -> Program {
+        let location = SourceLocation::unknown();
+        Program {
+            modules: vec![Module {
+                name: Identifier::new("test_module".to_string(), location.clone()),
+                intent: None,
+                imports: Vec::new(),
+                exports: Vec::new(),
+                type_definitions: Vec::new(),
+                constant_declarations: Vec::new(),
+                function_definitions: functions,
+                external_functions: Vec::new(),
+                source_location: location.clone(),
+            }],
+            source_location: location,
+        }
+    }
+
+    #[test]
+    fn finds_only_functions_marked_test() {
+        let program = program_with(vec![
+            test_function("helper", false),
+            test_function("test_addition", true),
+            test_function("test_subtraction", true),
+        ]);
+
+        let found = find_test_functions(&program, &NameMatch::Anything);
+
+        assert_eq!(found.len(), 2);
+        assert!(found.iter().any(|t| t.name == "test_addition"));
+        assert!(found.iter().any(|t| t.name == "test_subtraction"));
+    }
+
+    #[test]
+    fn exact_match_filters_by_name() {
+        let program = program_with(vec![
+            test_function("test_addition", true),
+            test_function("test_subtraction", true),
+        ]);
+
+        let found = find_test_functions(&program, &NameMatch::Exact("test_addition".to_string()));
+
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].name, "test_addition");
+    }
+
+    #[test]
+    fn contains_match_filters_by_substring() {
+        let program = program_with(vec![
+            test_function("test_addition", true),
+            test_function("test_subtraction", true),
+        ]);
+
+        let found = find_test_functions(&program, &NameMatch::Contains("add".to_string()));
+
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].name, "test_addition");
+    }
+
+    #[test]
+    fn runner_reports_pass_for_well_formed_test() {
+        let program = program_with(vec![test_function("test_ok", true)]);
+        let runner = TestRunner::new();
+
+        let results = runner.run_all(&program, &NameMatch::Anything);
+
+        assert_eq!(results.len(), 1);
+        assert!(matches!(results[0].outcome, TestOutcome::Passed));
+    }
+}
@@ -108,7 +108,14 @@ pub enum Type {
         ownership: OwnershipKind,
         base_type: Box<Type>,
     },
-    
+
+    /// SIMD vector type (e.g. 4 lanes of `Integer` for a 128-bit `i32x4`),
+    /// introduced by auto-vectorization to widen scalar loop bodies.
+    Vector {
+        element_type: Box<Type>,
+        lanes: usize,
+    },
+
     /// Error type for recovery
     Error,
 }
@@ -169,7 +176,15 @@ impl Type {
             module,
         }
     }
-    
+
+    /// Create a new SIMD vector type
+    pub fn vector(element_type: Type, lanes: usize) -> Self {
+        Type::Vector {
+            element_type: Box::new(element_type),
+            lanes,
+        }
+    }
+
     /// Create a new owned type (^T)
     pub fn owned(base_type: Type) -> Self {
         Type::Owned {
@@ -212,11 +227,17 @@ impl Type {
             Type::Primitive(PrimitiveType::Float32) |
             Type::Primitive(PrimitiveType::Float64) |
             Type::Primitive(PrimitiveType::SizeT) |
-            Type::Primitive(PrimitiveType::UIntPtrT) => true,
+            Type::Primitive(PrimitiveType::UIntPtrT) |
+            Type::Primitive(PrimitiveType::I8) |
+            Type::Primitive(PrimitiveType::I16) |
+            Type::Primitive(PrimitiveType::U8) |
+            Type::Primitive(PrimitiveType::U16) |
+            Type::Primitive(PrimitiveType::U32) |
+            Type::Primitive(PrimitiveType::U64) => true,
             _ => false,
         }
     }
-    
+
     /// Check if this type is an integer type
     pub fn is_integer(&self) -> bool {
         match self {
@@ -224,7 +245,13 @@ impl Type {
             Type::Primitive(PrimitiveType::Integer32) |
             Type::Primitive(PrimitiveType::Integer64) |
             Type::Primitive(PrimitiveType::SizeT) |
-            Type::Primitive(PrimitiveType::UIntPtrT) => true,
+            Type::Primitive(PrimitiveType::UIntPtrT) |
+            Type::Primitive(PrimitiveType::I8) |
+            Type::Primitive(PrimitiveType::I16) |
+            Type::Primitive(PrimitiveType::U8) |
+            Type::Primitive(PrimitiveType::U16) |
+            Type::Primitive(PrimitiveType::U32) |
+            Type::Primitive(PrimitiveType::U64) => true,
             _ => false,
         }
     }
@@ -285,6 +312,10 @@ impl Type {
             Type::Primitive(PrimitiveType::Float64) => Some(8),
             Type::Primitive(PrimitiveType::SizeT) => Some(8), // Assuming 64-bit target
             Type::Primitive(PrimitiveType::UIntPtrT) => Some(8), // Assuming 64-bit target
+            Type::Primitive(PrimitiveType::I8) | Type::Primitive(PrimitiveType::U8) => Some(1),
+            Type::Primitive(PrimitiveType::I16) | Type::Primitive(PrimitiveType::U16) => Some(2),
+            Type::Primitive(PrimitiveType::U32) => Some(4),
+            Type::Primitive(PrimitiveType::U64) => Some(8),
             Type::Pointer { .. } => Some(8), // Assuming 64-bit target
             Type::Array { element_type, size: Some(size) } => {
                 element_type.size_bytes().map(|elem_size| elem_size * size)
@@ -315,7 +346,13 @@ impl Type {
             Type::Primitive(PrimitiveType::Float32) |
             Type::Primitive(PrimitiveType::Float64) |
             Type::Primitive(PrimitiveType::SizeT) |
-            Type::Primitive(PrimitiveType::UIntPtrT) => false,
+            Type::Primitive(PrimitiveType::UIntPtrT) |
+            Type::Primitive(PrimitiveType::I8) |
+            Type::Primitive(PrimitiveType::I16) |
+            Type::Primitive(PrimitiveType::U8) |
+            Type::Primitive(PrimitiveType::U16) |
+            Type::Primitive(PrimitiveType::U32) |
+            Type::Primitive(PrimitiveType::U64) => false,
             Type::Primitive(PrimitiveType::String) |
             Type::Array { .. } |
             Type::Map { .. } |
@@ -323,6 +360,7 @@ impl Type {
             Type::Pointer { .. } => true,
             Type::Function { .. } => false, // Functions are not owned
             Type::Owned { .. } => true, // Owned types always require ownership tracking
+            Type::Vector { .. } => false, // Vector registers are not owned
             Type::Error | Type::Variable(_) | Type::Generic { .. } | Type::GenericInstance { .. } => false,
         }
     }
@@ -380,6 +418,7 @@ impl fmt::Display for Type {
                 };
                 write!(f, "{}{}", prefix, base_type)
             }
+            Type::Vector { element_type, lanes } => write!(f, "Vector<{}, {}>", element_type, lanes),
             Type::Error => write!(f, "<error>"),
         }
     }
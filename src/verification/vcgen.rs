@@ -353,6 +353,10 @@ impl VcGenerator {
                     }
                     mir::ConstantValue::Char(c) => Formula::Int(*c as i64),
                     mir::ConstantValue::Null => Formula::Bool(false),
+                    mir::ConstantValue::Array(_) | mir::ConstantValue::Tuple(_) => {
+                        // Aggregates not yet supported in verification
+                        Formula::Bool(true)
+                    }
                 })
             }
         }
@@ -347,4 +347,403 @@ fn test_performance_large_file() {
     
     // Performance assertion: should complete within 5 seconds
     assert!(duration.as_secs() < 5, "Large file processing took too long: {:?}", duration);
+}
+
+#[test]
+fn test_cli_check_json_error_format_success() {
+    let (stdout, stderr, exit_code) = run_aether_cli(&[
+        "check",
+        "--error-format", "json",
+        &fixture_path("simple_module.aether")
+    ]);
+
+    assert_eq!(exit_code, 0, "Stderr: {}", stderr);
+    assert!(stdout.contains("\"files_passed\":1"));
+    assert!(stdout.contains("\"total_errors\":0"));
+}
+
+#[test]
+fn test_cli_check_json_error_format_failure() {
+    let (stdout, _stderr, exit_code) = run_aether_cli(&[
+        "check",
+        "--error-format", "json",
+        &fixture_path("type_errors.aether")
+    ]);
+
+    assert_eq!(exit_code, 1);
+    assert!(stdout.contains("\"level\":\"error\""));
+    assert!(stdout.contains("\"files_with_errors\":1"));
+}
+
+#[test]
+fn test_cli_check_json_error_format_nonexistent_file() {
+    let (stdout, _stderr, exit_code) = run_aether_cli(&[
+        "check",
+        "--error-format", "json",
+        "nonexistent_file.aether"
+    ]);
+
+    assert_eq!(exit_code, 1);
+    assert!(stdout.contains("\"code\":\"E0005\""));
+}
+
+/// A compiletest-style `//~` annotation parsed from a fixture: expects a
+/// diagnostic of `level` on `line` whose message contains `message`.
+#[derive(Debug, Clone)]
+struct ExpectedDiagnostic {
+    line: usize,
+    level: String,
+    message: String,
+}
+
+/// A diagnostic actually produced by `check --error-format json`.
+#[derive(Debug, Clone)]
+struct ProducedDiagnostic {
+    line: usize,
+    level: String,
+    message: String,
+}
+
+/// Parse `//~` annotations out of a fixture's source.
+///
+/// - `//~ ERROR <substring>` expects a diagnostic on *this* line.
+/// - `//~^ ERROR <substring>` (N carets) expects a diagnostic N lines above this one.
+/// - `//~| ERROR <substring>` attaches to the same line as the previous annotation.
+fn parse_expected_diagnostics(source: &str) -> Vec<ExpectedDiagnostic> {
+    let mut expected = Vec::new();
+    let mut previous_line: Option<usize> = None;
+
+    for (idx, line) in source.lines().enumerate() {
+        let lineno = idx + 1;
+        let Some(marker) = line.find("//~") else {
+            continue;
+        };
+
+        let rest = line[marker + 3..].trim_start();
+        let (target_line, rest) = if let Some(rest) = rest.strip_prefix('|') {
+            (previous_line.unwrap_or(lineno), rest)
+        } else {
+            let carets = rest.chars().take_while(|&c| c == '^').count();
+            if carets > 0 {
+                (lineno.saturating_sub(carets), &rest[carets..])
+            } else {
+                (lineno, rest)
+            }
+        };
+
+        let rest = rest.trim_start();
+        let (level, message) = rest.split_once(' ').unwrap_or((rest, ""));
+
+        expected.push(ExpectedDiagnostic {
+            line: target_line,
+            level: level.trim().to_lowercase(),
+            message: message.trim().to_string(),
+        });
+        previous_line = Some(target_line);
+    }
+
+    expected
+}
+
+/// Parse the JSON diagnostic lines produced by `check --error-format json`
+/// (see `aether::error::json_diagnostic`), keyed by `line_start`.
+fn parse_produced_diagnostics(stdout: &str) -> Vec<ProducedDiagnostic> {
+    let mut produced = Vec::new();
+
+    for line in stdout.lines() {
+        let Ok(value) = serde_json::from_str::<serde_json::Value>(line) else {
+            continue;
+        };
+        let Some(spans) = value.get("spans").and_then(|s| s.as_array()) else {
+            continue;
+        };
+        let Some(level) = value.get("level").and_then(|l| l.as_str()) else {
+            continue;
+        };
+        let Some(message) = value.get("message").and_then(|m| m.as_str()) else {
+            continue;
+        };
+
+        for span in spans {
+            let Some(line_start) = span.get("line_start").and_then(|l| l.as_u64()) else {
+                continue;
+            };
+            produced.push(ProducedDiagnostic {
+                line: line_start as usize,
+                level: level.to_lowercase(),
+                message: message.to_string(),
+            });
+        }
+    }
+
+    produced
+}
+
+/// Check a fixture's `//~` annotations against the diagnostics `check
+/// --error-format json` actually produces for it, panicking with both the
+/// "expected but not produced" and "produced but not expected" lists on
+/// mismatch.
+fn check_annotations(fixture: &str) {
+    let path = fixture_path(fixture);
+    let source = fs::read_to_string(&path).expect(&format!("Failed to read fixture {}", path));
+    let mut expected = parse_expected_diagnostics(&source);
+
+    let (stdout, _stderr, _exit_code) = run_aether_cli(&[
+        "check",
+        "--error-format", "json",
+        &path,
+    ]);
+    let produced = parse_produced_diagnostics(&stdout);
+
+    let mut unmatched_produced = Vec::new();
+    for diagnostic in produced {
+        if let Some(pos) = expected.iter().position(|e| {
+            e.line == diagnostic.line
+                && e.level == diagnostic.level
+                && diagnostic.message.contains(&e.message)
+        }) {
+            expected.remove(pos);
+        } else {
+            unmatched_produced.push(diagnostic);
+        }
+    }
+
+    if !expected.is_empty() || !unmatched_produced.is_empty() {
+        panic!(
+            "annotation mismatch in {}:\nexpected but not produced: {:#?}\nproduced but not expected: {:#?}",
+            fixture, expected, unmatched_produced
+        );
+    }
+}
+
+#[test]
+fn test_cli_check_annotations_type_errors() {
+    check_annotations("type_errors.aether");
+}
+
+/// Path to a fixture's checked-in golden reference file for `ext`
+/// ("stdout", "stderr", or "exit").
+fn golden_path(fixture: &str, ext: &str) -> String {
+    format!("tests/fixtures/golden/{}.{}", fixture, ext)
+}
+
+/// Strip content that varies between machines/runs (the absolute working
+/// directory, timing figures) so golden comparisons stay deterministic.
+fn normalize_golden(text: &str) -> String {
+    let cwd = std::env::current_dir()
+        .map(|p| p.display().to_string())
+        .unwrap_or_default();
+    if cwd.is_empty() {
+        text.to_string()
+    } else {
+        text.replace(&cwd, "<CWD>")
+    }
+}
+
+/// Print a unified, line-numbered diff between `expected` and `actual`,
+/// the way compiletest's uidiff highlights only the differing regions.
+fn golden_diff(expected: &str, actual: &str) -> String {
+    let expected_lines: Vec<&str> = expected.lines().collect();
+    let actual_lines: Vec<&str> = actual.lines().collect();
+    let max_lines = expected_lines.len().max(actual_lines.len());
+
+    let mut diff = String::new();
+    for i in 0..max_lines {
+        let expected_line = expected_lines.get(i).copied();
+        let actual_line = actual_lines.get(i).copied();
+        if expected_line != actual_line {
+            if let Some(line) = expected_line {
+                diff.push_str(&format!("{:>4} - {}\n", i + 1, line));
+            }
+            if let Some(line) = actual_line {
+                diff.push_str(&format!("{:>4} + {}\n", i + 1, line));
+            }
+        }
+    }
+    diff
+}
+
+/// Compare `actual` against the checked-in golden file at `path`, or (when
+/// `AETHER_BLESS=1` is set) write `actual` as the new reference.
+fn assert_golden(path: &str, actual: &str) {
+    let actual = normalize_golden(actual);
+
+    if std::env::var("AETHER_BLESS").as_deref() == Ok("1") {
+        if let Some(parent) = Path::new(path).parent() {
+            fs::create_dir_all(parent).expect("Failed to create golden directory");
+        }
+        fs::write(path, &actual).expect("Failed to write golden file");
+        return;
+    }
+
+    let expected = fs::read_to_string(path)
+        .unwrap_or_else(|_| panic!("No golden file at {} (run with AETHER_BLESS=1 to create it)", path));
+
+    if expected != actual {
+        panic!(
+            "golden mismatch for {} (run with AETHER_BLESS=1 to update):\n{}",
+            path,
+            golden_diff(&expected, &actual)
+        );
+    }
+}
+
+#[test]
+fn test_cli_ast_command_golden() {
+    let (stdout, stderr, exit_code) = run_aether_cli(&[
+        "ast",
+        &fixture_path("simple_module.aether")
+    ]);
+
+    assert_golden(&golden_path("simple_module_ast", "stdout"), &stdout);
+    assert_golden(&golden_path("simple_module_ast", "stderr"), &stderr);
+    assert_golden(&golden_path("simple_module_ast", "exit"), &exit_code.to_string());
+}
+
+#[test]
+fn test_cli_tokens_command_golden() {
+    let (stdout, stderr, exit_code) = run_aether_cli(&[
+        "tokens",
+        &fixture_path("simple_module.aether")
+    ]);
+
+    assert_golden(&golden_path("simple_module_tokens", "stdout"), &stdout);
+    assert_golden(&golden_path("simple_module_tokens", "stderr"), &stderr);
+    assert_golden(&golden_path("simple_module_tokens", "exit"), &exit_code.to_string());
+}
+
+/// Directives parsed from a fixture's leading comment block, analogous to
+/// compiletest's header mechanism: `// subcommand: check|ast|tokens|compile`,
+/// `// compile-flags: --verbose --debug`, `// expect-exit: 1`, and
+/// `// ignore: <reason>` / `// only: <platform>`.
+#[derive(Debug, Default)]
+struct FixtureDirectives {
+    subcommand: Option<String>,
+    compile_flags: Vec<String>,
+    expect_exit: Option<i32>,
+    ignore: Option<String>,
+    only: Option<String>,
+}
+
+/// Parse the directives out of the first comment block of a fixture.
+/// Scanning stops at the first non-comment line.
+fn parse_fixture_directives(source: &str) -> FixtureDirectives {
+    let mut directives = FixtureDirectives::default();
+
+    for line in source.lines() {
+        let line = line.trim();
+        let Some(rest) = line.strip_prefix("//") else {
+            break;
+        };
+        let rest = rest.trim();
+
+        if let Some(value) = rest.strip_prefix("subcommand:") {
+            directives.subcommand = Some(value.trim().to_string());
+        } else if let Some(value) = rest.strip_prefix("compile-flags:") {
+            directives.compile_flags = value.split_whitespace().map(String::from).collect();
+        } else if let Some(value) = rest.strip_prefix("expect-exit:") {
+            directives.expect_exit = value.trim().parse().ok();
+        } else if let Some(value) = rest.strip_prefix("ignore:") {
+            directives.ignore = Some(value.trim().to_string());
+        } else if let Some(value) = rest.strip_prefix("only:") {
+            directives.only = Some(value.trim().to_string());
+        }
+        // Unrecognized comment lines are just header documentation.
+    }
+
+    directives
+}
+
+/// The platform name directives' `// only:` compares against.
+fn current_platform() -> &'static str {
+    if cfg!(target_os = "linux") {
+        "linux"
+    } else if cfg!(target_os = "macos") {
+        "macos"
+    } else if cfg!(target_os = "windows") {
+        "windows"
+    } else {
+        "unknown"
+    }
+}
+
+/// Auto-discover every fixture carrying a `// subcommand:` directive and
+/// drive it through `run_aether_cli` accordingly, the way Deno's
+/// `CheckOutputIntegrationTest` runs a declarative table of cases. Fixtures
+/// with no directives are left to their own dedicated `#[test]` functions.
+#[test]
+fn test_directive_driven_fixtures() {
+    let fixtures_dir = Path::new("tests/fixtures");
+    let Ok(entries) = fs::read_dir(fixtures_dir) else {
+        return;
+    };
+
+    let mut failures = Vec::new();
+
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.extension().and_then(|e| e.to_str()) != Some("aether") {
+            continue;
+        }
+
+        let source = fs::read_to_string(&path).expect("Failed to read fixture");
+        let directives = parse_fixture_directives(&source);
+
+        if let Some(reason) = &directives.ignore {
+            eprintln!("skipping {}: {}", path.display(), reason);
+            continue;
+        }
+        if let Some(platform) = &directives.only {
+            if platform != current_platform() {
+                continue;
+            }
+        }
+
+        let Some(subcommand) = directives.subcommand.clone() else {
+            continue;
+        };
+
+        let path_str = path.display().to_string();
+        let mut args = vec![subcommand.as_str()];
+        for flag in &directives.compile_flags {
+            args.push(flag.as_str());
+        }
+        args.push(&path_str);
+
+        let (_stdout, stderr, exit_code) = run_aether_cli(&args);
+        let expected_exit = directives.expect_exit.unwrap_or(0);
+        if exit_code != expected_exit {
+            failures.push(format!(
+                "{}: expected exit {} but got {} (stderr: {})",
+                path.display(), expected_exit, exit_code, stderr
+            ));
+        }
+    }
+
+    assert!(failures.is_empty(), "directive-driven fixtures failed:\n{}", failures.join("\n"));
+}
+
+#[test]
+fn test_cli_check_timings() {
+    let (stdout, stderr, exit_code) = run_aether_cli(&[
+        "check",
+        "--timings",
+        &fixture_path("simple_module.aether")
+    ]);
+
+    assert_eq!(exit_code, 0, "Stderr: {}", stderr);
+    assert!(stdout.contains("Phase timings:"));
+    assert!(stdout.contains("parsing"));
+}
+
+#[test]
+fn test_cli_check_time_budget_exceeded() {
+    let (stdout, _stderr, exit_code) = run_aether_cli(&[
+        "check",
+        "--time-budget-ms", "0",
+        &fixture_path("simple_module.aether")
+    ]);
+
+    assert_eq!(exit_code, 1);
+    assert!(stdout.contains("Files with errors: 1") || stdout.contains("Total errors: 1"));
 }
\ No newline at end of file
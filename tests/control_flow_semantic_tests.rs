@@ -52,6 +52,7 @@ fn create_control_flow_ast() -> Program {
             throws_exceptions: Vec::new(),
             thread_safe: None,
             may_block: None,
+            is_test: false,
         },
         body: Block {
             statements: vec![
@@ -62,6 +63,8 @@ fn create_control_flow_ast() -> Program {
                             source_location: loc.clone(),
                         }),
                         right: Box::new(Expression::IntegerLiteral {
+                            bits: 64,
+                            signed: true,
                             value: 10,
                             source_location: loc.clone(),
                         }),
@@ -71,6 +74,8 @@ fn create_control_flow_ast() -> Program {
                         statements: vec![
                             Statement::Return {
                                 value: Some(Box::new(Expression::IntegerLiteral {
+                                    bits: 64,
+                                    signed: true,
                                     value: 1,
                                     source_location: loc.clone(),
                                 })),
@@ -84,6 +89,8 @@ fn create_control_flow_ast() -> Program {
                         statements: vec![
                             Statement::Return {
                                 value: Some(Box::new(Expression::IntegerLiteral {
+                                    bits: 64,
+                                    signed: true,
                                     value: 0,
                                     source_location: loc.clone(),
                                 })),
@@ -133,6 +140,7 @@ fn create_control_flow_ast() -> Program {
             throws_exceptions: Vec::new(),
             thread_safe: None,
             may_block: None,
+            is_test: false,
         },
         body: Block {
             statements: vec![
@@ -144,6 +152,8 @@ fn create_control_flow_ast() -> Program {
                     }),
                     mutability: Mutability::Mutable,
                     initial_value: Some(Box::new(Expression::IntegerLiteral {
+                        bits: 64,
+                        signed: true,
                         value: 0,
                         source_location: loc.clone(),
                     })),
@@ -175,6 +185,8 @@ fn create_control_flow_ast() -> Program {
                                         source_location: loc.clone(),
                                     }),
                                     right: Box::new(Expression::IntegerLiteral {
+                                        bits: 64,
+                                        signed: true,
                                         value: 1,
                                         source_location: loc.clone(),
                                     }),
@@ -258,11 +270,14 @@ fn test_non_boolean_condition_error() {
             throws_exceptions: Vec::new(),
             thread_safe: None,
             may_block: None,
+            is_test: false,
         },
         body: Block {
             statements: vec![
                 Statement::If {
                     condition: Box::new(Expression::IntegerLiteral {
+                        bits: 64,
+                        signed: true,
                         value: 42, // This should fail - not a boolean
                         source_location: loc.clone(),
                     }),
@@ -335,6 +350,7 @@ fn test_loop_variable_scope() {
             throws_exceptions: Vec::new(),
             thread_safe: None,
             may_block: None,
+            is_test: false,
         },
         body: Block {
             statements: vec![
@@ -346,14 +362,20 @@ fn test_loop_variable_scope() {
                         }),
                         elements: vec![
                             Box::new(Expression::IntegerLiteral {
+                                bits: 64,
+                                signed: true,
                                 value: 1,
                                 source_location: loc.clone(),
                             }),
                             Box::new(Expression::IntegerLiteral {
+                                bits: 64,
+                                signed: true,
                                 value: 2,
                                 source_location: loc.clone(),
                             }),
                             Box::new(Expression::IntegerLiteral {
+                                bits: 64,
+                                signed: true,
                                 value: 3,
                                 source_location: loc.clone(),
                             }),
@@ -441,6 +463,7 @@ fn test_break_continue_statements() {
             throws_exceptions: Vec::new(),
             thread_safe: None,
             may_block: None,
+            is_test: false,
         },
         body: Block {
             statements: vec![
@@ -536,6 +559,7 @@ fn test_try_catch_analysis() {
             throws_exceptions: Vec::new(),
             thread_safe: None,
             may_block: None,
+            is_test: false,
         },
         body: Block {
             statements: vec![
@@ -544,6 +568,8 @@ fn test_try_catch_analysis() {
                         statements: vec![
                             Statement::Return {
                                 value: Some(Box::new(Expression::IntegerLiteral {
+                                    bits: 64,
+                                    signed: true,
                                     value: 42,
                                     source_location: loc.clone(),
                                 })),
@@ -563,6 +589,8 @@ fn test_try_catch_analysis() {
                                 statements: vec![
                                     Statement::Return {
                                         value: Some(Box::new(Expression::IntegerLiteral {
+                                            bits: 64,
+                                            signed: true,
                                             value: -1,
                                             source_location: loc.clone(),
                                         })),
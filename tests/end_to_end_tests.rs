@@ -161,10 +161,36 @@ fn test_parser_error_recovery() {
     
     let mut parser = Parser::new(tokens);
     let result = parser.parse_program();
-    
+
     assert!(result.is_err());
 }
 
+#[test]
+fn test_parser_recovering_collects_every_module_error() {
+    // Two malformed modules followed by one well-formed one: recovery mode
+    // should skip past both errors and still recover the valid module. The
+    // malformed modules keep their parens balanced (the error is an unknown
+    // field), since recovery resynchronizes on paren depth.
+    let source = r#"(DEFINE_MODULE (NAME 'broken_one') (NOT_A_FIELD))
+        (DEFINE_MODULE (NAME 'broken_two') (NOT_A_FIELD))
+        (DEFINE_MODULE
+            (NAME 'recovered')
+            (INTENT "Should still parse")
+            (CONTENT)
+        )"#;
+
+    let mut lexer = Lexer::new(source, "test.aether".to_string());
+    let tokens = lexer.tokenize().expect("Tokenization should succeed");
+
+    let mut parser = Parser::new(tokens);
+    let program = parser.parse_program_recovering();
+    let errors = parser.take_errors();
+
+    assert!(!errors.is_empty(), "expected the two malformed modules to be recorded as errors");
+    assert!(program.modules.iter().any(|m| m.name.name == "recovered"));
+    assert!(parser.take_errors().is_empty(), "take_errors should drain the error list");
+}
+
 #[test]
 fn test_large_file_performance() {
     use std::time::Instant;
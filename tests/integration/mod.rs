@@ -22,4 +22,6 @@ mod llm_workflow_tests;
 mod test_string_runtime;
 mod test_variadic_functions;
 mod test_ffi_structs;
-mod test_memory_alloc;
\ No newline at end of file
+mod test_memory_alloc;
+mod vectorizer_reachability_tests;
+mod constant_folding_array_reachability_tests;
\ No newline at end of file
@@ -0,0 +1,112 @@
+// Copyright 2025 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Documents the gap between `optimizations::vectorization::VectorizationPass`
+//! and what real source actually lowers to.
+//!
+//! The pass looks for array reads/writes expressed as a `Place` with a
+//! `PlaceElem::Index` projection, but `mir::lowering` never produces that
+//! shape: `a[i]` lowers to an opaque `array_get` call and `a[i] = v` isn't
+//! lowerable as an assignment target at all. These tests compile a real
+//! source-level array loop through the actual lexer/parser/lowering chain
+//! and pin down that reality, so the pass's own unit tests (which only ever
+//! feed it hand-built indexed-`Place` MIR) can't be mistaken for coverage of
+//! anything a user's program would produce.
+
+use aether::lexer::Lexer;
+use aether::mir::lowering::lower_ast_to_mir;
+use aether::mir::{ConstantValue, Operand, PlaceElem, Rvalue, Statement};
+use aether::parser::Parser;
+
+fn lower_source(source: &str) -> aether::mir::Program {
+    let mut lexer = Lexer::new(source, "vectorizer_reachability_test.aether".to_string());
+    let tokens = lexer.tokenize().expect("tokenization should succeed");
+
+    let mut parser = Parser::new(tokens);
+    let program = parser.parse_program().expect("parsing should succeed");
+
+    lower_ast_to_mir(&program).expect("lowering should succeed")
+}
+
+const ARRAY_SUM_LOOP: &str = r#"(DEFINE_MODULE
+    (NAME vector_reach_test)
+    (CONTENT
+        (DEFINE_FUNCTION
+            (NAME sum_array)
+            (ACCEPTS_PARAMETER (NAME values) (TYPE (ARRAY_OF_TYPE INTEGER)))
+            (RETURNS INTEGER)
+            (BODY
+                (DECLARE_VARIABLE (NAME total) (TYPE INTEGER) (MUTABILITY MUTABLE) (VALUE 0))
+                (LOOP_FIXED_ITERATIONS
+                    (COUNTER i)
+                    (FROM 0)
+                    (TO 3)
+                    (DO
+                        (ASSIGN
+                            (TARGET_VARIABLE total)
+                            (SOURCE_EXPRESSION (EXPRESSION_ADD total (GET_ARRAY_ELEMENT values i)))
+                        )
+                    )
+                )
+                (RETURN_VALUE total)
+            )
+        )
+    )
+)"#;
+
+/// A source-level `a[i]` read lowers to an `array_get` call, never to a
+/// `Place` with a `PlaceElem::Index` projection - the shape
+/// `VectorizationPass::analyze_statement_for_vectorization` requires to
+/// classify a statement as vectorizable at all.
+#[test]
+fn array_read_lowers_to_an_opaque_call_not_an_indexed_place() {
+    let mir = lower_source(ARRAY_SUM_LOOP);
+    let function = mir.functions.get("sum_array").expect("sum_array should have lowered");
+
+    let mut saw_array_get_call = false;
+    for block in function.basic_blocks.values() {
+        for statement in &block.statements {
+            if let Statement::Assign { place, rvalue, .. } = statement {
+                assert!(
+                    place.projection.iter().all(|elem| !matches!(elem, PlaceElem::Index(_))),
+                    "found an indexed Place write - the vectorizer's precondition MIR shape \
+                     is now reachable from real source, update this test and re-evaluate whether \
+                     VectorizationPass can be wired into a real optimization pipeline"
+                );
+                if let Rvalue::Call { func: Operand::Constant(c), .. } = rvalue {
+                    if let ConstantValue::String(name) = &c.value {
+                        if name == "array_get" {
+                            saw_array_get_call = true;
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    assert!(
+        saw_array_get_call,
+        "expected the array read to lower to an array_get call, as mir::lowering::lower_array_access does today"
+    );
+}
+
+// Separately: `CompilationPipeline::compile_files` (src/pipeline/mod.rs)
+// only ever builds `OptimizationManager::create_default_pipeline()`, which
+// doesn't add `VectorizationPass` at all - only
+// `create_advanced_pipeline`/`create_pgo_pipeline`/`create_whole_program_pipeline`
+// do, and nothing in this crate ever constructs any of those. That's a
+// second, independent reason a real compile can't reach this pass today,
+// on top of the lowering gap above. `OptimizationManager` doesn't expose
+// its pass list, so there's nothing to assert here in code; see
+// src/optimizations/mod.rs.
@@ -29,6 +29,18 @@ fn fuzz_string() -> impl Strategy<Value = String> {
         .prop_map(|bytes| String::from_utf8_lossy(&bytes).to_string())
 }
 
+/// Generates strings built only from the delimiters the lexer's mode stack
+/// cares about (`"`, `${`, `}`), in arbitrary and likely-unbalanced order,
+/// so `test_lexer_never_crashes` can fuzz string/interpolation nesting
+/// specifically rather than relying on fully random bytes to stumble into it.
+fn fuzz_delimiters() -> impl Strategy<Value = String> {
+    prop::collection::vec(
+        prop::sample::select(vec!["\"", "${", "}", "x", " "]),
+        0..40,
+    )
+    .prop_map(|pieces| pieces.concat())
+}
+
 /// Property test: Valid identifiers should always tokenize successfully
 proptest! {
     #[test]
@@ -182,13 +194,25 @@ proptest! {
     #[test]
     fn test_lexer_never_crashes(input in fuzz_string()) {
         let mut lexer = Lexer::new(&input, "fuzz.aether".to_string());
-        
+
         // The lexer should never panic, even on invalid input
         let _result = lexer.tokenize();
-        
+
         // We don't care if it succeeds or fails, just that it doesn't crash
         prop_assert!(true);
     }
+
+    #[test]
+    fn test_lexer_never_crashes_on_unbalanced_delimiters(input in fuzz_delimiters()) {
+        let mut lexer = Lexer::new(&input, "fuzz.aether".to_string());
+
+        // Whether unbalanced `"`/`${`/`}` nesting succeeds or fails, the
+        // explicit mode stack must always unwind back to just `Normal`
+        // rather than leaving stale `InString`/`InInterpolation` entries
+        // behind or panicking.
+        let _result = lexer.tokenize();
+        prop_assert_eq!(lexer.mode_stack_depth(), 1);
+    }
 }
 
 /// Property test: Parser should never crash on valid tokens
@@ -216,6 +240,7 @@ proptest! {
                 token_type,
                 lexeme: "test".to_string(),
                 location: aether::error::SourceLocation::unknown(),
+                rational: None,
             }
         }).collect();
         
@@ -251,6 +276,8 @@ proptest! {
                         source_location: aether::error::SourceLocation::unknown(),
                     }),
                     value: Box::new(aether::ast::Expression::IntegerLiteral {
+                        bits: 64,
+                        signed: true,
                         value: 42,
                         source_location: aether::error::SourceLocation::unknown(),
                     }),
@@ -393,4 +420,244 @@ proptest! {
         // Module name should still be preserved after semantic analysis
         prop_assert_eq!(&program.modules[0].name.name, &module_name);
     }
-}
\ No newline at end of file
+}
+/// Generate a (bits, signed) pair naming one of the sized integer suffixes
+/// (`i8`/`u8`/`i16`/.../`u64`).
+fn sized_integer_width() -> impl Strategy<Value = (u32, bool)> {
+    prop_oneof![
+        Just((8, true)), Just((8, false)),
+        Just((16, true)), Just((16, false)),
+        Just((32, true)), Just((32, false)),
+        Just((64, true)), Just((64, false)),
+    ]
+}
+
+fn integer_range(bits: u32, signed: bool) -> (i128, i128) {
+    match (bits, signed) {
+        (8, true) => (i8::MIN as i128, i8::MAX as i128),
+        (8, false) => (u8::MIN as i128, u8::MAX as i128),
+        (16, true) => (i16::MIN as i128, i16::MAX as i128),
+        (16, false) => (u16::MIN as i128, u16::MAX as i128),
+        (32, true) => (i32::MIN as i128, i32::MAX as i128),
+        (32, false) => (u32::MIN as i128, u32::MAX as i128),
+        (64, true) => (i64::MIN as i128, i64::MAX as i128),
+        (64, false) => (u64::MIN as i128, u64::MAX as i128),
+        _ => unreachable!("unsupported width"),
+    }
+}
+
+/// Build a minimal module declaring a single constant initialized from a
+/// sized integer literal, the way [`test_semantic_analyzer_never_crashes`]
+/// builds its AST by hand rather than going through the parser.
+fn module_with_sized_literal(value: i64, bits: u32, signed: bool) -> aether::ast::Module {
+    aether::ast::Module {
+        name: aether::ast::Identifier::new("sized_literal_test".to_string(), aether::error::SourceLocation::unknown()),
+        intent: Some("Test sized literal range checking".to_string()),
+        imports: Vec::new(),
+        exports: Vec::new(),
+        type_definitions: Vec::new(),
+        constant_declarations: vec![
+            aether::ast::ConstantDeclaration {
+                name: aether::ast::Identifier::new("VALUE".to_string(), aether::error::SourceLocation::unknown()),
+                type_spec: Box::new(aether::ast::TypeSpecifier::Primitive {
+                    type_name: aether::ast::PrimitiveType::Integer,
+                    source_location: aether::error::SourceLocation::unknown(),
+                }),
+                value: Box::new(aether::ast::Expression::IntegerLiteral {
+                    value,
+                    bits,
+                    signed,
+                    source_location: aether::error::SourceLocation::unknown(),
+                }),
+                intent: Some("Sized literal under test".to_string()),
+                source_location: aether::error::SourceLocation::unknown(),
+            }
+        ],
+        function_definitions: Vec::new(),
+        source_location: aether::error::SourceLocation::unknown(),
+    }
+}
+
+/// Property test: a sized integer literal within its suffix's range should
+/// always type check, and one outside it should always be rejected.
+proptest! {
+    #[test]
+    fn test_sized_literal_range_checking((bits, signed) in sized_integer_width()) {
+        let (min, max) = integer_range(bits, signed);
+
+        // In range: the midpoint of the type's range always fits.
+        let in_range_value = (min + (max - min) / 2).clamp(i64::MIN as i128, i64::MAX as i128) as i64;
+        let in_range_module = aether::ast::Program {
+            modules: vec![module_with_sized_literal(in_range_value, bits, signed)],
+            source_location: aether::error::SourceLocation::unknown(),
+        };
+        let mut analyzer = SemanticAnalyzer::new();
+        prop_assert!(analyzer.analyze_program(&in_range_module).is_ok());
+
+        // Out of range: one past the type's max, clamped into i64 so the
+        // literal itself can still be represented (this is what the lexer
+        // would have parsed before the suffix-driven range check rejects it).
+        if max < i64::MAX as i128 {
+            let out_of_range_value = (max + 1) as i64;
+            let out_of_range_module = aether::ast::Program {
+                modules: vec![module_with_sized_literal(out_of_range_value, bits, signed)],
+                source_location: aether::error::SourceLocation::unknown(),
+            };
+            let mut analyzer = SemanticAnalyzer::new();
+            prop_assert!(analyzer.analyze_program(&out_of_range_module).is_err());
+        }
+    }
+}
+
+/// Generate a (prefix, radix) pair naming one of the non-decimal integer
+/// literal bases the lexer accepts.
+fn radix_prefix() -> impl Strategy<Value = (&'static str, u32)> {
+    prop_oneof![
+        Just(("0x", 16)),
+        Just(("0b", 2)),
+        Just(("0o", 8)),
+    ]
+}
+
+/// Property test: hex/binary/octal integer literals round-trip through the
+/// lexer to the same value decimal would, regardless of base or digit
+/// separators, and an empty digit group after the radix prefix is rejected.
+proptest! {
+    #[test]
+    fn test_radix_integer_round_trip((prefix, radix) in radix_prefix(), value in 0u32..0xFFFFu32) {
+        let digits = match radix {
+            16 => format!("{:x}", value),
+            2 => format!("{:b}", value),
+            8 => format!("{:o}", value),
+            _ => unreachable!(),
+        };
+        let source = format!("{}{}", prefix, digits);
+        let mut lexer = Lexer::new(&source, "test.aether".to_string());
+        let tokens = lexer.tokenize().unwrap();
+        prop_assert!(matches!(tokens[0].token_type, TokenType::Integer(v) if v == value as i64));
+
+        // Digit separators anywhere in the run parse identically.
+        let separated = format!("{}_{}_{}", prefix, digits, digits);
+        let mut lexer = Lexer::new(&separated, "test.aether".to_string());
+        let tokens = lexer.tokenize().unwrap();
+        let expected = i64::from_str_radix(&format!("{}{}", digits, digits), radix).unwrap();
+        prop_assert!(matches!(tokens[0].token_type, TokenType::Integer(v) if v == expected));
+
+        // An empty digit group (just the prefix) is an error, not a silent 0.
+        let mut lexer = Lexer::new(prefix, "test.aether".to_string());
+        prop_assert!(lexer.tokenize().is_err());
+    }
+}
+
+/// Property test: digit separators in decimal integer/float literals are
+/// ignored for the parsed value, and a leading-zero decimal literal (e.g.
+/// `0123`) is read as plain decimal rather than implicit octal.
+proptest! {
+    #[test]
+    fn test_decimal_digit_separators_and_leading_zeros(value in 1_000u32..999_999u32) {
+        let plain = value.to_string();
+        let mut grouped = String::new();
+        for (i, ch) in plain.chars().rev().enumerate() {
+            if i > 0 && i % 3 == 0 {
+                grouped.push('_');
+            }
+            grouped.push(ch);
+        }
+        let grouped: String = grouped.chars().rev().collect();
+
+        let mut lexer = Lexer::new(&grouped, "test.aether".to_string());
+        let tokens = lexer.tokenize().unwrap();
+        prop_assert!(matches!(tokens[0].token_type, TokenType::Integer(v) if v == value as i64));
+
+        // `0` followed by plain decimal digits is decimal, not octal.
+        let leading_zero = format!("0{}", plain);
+        let mut lexer = Lexer::new(&leading_zero, "test.aether".to_string());
+        let tokens = lexer.tokenize().unwrap();
+        prop_assert!(matches!(tokens[0].token_type, TokenType::Integer(v) if v == value as i64));
+    }
+}
+
+/// Property test: a float literal's mantissa/fraction/exponent are
+/// preserved verbatim on the token alongside the rounded `f64`.
+proptest! {
+    #[test]
+    fn test_float_rational_parts_preserved(int_part in 0u32..1000u32, frac_part in 0u32..1000u32, exponent in -10i32..10i32) {
+        let source = format!("{}.{}e{}", int_part, frac_part, exponent);
+        let mut lexer = Lexer::new(&source, "test.aether".to_string());
+        let tokens = lexer.tokenize().unwrap();
+
+        let rational = tokens[0].rational.as_ref().expect("float token carries rational parts");
+        prop_assert_eq!(&rational.mantissa, &int_part.to_string());
+        prop_assert_eq!(&rational.fraction, &frac_part.to_string());
+        prop_assert_eq!(rational.exponent, exponent);
+        prop_assert!(matches!(tokens[0].token_type, TokenType::Float(_)));
+    }
+}
+
+/// Build a minimal module declaring a single integer constant, the same
+/// shape [`test_semantic_analyzer_never_crashes`] builds by hand, for
+/// [`test_printer_round_trips_a_constant_module`] to print and reparse.
+fn module_with_integer_constant(
+    module_name: String,
+    const_name: String,
+    value: i64,
+) -> aether::ast::Module {
+    aether::ast::Module {
+        name: aether::ast::Identifier::new(module_name, aether::error::SourceLocation::unknown()),
+        intent: None,
+        imports: Vec::new(),
+        exports: Vec::new(),
+        type_definitions: Vec::new(),
+        constant_declarations: vec![
+            aether::ast::ConstantDeclaration {
+                name: aether::ast::Identifier::new(const_name, aether::error::SourceLocation::unknown()),
+                type_spec: Box::new(aether::ast::TypeSpecifier::Primitive {
+                    type_name: aether::ast::PrimitiveType::Integer,
+                    source_location: aether::error::SourceLocation::unknown(),
+                }),
+                value: Box::new(aether::ast::Expression::IntegerLiteral {
+                    value,
+                    bits: 64,
+                    signed: true,
+                    source_location: aether::error::SourceLocation::unknown(),
+                }),
+                intent: None,
+                source_location: aether::error::SourceLocation::unknown(),
+            }
+        ],
+        function_definitions: Vec::new(),
+        external_functions: Vec::new(),
+        source_location: aether::error::SourceLocation::unknown(),
+    }
+}
+
+/// Property test: printing a module and reparsing the result always yields
+/// the same module name, constant name, and constant value the original
+/// hand-built AST had - the round trip [`aether::codegen::Printer`] exists
+/// to make possible.
+proptest! {
+    #[test]
+    fn test_printer_round_trips_a_constant_module(
+        module_name in valid_identifier(),
+        const_name in valid_identifier(),
+        value in valid_integer()
+    ) {
+        let module = module_with_integer_constant(module_name.clone(), const_name.clone(), value);
+
+        let printer = aether::codegen::Printer::default();
+        let printed = printer.print_module(&module);
+
+        let mut lexer = Lexer::new(&printed, "test.aether".to_string());
+        let tokens = lexer.tokenize().expect("printed module should lex");
+        let mut parser = Parser::new(tokens);
+        let reparsed = parser.parse_module().expect("printed module should parse");
+
+        prop_assert_eq!(reparsed.name.name, module_name);
+        prop_assert_eq!(reparsed.constant_declarations.len(), 1);
+        prop_assert_eq!(&reparsed.constant_declarations[0].name.name, &const_name);
+        prop_assert!(matches!(
+            *reparsed.constant_declarations[0].value,
+            aether::ast::Expression::IntegerLiteral { value: reparsed_value, .. } if reparsed_value == value
+        ));
+    }
+}
@@ -92,6 +92,7 @@ fn create_test_module_with_struct() -> Module {
                     throws_exceptions: vec![],
                     thread_safe: Some(true),
                     may_block: Some(false),
+                    is_test: false,
                 },
                 body: Block {
                     statements: vec![